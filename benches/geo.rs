@@ -5,7 +5,8 @@
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use geo::InterpolatePoint;
-use geo::{Bearing, Destination, Distance, Geodesic, Haversine, Rhumb, point};
+use geo::line_measures::LengthMeasurable;
+use geo::{Bearing, Destination, Distance, Geodesic, Haversine, LineString, Point, Rhumb, point};
 use geo_ruler::geo::RulerMeasure;
 
 macro_rules! bench_distance {
@@ -152,5 +153,44 @@ pub fn benchmark(c: &mut Criterion) {
     bench_new_york!(c, "rhumb", &Rhumb);
 }
 
-criterion_group!(benches, benchmark);
+/// Generates a spiral route around the Empire State Building.
+///
+/// Every hop turns 3 degrees and is 0.2 meters longer than the previous one, starting
+/// at 20 meters, so 500 points wind outwards for about 35 km like a GPS trace.
+fn generate_route(size: usize) -> LineString<f64> {
+    let mut point = point!(x: -73.9857, y: 40.7484);
+    let mut bearing = 0.;
+
+    let mut points: Vec<Point<f64>> = Vec::with_capacity(size);
+
+    for i in 0..size {
+        points.push(point);
+        point = Geodesic.destination(point, bearing, 20. + 0.2 * i as f64);
+        bearing = (bearing + 3.) % 360.;
+    }
+
+    points.into()
+}
+
+pub fn length_benchmark(c: &mut Criterion) {
+    let route = generate_route(500);
+
+    let ruler = RulerMeasure::<f64>::WGS84();
+
+    let mut group = c.benchmark_group("length");
+
+    group.bench_with_input("ruler", &route, |b, route| {
+        b.iter(|| black_box(route).length(&ruler))
+    });
+    group.bench_with_input("geodesic", &route, |b, route| {
+        b.iter(|| black_box(route).length(&Geodesic))
+    });
+    group.bench_with_input("haversine", &route, |b, route| {
+        b.iter(|| black_box(route).length(&Haversine))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark, length_benchmark);
 criterion_main!(benches);
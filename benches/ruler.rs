@@ -34,5 +34,217 @@ pub fn benchmark(c: &mut Criterion) {
     g.finish();
 }
 
-criterion_group!(benches, benchmark);
+/// Points spread around the origin, a prime number of them so no batch size divides it.
+fn generate_test_data(origin: &[f32; 2], size: usize) -> Vec<[f32; 2]> {
+    let ruler = CheapRuler::WGS84();
+
+    (0..size)
+        .map(|i| {
+            let bearing = 360. * i as f32 / size as f32;
+            let distance = 100. + (i % 97) as f32 * 100.;
+            ruler.destination(origin, &bearing, &distance)
+        })
+        .collect()
+}
+
+pub fn batch_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("cheap-ruler-batch");
+
+    let origin = [-73.9857, 40.7484];
+    let data = generate_test_data(&origin, 4093);
+
+    let ruler = CheapRuler::<f32>::WGS84();
+
+    let mut out = vec![0.; data.len()];
+
+    g.bench_with_input("naive", &data, |b, data| {
+        b.iter(|| {
+            for (destination, distance) in black_box(data).iter().zip(out.iter_mut()) {
+                *distance = ruler.distance(black_box(&origin), destination);
+            }
+        });
+    });
+
+    g.bench_with_input("distance_to_many", &data, |b, data| {
+        b.iter(|| ruler.distance_to_many(black_box(&origin), black_box(data), &mut out));
+    });
+
+    g.finish();
+}
+
+/// Compares a radius filter on distances against one on squared distances.
+pub fn radius_filter_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("cheap-ruler-radius-filter");
+
+    let origin = [-73.9857, 40.7484];
+    let data = generate_test_data(&origin, 4093);
+
+    let ruler = CheapRuler::<f32>::WGS84();
+    let radius = 5_000.;
+
+    let mut out = vec![0.; data.len()];
+
+    g.bench_with_input("distance_to_many", &data, |b, data| {
+        b.iter(|| {
+            ruler.distance_to_many(black_box(&origin), black_box(data), &mut out);
+            out.iter().filter(|distance| **distance <= radius).count()
+        });
+    });
+
+    g.bench_with_input("distances_squared_to_many", &data, |b, data| {
+        b.iter(|| {
+            ruler.distances_squared_to_many(black_box(&origin), black_box(data), &mut out);
+            out.iter()
+                .filter(|squared| **squared <= radius * radius)
+                .count()
+        });
+    });
+
+    g.finish();
+}
+
+/// Compares calls sharing the origin latitude with calls alternating between two latitudes.
+///
+/// Run with and without `--features memoize` to measure the effect of caching the
+/// scaling factors, the alternating pattern defeats the cache on every call. The origin
+/// goes through `black_box` so the compiler cannot hoist the factors out of the loop,
+/// as it would not across opaque call sites in a real application.
+pub fn repeated_origin_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("cheap-ruler-repeated-origin");
+
+    let origin = [-73.9857, 40.7484];
+    let other = [-73.9857, 40.7580];
+    let data = generate_test_data(&origin, 4093);
+
+    let ruler = CheapRuler::<f32>::WGS84();
+
+    g.bench_with_input("same-latitude", &data, |b, data| {
+        b.iter(|| {
+            black_box(data)
+                .iter()
+                .map(|destination| ruler.distance(black_box(&origin), destination))
+                .sum::<f32>()
+        });
+    });
+
+    g.bench_with_input("alternating-latitude", &data, |b, data| {
+        b.iter(|| {
+            black_box(data)
+                .iter()
+                .enumerate()
+                .map(|(i, destination)| {
+                    let origin = if i % 2 == 0 { &origin } else { &other };
+                    ruler.distance(black_box(origin), destination)
+                })
+                .sum::<f32>()
+        });
+    });
+
+    g.finish();
+}
+
+/// Compares a spatial join measured with `CheapRuler::distance` against a `FixedRuler`.
+///
+/// The naive double loop computes the scaling for every pair, the fixed ruler once for
+/// the whole join.
+pub fn distance_matrix_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("cheap-ruler-distance-matrix");
+
+    let origin = [-73.9857, 40.7484];
+    let origins = generate_test_data(&origin, 67);
+    let destinations = generate_test_data(&origin, 257);
+
+    let ruler = CheapRuler::<f32>::WGS84();
+
+    let mut out = vec![0.; origins.len() * destinations.len()];
+
+    g.bench_with_input(
+        "naive",
+        &(&origins, &destinations),
+        |b, (origins, destinations)| {
+            b.iter(|| {
+                let distances = out.chunks_exact_mut(destinations.len());
+
+                for (origin, row) in black_box(origins).iter().zip(distances) {
+                    for (destination, distance) in black_box(destinations).iter().zip(row) {
+                        *distance = ruler.distance(origin, destination);
+                    }
+                }
+            });
+        },
+    );
+
+    g.bench_with_input(
+        "fixed",
+        &(&origins, &destinations),
+        |b, (origins, destinations)| {
+            b.iter(|| {
+                ruler.fixed(black_box(&origin[1])).distance_matrix(
+                    black_box(origins),
+                    black_box(destinations),
+                    &mut out,
+                )
+            });
+        },
+    );
+
+    g.finish();
+}
+
+/// Compares distances from a moving origin measured with `CheapRuler::distance` against
+/// a `FixedRuler` covering the whole city.
+///
+/// The origin follows a vehicle across the city, measuring to a cloud of points of
+/// interest at every step. Its latitude changes on every step, so `CheapRuler` computes
+/// the scaling for every pair, even with `memoize`, while the fixed ruler reuses the
+/// scaling at the mean latitude of the points.
+pub fn moving_origin_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("cheap-ruler-moving-origin");
+
+    let center = [-73.9857, 40.7484];
+    let points = generate_test_data(&center, 1021);
+
+    let ruler = CheapRuler::<f32>::WGS84();
+
+    // a drive of about 10 km heading north-north-east, a position every 100 meters
+    let track: Vec<[f32; 2]> = (0..101)
+        .map(|i| ruler.destination(&[-74.0134, 40.7033], &20., &(i as f32 * 100.)))
+        .collect();
+
+    let mut out = vec![0.; points.len()];
+
+    g.bench_with_input("cheap", &(&track, &points), |b, (track, points)| {
+        b.iter(|| {
+            for origin in black_box(track).iter() {
+                for (point, distance) in black_box(points).iter().zip(out.iter_mut()) {
+                    *distance = ruler.distance(origin, point);
+                }
+            }
+        });
+    });
+
+    g.bench_with_input("fixed", &(&track, &points), |b, (track, points)| {
+        b.iter(|| {
+            let city = ruler.for_region(black_box(points)).unwrap();
+
+            for origin in black_box(track).iter() {
+                for (point, distance) in black_box(points).iter().zip(out.iter_mut()) {
+                    *distance = city.distance(origin, point);
+                }
+            }
+        });
+    });
+
+    g.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark,
+    batch_benchmark,
+    radius_filter_benchmark,
+    repeated_origin_benchmark,
+    distance_matrix_benchmark,
+    moving_origin_benchmark
+);
 criterion_main!(benches);
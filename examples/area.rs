@@ -0,0 +1,27 @@
+//! This example demonstrates how to calculate the area of a polygon without the geo crate,
+//! using plain `[longitude, latitude]` arrays and the `CheapRuler` directly.
+
+use geo_ruler::{CheapRuler, Units};
+
+/// Square feet in an acre.
+const SQUARE_FEET_PER_ACRE: f32 = 43_560.;
+
+fn main() {
+    // Bryant Park in New York City (longitude, latitude in degrees)
+    let bryant_park: [[f32; 2]; 4] = [
+        [-73.9853, 40.7545], // 42nd St & 6th Ave
+        [-73.9818, 40.7531], // 42nd St, New York Public Library
+        [-73.9829, 40.7516], // 40th St, New York Public Library
+        [-73.9865, 40.7530], // 40th St & 6th Ave
+    ];
+
+    // Areas are measured in the square of the ruler units, meters by default
+    let ruler = CheapRuler::WGS84();
+    let square_meters = ruler.area(&[&bryant_park]);
+
+    // A ruler measuring in feet gives square feet, which convert to acres
+    let square_feet = ruler.with_units(Units::Feet).area(&[&bryant_park]);
+    let acres = square_feet / SQUARE_FEET_PER_ACRE;
+
+    println!("Area of Bryant Park: {square_meters:.0} square meters ({acres:.2} acres)");
+}
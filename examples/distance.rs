@@ -1,5 +1,5 @@
 //! This example demonstrates how to calculate the distance between two geographic points
-//! using the fast Ruler implementation, which is optimized for city-scale distances.
+//! using the fast `RulerMeasure`, which is optimized for city-scale distances.
 
 use geo::{Distance, point};
 use geo_ruler::geo::RulerMeasure;
@@ -10,7 +10,7 @@ fn main() {
     let flatiron = point!(x: -73.9897, y: 40.7411); // Flatiron Building
 
     // Calculate the distance between the two points using the WGS84 ellipsoid model
-    // The Ruler implementation provides fast approximation suitable for city-scale distances
+    // The `RulerMeasure` provides a fast approximation suitable for city-scale distances
     let distance = RulerMeasure::WGS84().distance(empire_state, flatiron);
 
     println!("Distance from Empire State Building to Flatiron Building: {distance:.1} meters");
@@ -0,0 +1,47 @@
+//! This example walks through the `geo` trait API of `RulerMeasure` end to end: distance,
+//! bearing, destination and interpolation, all on `geo::Point`s.
+
+use geo::{Bearing, Destination, Distance, InterpolatePoint, point};
+use geo_ruler::geo::RulerMeasure;
+
+fn main() {
+    // Define our two landmarks in New York City (longitude, latitude in degrees)
+    let empire_state = point!(x: -73.9857, y: 40.7484); // Empire State Building
+    let flatiron = point!(x: -73.9897, y: 40.7411); // Flatiron Building
+
+    // The measure is used like any other geo measure, e.g. Haversine or Geodesic
+    let ruler = RulerMeasure::WGS84();
+
+    let distance = ruler.distance(empire_state, flatiron);
+    let bearing = ruler.bearing(empire_state, flatiron);
+
+    println!(
+        "From Empire State Building to Flatiron Building: {distance:.1} meters at {bearing:.1}°"
+    );
+
+    // Going the same distance in the same direction arrives at the Flatiron Building,
+    // up to the approximation of the bearing
+    let arrival = ruler.destination(empire_state, bearing, distance);
+
+    println!(
+        "Destination: lat: {:.4}, lon: {:.4}, {:.2} meters off",
+        arrival.y(),
+        arrival.x(),
+        ruler.distance(arrival, flatiron)
+    );
+
+    // Halfway between the two landmarks, and 100 meters along the way
+    let midpoint = ruler.point_at_ratio_between(empire_state, flatiron, 0.5);
+    let first_block = ruler.point_at_distance_between(empire_state, flatiron, 100.);
+
+    println!(
+        "Midpoint: lat: {:.4}, lon: {:.4}",
+        midpoint.y(),
+        midpoint.x()
+    );
+    println!(
+        "100 meters along: lat: {:.4}, lon: {:.4}",
+        first_block.y(),
+        first_block.x()
+    );
+}
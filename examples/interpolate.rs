@@ -1,5 +1,5 @@
 //! This example demonstrates how to generate evenly spaced points
-//! along a line between two landmarks in New York City using the Ruler.
+//! along a line between two landmarks in New York City using the `RulerMeasure`.
 
 use geo::{InterpolatePoint, point};
 use geo_ruler::geo::RulerMeasure;
@@ -1,5 +1,5 @@
 //! This example demonstrates how to calculate the distance between two geographic points
-//! using the fast Ruler implementation, which is optimized for city-scale distances.
+//! using the fast `RulerMeasure`, which is optimized for city-scale distances.
 
 use geo::{Distance, point};
 use geo_ruler::geo::RulerMeasure;
@@ -14,11 +14,11 @@ fn main() {
     let mars_equatorial_radius = 3_396_200.;
     let mars_polar_radius = 3_376_200.;
 
-    // Create a Ruler instance for Mars using its equatorial and polar radii
+    // Create a `RulerMeasure` for Mars using its equatorial and polar radii
     // This allows us to calculate distances on the Martian surface accurately
     let mars_ruler = RulerMeasure::new(mars_equatorial_radius, mars_polar_radius);
 
-    // Calculate the distance between the two points using the Mars Ruler instance
+    // Calculate the distance between the two points using the Mars ruler
     let distance = mars_ruler.distance(olympus_mons, karzok_crater);
 
     println!("Distance from Olympus Mons to Karzok Crater on Mars: {distance:.1} meters");
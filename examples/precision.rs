@@ -0,0 +1,32 @@
+//! This example demonstrates how single precision coordinates quantize distances
+//! between nearby points, and how measuring in double precision avoids it.
+
+use geo::{Coord, Distance, MapCoords, Point, point};
+use geo_ruler::geo::{RulerMeasure, promote};
+
+fn main() {
+    // Two points roughly half a meter apart, right next to the antimeridian
+    let a = point!(x: 179.999_900, y: 0.);
+    let b = point!(x: 179.999_905, y: 0.);
+
+    // Measuring the original double precision coordinates
+    let double = RulerMeasure::<f64>::WGS84().distance(a, b);
+
+    // Storing the same coordinates as f32 rounds them to ~1.7 m steps at this longitude
+    let to_f32 = |p: Point<f64>| {
+        p.map_coords(|Coord { x, y }| Coord {
+            x: x as f32,
+            y: y as f32,
+        })
+    };
+    let (a32, b32) = (to_f32(a), to_f32(b));
+
+    let single = RulerMeasure::<f32>::WGS84().distance(a32, b32);
+
+    // Promoting the f32 points lets the ruler run in f64, but the rounding already happened
+    let promoted = RulerMeasure::<f64>::WGS84().distance(promote(&a32), promote(&b32));
+
+    println!("f64 coordinates:          {double:.3} meters");
+    println!("f32 coordinates:          {single:.3} meters");
+    println!("f32 promoted to f64:      {promoted:.3} meters");
+}
@@ -1,6 +1,9 @@
-use criterion::{Criterion, criterion_group, criterion_main};
+use approx::assert_relative_eq;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use geo::LineString;
+use geo::line_measures::FrechetDistance;
 use geo_ruler::CheapRuler;
-use simd_ruler;
+use geo_ruler::geo::RulerMeasure;
 use std::hint::black_box;
 
 fn generate_test_data(size: usize) -> Vec<[f32; 2]> {
@@ -33,16 +36,49 @@ pub fn benchmark(c: &mut Criterion) {
 
     let points = [&lats[..], &lons[..]];
 
+    // guard against chunk stepping regressions, every segment must be summed: one
+    // missing segment is about 0.1% of the total, well above the summation error
+    let segment_sum: f32 = (1..data.len())
+        .map(|i| simd_ruler::length(&[&lats[i - 1..=i], &lons[i - 1..=i]]))
+        .sum();
+
+    assert_relative_eq!(
+        simd_ruler::length(&points),
+        segment_sum,
+        max_relative = 1e-5
+    );
+
+    #[cfg(all(feature = "neon", target_arch = "aarch64"))]
+    assert_eq!(
+        simd_ruler::neon::length(&points),
+        simd_ruler::length(&points)
+    );
+
+    // report throughput in segments per second, comparable across groups
+    let segments = Throughput::Elements(data.len() as u64 - 1);
+    g.throughput(segments.clone());
+
     g.bench_with_input("length", &points, |b, points| {
         b.iter(|| simd_ruler::length(black_box(points)))
     });
 
+    g.bench_with_input("length_fast", &points, |b, points| {
+        b.iter(|| simd_ruler::length_fast(black_box(points)))
+    });
+
+    #[cfg(all(feature = "neon", target_arch = "aarch64"))]
+    g.bench_with_input("length_neon", &points, |b, points| {
+        b.iter(|| simd_ruler::neon::length(black_box(points)))
+    });
+
     g.finish();
 
     let mut g = c.benchmark_group("iter");
 
     let points: Vec<&[f32; 2]> = data.iter().collect();
 
+    g.throughput(segments);
+
     let ruler = CheapRuler::WGS84();
 
     g.bench_with_input("length", &points, |b, points| {
@@ -61,5 +97,92 @@ pub fn benchmark(c: &mut Criterion) {
     g.finish();
 }
 
-criterion_group!(benches, benchmark);
+pub fn distances_benchmark(c: &mut Criterion) {
+    let origin = [-73.9857, 40.7484];
+
+    // the same prime number of points as the scalar batch benchmark, `generate_test_data`
+    // yields `[latitude, longitude]` pairs
+    let data: Vec<[f32; 2]> = generate_test_data(4093)
+        .into_iter()
+        .map(|[lat, lon]| [lon, lat])
+        .collect();
+
+    let lons: Vec<f32> = data.iter().map(|p| p[0]).collect();
+    let lats: Vec<f32> = data.iter().map(|p| p[1]).collect();
+
+    let ruler = CheapRuler::WGS84();
+
+    let mut out = vec![0.; data.len()];
+
+    let mut g = c.benchmark_group("distances");
+
+    g.bench_with_input("naive", &data, |b, data| {
+        b.iter(|| {
+            for (destination, distance) in black_box(data).iter().zip(out.iter_mut()) {
+                *distance = ruler.distance(black_box(&origin), destination);
+            }
+        })
+    });
+
+    g.bench_with_input("batch", &data, |b, data| {
+        b.iter(|| ruler.distance_to_many(black_box(&origin), black_box(data), &mut out))
+    });
+
+    let points = [&lons[..], &lats[..]];
+
+    g.bench_with_input("simd", &points, |b, points| {
+        b.iter(|| simd_ruler::distances_from(black_box(origin), black_box(points), &mut out))
+    });
+
+    g.finish();
+}
+
+pub fn frechet_benchmark(c: &mut Criterion) {
+    // a route and a noisy trace of it, both a few hundred points long
+    let route: Vec<[f32; 2]> = generate_test_data(307)
+        .into_iter()
+        .map(|[lat, lon]| [lon, lat])
+        .collect();
+    let trace: Vec<[f32; 2]> = route
+        .iter()
+        .enumerate()
+        .map(|(i, [lon, lat])| [lon + 0.0001 * (i % 5) as f32, lat - 0.0001 * (i % 3) as f32])
+        .collect();
+
+    let line_string = |points: &[[f32; 2]]| -> LineString<f32> {
+        points.iter().map(|&[lon, lat]| (lon, lat)).collect()
+    };
+    let columns = |points: &[[f32; 2]]| -> [Vec<f32>; 2] {
+        [
+            points.iter().map(|p| p[0]).collect(),
+            points.iter().map(|p| p[1]).collect(),
+        ]
+    };
+
+    let ruler = RulerMeasure::<f32>::WGS84();
+
+    let mut g = c.benchmark_group("frechet");
+
+    let lines = (line_string(&route), line_string(&trace));
+
+    g.bench_with_input("geo", &lines, |b, (route, trace)| {
+        b.iter(|| ruler.frechet_distance(black_box(route), black_box(trace)))
+    });
+
+    let [route_lons, route_lats] = columns(&route);
+    let [trace_lons, trace_lats] = columns(&trace);
+
+    let points = (
+        [&route_lons[..], &route_lats[..]],
+        [&trace_lons[..], &trace_lats[..]],
+    );
+
+    g.bench_with_input("simd", &points, |b, (route, trace)| {
+        b.iter(|| simd_ruler::frechet_distance(black_box(route), black_box(trace)))
+    });
+
+    g.finish();
+}
+
+criterion_group!(benches, benchmark, distances_benchmark, frechet_benchmark);
 criterion_main!(benches);
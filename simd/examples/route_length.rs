@@ -0,0 +1,69 @@
+//! This example demonstrates how to measure the length of a route stored as GeoJSON
+//! using the SIMD accelerated ruler, and compares it with the scalar `CheapRuler`.
+
+use geo_ruler::CheapRuler;
+
+// A walk along Broadway from Union Square to Central Park, as a GeoJSON feature
+const ROUTE: &str = r#"{
+    "type": "Feature",
+    "properties": { "name": "Broadway walk" },
+    "geometry": {
+        "type": "LineString",
+        "coordinates": [
+            [-73.9903, 40.7359], [-73.9897, 40.7411], [-73.9880, 40.7433],
+            [-73.9871, 40.7450], [-73.9880, 40.7484], [-73.9876, 40.7505],
+            [-73.9867, 40.7527], [-73.9855, 40.7580], [-73.9850, 40.7597],
+            [-73.9845, 40.7614], [-73.9838, 40.7632], [-73.9832, 40.7648],
+            [-73.9826, 40.7664], [-73.9819, 40.7681]
+        ]
+    }
+}"#;
+
+/// Extracts the `[longitude, latitude]` pairs of a GeoJSON `LineString`.
+///
+/// This is a deliberately tiny parser that only understands the `coordinates`
+/// member of a single `LineString`, enough to avoid pulling in a JSON dependency.
+fn parse_coordinates(geojson: &str) -> Vec<[f32; 2]> {
+    let start = geojson
+        .find("\"coordinates\"")
+        .expect("missing coordinates");
+    let body = &geojson[start..];
+
+    let open = body.find('[').expect("missing coordinates array") + 1;
+    let close = body.rfind(']').expect("unterminated coordinates array");
+
+    body[open..close]
+        .split(']')
+        .filter_map(|pair| {
+            let mut values = pair
+                .trim_start_matches([',', ' ', '\n', '['])
+                .split(',')
+                .filter_map(|value| value.trim().parse::<f32>().ok());
+
+            Some([values.next()?, values.next()?])
+        })
+        .collect()
+}
+
+fn main() {
+    let points = parse_coordinates(ROUTE);
+
+    // Split the interleaved coordinates into the structure-of-arrays layout used by SIMD
+    let (lons, lats): (Vec<f32>, Vec<f32>) = points.iter().map(|p| (p[0], p[1])).unzip();
+
+    let simd_length = simd_ruler::length(&[&lons[..], &lats[..]]);
+
+    // Sum the same route one segment at a time with the scalar ruler
+    let ruler = CheapRuler::WGS84();
+    let scalar_length: f32 = points
+        .windows(2)
+        .map(|segment| ruler.distance(&segment[0], &segment[1]))
+        .sum();
+
+    let difference = (simd_length - scalar_length).abs() / scalar_length;
+
+    println!("Route with {} points", points.len());
+    println!("SIMD length:   {simd_length:.1} meters");
+    println!("Scalar length: {scalar_length:.1} meters");
+    println!("Relative difference: {:.4}%", difference * 100.);
+}
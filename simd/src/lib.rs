@@ -4,9 +4,17 @@
 //!
 //! Provides vectorized implementations of geospatial operations processing multiple
 //! coordinate pairs simultaneously with an ellipsoidal Earth model.
+//!
+//! Longitude differences are wrapped across the antimeridian, so the results match a
+//! `CheapRuler` in its default `AntimeridianMode::Wrap`. There is no raw mode here.
 
 use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
-use wide::{CmpGt, CmpLt, f32x4};
+use wide::{CmpGt, CmpLe, CmpLt};
+
+pub use wide::f32x4;
+
+#[cfg(all(feature = "neon", target_arch = "aarch64"))]
+pub mod neon;
 
 const N: usize = 4;
 
@@ -28,30 +36,19 @@ where
     [kx, ky]
 }
 
+/// Loads up to `N` consecutive values starting at `offset` into a vector.
+///
+/// Lanes past the end of the slice are filled with zeros, so the same safe load is used
+/// for full chunks and for the ragged tail, on every architecture.
 #[inline(always)]
-unsafe fn read(s: &[f32], offset: usize) -> f32x4 {
-    #[cfg(target_arch = "aarch64")]
-    return unsafe { std::ptr::read(s.as_ptr().add(offset).cast::<f32x4>()) };
+fn load(s: &[f32], offset: usize) -> f32x4 {
+    let tail = s.get(offset..).unwrap_or_default();
+    let len = tail.len().min(N);
 
-    #[cfg(not(target_arch = "aarch64"))]
-    return read_safe(s, offset);
-}
-
-#[inline(always)]
-fn read_safe(s: &[f32], offset: usize) -> f32x4 {
-    if (offset + N) < s.len() {
-        f32x4::new(s[offset..(offset + N)].try_into().unwrap())
-    } else {
-        let mut m = [0.; N];
-
-        let mut j = 0;
-        for i in offset..s.len() {
-            m[j] = s[i];
-            j += 1;
-        }
+    let mut lanes = [0.; N];
+    lanes[..len].copy_from_slice(&tail[..len]);
 
-        f32x4::new(m)
-    }
+    f32x4::new(lanes)
 }
 
 /// Calculates the total length of a polyline using SIMD vectorization.
@@ -59,7 +56,9 @@ fn read_safe(s: &[f32], offset: usize) -> f32x4 {
 /// Processes multiple coordinate pairs simultaneously using the `wide` crate
 /// with an ellipsoidal Earth model.
 ///
-/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`.
+/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`, both
+/// of the same length. Mismatched arrays panic in debug builds, in release builds the
+/// longer one is truncated.
 ///
 /// Returns the total length in meters.
 ///
@@ -72,7 +71,14 @@ fn read_safe(s: &[f32], offset: usize) -> f32x4 {
 /// let distance = length(&points);
 /// ```
 pub fn length(points: &[&[f32]; 2]) -> f32 {
-    let n = points[0].len();
+    debug_assert_eq!(
+        points[0].len(),
+        points[1].len(),
+        "longitude and latitude arrays differ in length"
+    );
+
+    let n = points[0].len().min(points[1].len());
+    let points = [&points[0][..n], &points[1][..n]];
 
     if n < 2 {
         return 0.;
@@ -82,9 +88,9 @@ pub fn length(points: &[&[f32]; 2]) -> f32 {
 
     let num_chunks = (n - 1) / N;
 
-    for offset in (0..num_chunks).step_by(N) {
-        let origins = unsafe { [read(points[0], offset), read(points[1], offset)] };
-        let destinations = unsafe { [read(points[0], 1 + offset), read(points[1], 1 + offset)] };
+    for offset in (0..num_chunks * N).step_by(N) {
+        let origins = [load(points[0], offset), load(points[1], offset)];
+        let destinations = [load(points[0], 1 + offset), load(points[1], 1 + offset)];
 
         total_length += distance(&origins, &destinations).reduce_add();
     }
@@ -94,8 +100,8 @@ pub fn length(points: &[&[f32]; 2]) -> f32 {
 
     if rem_pairs > 0 {
         let offset = num_chunks * N;
-        let origins = unsafe { [read(points[0], offset), read(points[1], offset)] };
-        let destinations = unsafe { [read(points[0], 1 + offset), read(points[1], 1 + offset)] };
+        let origins = [load(points[0], offset), load(points[1], offset)];
+        let destinations = [load(points[0], 1 + offset), load(points[1], 1 + offset)];
 
         let mask = f32x4::splat(rem_pairs as f32).cmp_gt(f32x4::new([0., 1., 2., 3.]));
 
@@ -107,40 +113,602 @@ pub fn length(points: &[&[f32]; 2]) -> f32 {
     total_length
 }
 
-#[inline(always)]
-fn distance(origin: &[f32x4; 2], destination: &[f32x4; 2]) -> f32x4 {
+/// Calculates the total length of a polyline using SIMD vectorization, with a single scaling.
+///
+/// The latitude-dependent scaling is computed once at the mean latitude of the polyline
+/// and broadcast to all lanes, so no trigonometry is computed in the vectorized loop.
+/// This is faster than [`length`] and accurate for polylines spanning a small range of
+/// latitudes, such as city routes.
+///
+/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`.
+///
+/// Returns the total length in meters.
+///
+/// ```rust
+/// # use simd_ruler::length_fast;
+/// let lons = [-73.9857, -73.9897, -73.9927];
+/// let lats = [40.7484, 40.7411, 40.7394];
+///
+/// let points = [&lons[..], &lats[..]];
+/// let distance = length_fast(&points);
+/// ```
+pub fn length_fast(points: &[&[f32]; 2]) -> f32 {
+    let n = points[0].len().min(points[1].len());
+
+    if n < 2 {
+        return 0.;
+    }
+
+    let lats = &points[1][..n];
+
+    let mut lat_sum = f32x4::splat(0.);
+
+    for offset in (0..n).step_by(N) {
+        lat_sum += load(lats, offset);
+    }
+
+    let mean_lat = f32x4::splat(lat_sum.reduce_add() / n as f32);
+    let coefs = coefs(&mean_lat);
+
+    let mut total_length = f32x4::splat(0.);
+
+    for offset in (0..n - 1).step_by(N) {
+        let origins = [load(points[0], offset), load(lats, offset)];
+        let destinations = [load(points[0], 1 + offset), load(lats, 1 + offset)];
+
+        let pairs = f32x4::splat((n - 1 - offset) as f32);
+        let mask = pairs.cmp_gt(f32x4::new([0., 1., 2., 3.]));
+
+        total_length += mask.blend(
+            distance_with(&coefs, &origins, &destinations),
+            f32x4::splat(0.),
+        );
+    }
+
+    total_length.reduce_add()
+}
+
+/// Calculates the area and the perimeter of a polygon ring in one pass using SIMD vectorization.
+///
+/// The latitude-dependent scaling is computed once at the mean latitude of the ring and
+/// the vertices are projected around the first one. The per-edge deltas are then shared
+/// by the shoelace sum for the area and the distance sum for the perimeter.
+///
+/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`. The
+/// ring may be closed or not, the closing edge back to the first vertex is always added.
+///
+/// Returns the area in square meters, regardless of the winding order, and the perimeter
+/// in meters.
+///
+/// ```rust
+/// # use simd_ruler::polygon_metrics;
+/// // Bryant Park, New York
+/// let lons = [-73.9853, -73.9818, -73.9829, -73.9865];
+/// let lats = [40.7545, 40.7531, 40.7516, 40.7530];
+///
+/// let (area, perimeter) = polygon_metrics(&[&lons[..], &lats[..]]);
+/// ```
+pub fn polygon_metrics(ring: &[&[f32]; 2]) -> (f32, f32) {
+    let n = ring[0].len().min(ring[1].len());
+
+    if n < 2 {
+        return (0., 0.);
+    }
+
+    let lons = &ring[0][..n];
+    let lats = &ring[1][..n];
+
+    let mut lat_sum = f32x4::splat(0.);
+
+    for offset in (0..n).step_by(N) {
+        lat_sum += load(lats, offset);
+    }
+
+    let [kx, ky] = coefs(&f32x4::splat(lat_sum.reduce_add() / n as f32));
+
+    let first = [f32x4::splat(lons[0]), f32x4::splat(lats[0])];
+
+    let mut twice_area = f32x4::splat(0.);
+    let mut perimeter = f32x4::splat(0.);
+
+    for offset in (0..n - 1).step_by(N) {
+        let x0 = delta_lon(&first[0], &load(lons, offset)) * kx;
+        let y0 = (load(lats, offset) - first[1]) * ky;
+
+        let dx = delta_lon(&first[0], &load(lons, 1 + offset)) * kx - x0;
+        let dy = (load(lats, 1 + offset) - first[1]) * ky - y0;
+
+        let pairs = f32x4::splat((n - 1 - offset) as f32);
+        let mask = pairs.cmp_gt(f32x4::new([0., 1., 2., 3.]));
+
+        twice_area += mask.blend(x0 * dy - y0 * dx, f32x4::splat(0.));
+        perimeter += mask.blend(((dx * dx) + (dy * dy)).sqrt(), f32x4::splat(0.));
+    }
+
+    // the closing edge ends at the first vertex, the origin of the projection, so it
+    // adds nothing to the shoelace sum
+    let x = (delta_lon(&first[0], &f32x4::splat(lons[n - 1])) * kx).to_array()[0];
+    let y = (lats[n - 1] - lats[0]) * ky.to_array()[0];
+
+    let area = twice_area.reduce_add().abs() / 2.;
+    let perimeter = perimeter.reduce_add() + (x * x + y * y).sqrt();
+
+    (area, perimeter)
+}
+
+/// Calculates the total signed turning along a track using SIMD vectorization.
+///
+/// The segment bearings are computed four at a time with the vectorized `atan2`, then
+/// the turns between consecutive bearings are summed over sliding windows, blending
+/// them into (-180°, 180°]. Zero-length segments, e.g. repeated GPS fixes, have no
+/// heading and are skipped. If the track is closed, its first and last points being
+/// equal, the turn where it closes is included as well.
+///
+/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`.
+///
+/// Returns the total turning in degrees, about ±360° for a loop, positive clockwise.
+///
+/// ```rust
+/// # use simd_ruler::total_turning;
+/// // around the block of the Empire State Building, clockwise
+/// let lons = [-73.9863, -73.9848, -73.9842, -73.9857, -73.9863];
+/// let lats = [40.7479, 40.7486, 40.7478, 40.7471, 40.7479];
+///
+/// let turning = total_turning(&[&lons[..], &lats[..]]);
+/// assert!((turning - 360.).abs() < 1.);
+/// ```
+pub fn total_turning(points: &[&[f32]; 2]) -> f32 {
+    let n = points[0].len().min(points[1].len());
+
+    if n < 3 {
+        return 0.;
+    }
+
+    let [lons, lats] = [&points[0][..n], &points[1][..n]];
+
+    let mut bearings = vec![0f32; n - 1];
+
+    for offset in (0..n - 1).step_by(N) {
+        let origins = [load(lons, offset), load(lats, offset)];
+        let destinations = [load(lons, 1 + offset), load(lats, 1 + offset)];
+
+        let lanes = (n - 1 - offset).min(N);
+
+        bearings[offset..(offset + lanes)]
+            .copy_from_slice(&bearing(&origins, &destinations).to_array()[..lanes]);
+    }
+
+    let mut segment = 0;
+    bearings.retain(|_| {
+        segment += 1;
+        lons[segment - 1] != lons[segment] || lats[segment - 1] != lats[segment]
+    });
+
+    let m = bearings.len();
+
+    if m == 0 {
+        return 0.;
+    }
+
+    let mut total = f32x4::splat(0.);
+
+    for offset in (0..m - 1).step_by(N) {
+        let turns = turn(&load(&bearings, offset), &load(&bearings, 1 + offset));
+
+        let pairs = f32x4::splat((m - 1 - offset) as f32);
+        let mask = pairs.cmp_gt(f32x4::new([0., 1., 2., 3.]));
+
+        total += mask.blend(turns, f32x4::splat(0.));
+    }
+
+    let mut total = total.reduce_add();
+
+    if lons[0] == lons[n - 1] && lats[0] == lats[n - 1] {
+        let closing = turn(&f32x4::splat(bearings[m - 1]), &f32x4::splat(bearings[0]));
+        total += closing.to_array()[0];
+    }
+
+    total
+}
+
+/// Calculates the distances from one origin to many points using SIMD vectorization.
+///
+/// The latitude-dependent scaling is computed once at the origin and broadcast to all
+/// lanes, so only the per-point deltas are computed in the vectorized loop.
+///
+/// Coordinates are expected in decimal degrees, the origin as `[longitude, latitude]`
+/// and the points as `[longitude_array, latitude_array]`.
+///
+/// Writes the distance in meters to each point into `out`, only the first
+/// `min(points[0].len(), out.len())` distances are written.
+///
+/// ```rust
+/// # use simd_ruler::distances_from;
+/// let lons = [-73.9897, -73.9927, -73.9819];
+/// let lats = [40.7411, 40.7394, 40.7681];
+///
+/// let empire_state = [-73.9857, 40.7484];
+///
+/// let mut distances = [0.; 3];
+/// distances_from(empire_state, &[&lons[..], &lats[..]], &mut distances);
+/// ```
+pub fn distances_from(origin: [f32; 2], points: &[&[f32]; 2], out: &mut [f32]) {
+    let n = points[0].len().min(out.len());
+
+    let origin = [f32x4::splat(origin[0]), f32x4::splat(origin[1])];
+    let coefs = coefs(&origin[1]);
+
+    for offset in (0..n).step_by(N) {
+        let destinations = [load(points[0], offset), load(points[1], offset)];
+
+        let lanes = (n - offset).min(N);
+
+        out[offset..(offset + lanes)]
+            .copy_from_slice(&distance_with(&coefs, &origin, &destinations).to_array()[..lanes]);
+    }
+}
+
+/// Calculates the distances from many origins to many destinations using SIMD vectorization.
+///
+/// Origins are processed four at a time, one per lane, so their latitude-dependent
+/// scalings are computed at once. Each destination is then broadcast to all lanes and
+/// measured against the four origins, filling four rows of the matrix.
+///
+/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`.
+///
+/// Writes the distances in meters row by row into `out`, the distance from origin `i`
+/// to destination `j` at `i * destinations[0].len() + j`. Only the rows fitting
+/// entirely in `out` are written.
+///
+/// ```rust
+/// # use simd_ruler::distance_matrix;
+/// let station_lons = [-73.9855, -73.9772];
+/// let station_lats = [40.7580, 40.7527];
+///
+/// let venue_lons = [-73.9857, -73.9934, -73.9787];
+/// let venue_lats = [40.7484, 40.7505, 40.7587];
+///
+/// let mut distances = [0.; 6];
+/// distance_matrix(
+///     &[&station_lons[..], &station_lats[..]],
+///     &[&venue_lons[..], &venue_lats[..]],
+///     &mut distances,
+/// );
+/// ```
+pub fn distance_matrix(origins: &[&[f32]; 2], destinations: &[&[f32]; 2], out: &mut [f32]) {
+    let m = destinations[0].len().min(destinations[1].len());
+
+    if m == 0 {
+        return;
+    }
+
+    let n = origins[0].len().min(origins[1].len()).min(out.len() / m);
+
+    for offset in (0..n).step_by(N) {
+        let origins = [load(origins[0], offset), load(origins[1], offset)];
+        let coefs = coefs(&origins[1]);
+
+        let lanes = (n - offset).min(N);
+
+        for (j, destination) in destinations[0]
+            .iter()
+            .zip(destinations[1])
+            .take(m)
+            .enumerate()
+        {
+            let destination = [f32x4::splat(*destination.0), f32x4::splat(*destination.1)];
+
+            let distances = distance_with(&coefs, &origins, &destination).to_array();
+
+            for (lane, distance) in distances[..lanes].iter().enumerate() {
+                out[(offset + lane) * m + j] = *distance;
+            }
+        }
+    }
+}
+
+/// Calculates the discrete Fréchet distance between two polylines using SIMD vectorization.
+///
+/// Follows the dynamic programming formulation of Eiter and Mannila, keeping only the
+/// previous row of the coupling table. Each row of pointwise distances, from one vertex
+/// of `a` to every vertex of `b`, is computed with [`distances_from`].
+///
+/// Coordinates are expected in decimal degrees `[longitude_array, latitude_array]`.
+///
+/// Returns the Fréchet distance in meters, zero if either polyline is empty.
+///
+/// ```rust
+/// # use simd_ruler::frechet_distance;
+/// let route_lons = [-73.9897, -73.9857, -73.9855];
+/// let route_lats = [40.7411, 40.7484, 40.7580];
+///
+/// let trace_lons = [-73.9899, -73.9871, -73.9856, -73.9853];
+/// let trace_lats = [40.7412, 40.7449, 40.7485, 40.7579];
+///
+/// let distance = frechet_distance(
+///     &[&route_lons[..], &route_lats[..]],
+///     &[&trace_lons[..], &trace_lats[..]],
+/// );
+/// ```
+pub fn frechet_distance(a: &[&[f32]; 2], b: &[&[f32]; 2]) -> f32 {
+    let n = a[0].len().min(a[1].len());
+    let m = b[0].len().min(b[1].len());
+
+    if n == 0 || m == 0 {
+        return 0.;
+    }
+
+    let mut distances = vec![0f32; m];
+    let mut prev = vec![0f32; m];
+    let mut curr = vec![0f32; m];
+
+    // plain comparisons, distances are never NaN for valid coordinates
+    let max = |a: f32, b: f32| if a > b { a } else { b };
+    let min = |a: f32, b: f32| if a < b { a } else { b };
+
+    for (i, origin) in a[0].iter().zip(a[1]).take(n).enumerate() {
+        distances_from([*origin.0, *origin.1], b, &mut distances);
+
+        if i == 0 {
+            curr[0] = distances[0];
+
+            for j in 1..m {
+                curr[j] = max(distances[j], curr[j - 1]);
+            }
+        } else {
+            curr[0] = max(distances[0], prev[0]);
+
+            for j in 1..m {
+                curr[j] = max(distances[j], min(min(prev[j], prev[j - 1]), curr[j - 1]));
+            }
+        }
+
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m - 1]
+}
+
+/// Finds the indices of all the points within a given distance from a center point.
+///
+/// The latitude-dependent scaling is computed once at the center and broadcast to all
+/// lanes. Squared distances are compared against the squared radius, so no square
+/// roots are computed.
+///
+/// Coordinates are expected in decimal degrees, the center as `[longitude, latitude]`
+/// and the points as `[longitude_array, latitude_array]`.
+///
+/// Returns the indices, in ascending order, of the points at most `radius` meters away.
+///
+/// ```rust
+/// # use simd_ruler::within_radius;
+/// let lons = [-73.9897, -73.9927, -73.9819];
+/// let lats = [40.7411, 40.7394, 40.7681];
+///
+/// let empire_state = [-73.9857, 40.7484];
+///
+/// let nearby = within_radius(empire_state, 1_000., &[&lons[..], &lats[..]]);
+/// assert_eq!(nearby, vec![0]);
+/// ```
+pub fn within_radius(center: [f32; 2], radius: f32, points: &[&[f32]; 2]) -> Vec<u32> {
+    let n = points[0].len();
+
+    let center = [f32x4::splat(center[0]), f32x4::splat(center[1])];
+    let [kx, ky] = coefs(&center[1]);
+
+    let radius_sq = f32x4::splat(radius * radius);
+
+    let mut indices = Vec::new();
+
+    for offset in (0..n).step_by(N) {
+        let dx = delta_lon(&center[0], &load(points[0], offset)) * kx;
+        let dy = (load(points[1], offset) - center[1]) * ky;
+
+        let mask = ((dx * dx) + (dy * dy)).cmp_le(radius_sq).move_mask();
+
+        let lanes = (n - offset).min(N);
+
+        indices.extend(
+            (0..lanes)
+                .filter(|lane| mask & (1 << lane) != 0)
+                .map(|lane| (offset + lane) as u32),
+        );
+    }
+
+    indices
+}
+
+/// Calculates the distances and bearings from one origin to many points using SIMD vectorization.
+///
+/// The latitude-dependent scaling is computed once at the origin and broadcast to all
+/// lanes, and the per-point deltas are shared by both outputs. Bearings use a fast
+/// polynomial approximation of `atan2`.
+///
+/// Coordinates are expected in decimal degrees, the origin as `[longitude, latitude]`
+/// and the points as `[longitude_array, latitude_array]`.
+///
+/// Writes the distance in meters to each point into `out_dist`, and the bearing in
+/// degrees (0° = North, 90° = East) into `out_bearing`. Only the first
+/// `min(points[0].len(), out_dist.len(), out_bearing.len())` values are written.
+///
+/// ```rust
+/// # use simd_ruler::vectors_from;
+/// let lons = [-73.9897, -73.9927, -73.9819];
+/// let lats = [40.7411, 40.7394, 40.7681];
+///
+/// let depot = [-73.9857, 40.7484];
+///
+/// let mut distances = [0.; 3];
+/// let mut bearings = [0.; 3];
+/// vectors_from(depot, &[&lons[..], &lats[..]], &mut distances, &mut bearings);
+/// ```
+pub fn vectors_from(
+    origin: [f32; 2],
+    points: &[&[f32]; 2],
+    out_dist: &mut [f32],
+    out_bearing: &mut [f32],
+) {
+    let n = points[0].len().min(out_dist.len()).min(out_bearing.len());
+
+    let origin = [f32x4::splat(origin[0]), f32x4::splat(origin[1])];
     let [kx, ky] = coefs(&origin[1]);
 
-    let dx = (destination[0] - origin[0]) * kx;
+    for offset in (0..n).step_by(N) {
+        let dx = delta_lon(&origin[0], &load(points[0], offset)) * kx;
+        let dy = (load(points[1], offset) - origin[1]) * ky;
+
+        let lanes = (n - offset).min(N);
+
+        out_dist[offset..(offset + lanes)]
+            .copy_from_slice(&((dx * dx) + (dy * dy)).sqrt().to_array()[..lanes]);
+        out_bearing[offset..(offset + lanes)]
+            .copy_from_slice(&atan2(dx, dy).to_degrees().to_array()[..lanes]);
+    }
+}
+
+/// Calculates the destinations of many points, each with its own bearing and distance, using SIMD vectorization.
+///
+/// Bearings and distances are loaded per lane, and the latitude-dependent scaling is
+/// computed at each origin point.
+///
+/// Coordinates are expected in decimal degrees as `[longitude_array, latitude_array]`,
+/// bearings in degrees (0° = North, 90° = East) and distances in meters.
+///
+/// Writes the destination longitudes into `out[0]` and latitudes into `out[1]`. Only the
+/// first `min(points[0].len(), bearings.len(), distances.len(), out[0].len(), out[1].len())`
+/// destinations are written.
+///
+/// ```rust
+/// # use simd_ruler::destinations;
+/// let lons = [-73.9857, -73.9897, -73.9927];
+/// let lats = [40.7484, 40.7411, 40.7394];
+///
+/// let bearings = [0., 90., 225.];
+/// let distances = [100., 250., 1_000.];
+///
+/// let mut dest_lons = [0.; 3];
+/// let mut dest_lats = [0.; 3];
+/// destinations(
+///     &[&lons[..], &lats[..]],
+///     &bearings,
+///     &distances,
+///     [&mut dest_lons, &mut dest_lats],
+/// );
+/// ```
+pub fn destinations(
+    points: &[&[f32]; 2],
+    bearings: &[f32],
+    distances: &[f32],
+    out: [&mut [f32]; 2],
+) {
+    let n = points[0]
+        .len()
+        .min(bearings.len())
+        .min(distances.len())
+        .min(out[0].len())
+        .min(out[1].len());
+
+    let [out_lons, out_lats] = out;
+
+    for offset in (0..n).step_by(N) {
+        let origins = [load(points[0], offset), load(points[1], offset)];
+
+        let [x, y] = destination(&origins, &load(bearings, offset), &load(distances, offset));
+
+        let lanes = (n - offset).min(N);
+
+        out_lons[offset..(offset + lanes)].copy_from_slice(&x.to_array()[..lanes]);
+        out_lats[offset..(offset + lanes)].copy_from_slice(&y.to_array()[..lanes]);
+    }
+}
+
+/// Calculates the distances between four pairs of points at once.
+///
+/// This is the building block behind [`length`], exposed so that custom pipelines can
+/// build their own reductions without reimplementing the latitude-dependent scaling.
+///
+/// Points are laid out as `[lon_lanes, lat_lanes]`, where each lane holds the
+/// coordinates, in decimal degrees, of a different point. Lane `i` of the result is
+/// the distance in meters between the origin and destination in lane `i`.
+///
+/// ```rust
+/// # use simd_ruler::{distance, f32x4};
+/// let origins = [
+///     f32x4::new([-73.9857, -73.9897, -73.9927, -73.9819]),
+///     f32x4::new([40.7484, 40.7411, 40.7394, 40.7681]),
+/// ];
+/// let destinations = [
+///     f32x4::new([-73.9897, -73.9927, -73.9819, -73.9855]),
+///     f32x4::new([40.7411, 40.7394, 40.7681, 40.7580]),
+/// ];
+///
+/// // keep the longest hop instead of summing them
+/// let longest = distance(&origins, &destinations)
+///     .to_array()
+///     .into_iter()
+///     .fold(0., f32::max);
+/// ```
+#[inline(always)]
+pub fn distance(origin: &[f32x4; 2], destination: &[f32x4; 2]) -> f32x4 {
+    distance_with(&coefs(&origin[1]), origin, destination)
+}
+
+#[inline(always)]
+fn distance_with(coefs: &[f32x4; 2], origin: &[f32x4; 2], destination: &[f32x4; 2]) -> f32x4 {
+    let [kx, ky] = *coefs;
+
+    let dx = delta_lon(&origin[0], &destination[0]) * kx;
     let dy = (destination[1] - origin[1]) * ky;
 
     ((dx * dx) + (dy * dy)).sqrt()
 }
 
-#[allow(dead_code)] // not public API yet
+/// Computes the longitude difference in degrees, wrapped across the antimeridian.
 #[inline(always)]
-fn destination(origin: &[f32x4; 2], bearing: &f32, distance: &f32) -> [f32x4; 2] {
-    let [kx, ky] = coefs(&origin[1]);
+fn delta_lon(from: &f32x4, to: &f32x4) -> f32x4 {
+    let delta = *to - *from;
+    let full = f32x4::splat(360.);
+
+    delta
+        .abs()
+        .cmp_gt(f32x4::splat(180.))
+        .blend(delta - full * (delta / full).round(), delta)
+}
 
-    let distance = f32x4::splat(*distance);
+#[inline(always)]
+fn destination(origin: &[f32x4; 2], bearing: &f32x4, distance: &f32x4) -> [f32x4; 2] {
+    let [kx, ky] = coefs(&origin[1]);
 
     let (sin, cos) = bearing.to_radians().sin_cos();
 
-    let x = origin[0] + distance * f32x4::splat(sin) / kx;
-    let y = origin[1] + distance * f32x4::splat(cos) / ky;
+    let x = origin[0] + *distance * sin / kx;
+    let y = origin[1] + *distance * cos / ky;
 
     [x, y]
 }
 
-#[allow(dead_code)] // not public API yet
 #[inline(always)]
 fn bearing(origin: &[f32x4; 2], destination: &[f32x4; 2]) -> f32x4 {
     let [kx, ky] = coefs(&origin[1]);
 
-    let dx = (destination[0] - origin[0]) * kx;
+    let dx = delta_lon(&origin[0], &destination[0]) * kx;
     let dy = (destination[1] - origin[1]) * ky;
 
-    atan2(dy, dx).to_degrees()
+    atan2(dx, dy).to_degrees()
+}
+
+/// Computes the signed change between bearings in degrees, within (-180°, 180°].
+#[inline(always)]
+fn turn(from: &f32x4, to: &f32x4) -> f32x4 {
+    let delta = *to - *from;
+
+    let delta = delta
+        .cmp_gt(f32x4::splat(180.))
+        .blend(delta - f32x4::splat(360.), delta);
+
+    delta
+        .cmp_le(f32x4::splat(-180.))
+        .blend(delta + f32x4::splat(360.), delta)
 }
 
 #[inline(always)]
@@ -173,7 +741,7 @@ fn cos(mut x: f32x4) -> f32x4 {
     // reduce to [0, π/2] using symmetry
     mask = x.cmp_gt(f32x4::splat(PI));
     x = mask.blend(x - f32x4::splat(PI), x);
-    let mut sign = mask.blend(f32x4::splat(1.), f32x4::splat(-1.));
+    let mut sign = mask.blend(f32x4::splat(-1.), f32x4::splat(1.));
 
     mask = x.cmp_gt(f32x4::splat(FRAC_PI_2));
     x = mask.blend(f32x4::splat(PI) - x, x);
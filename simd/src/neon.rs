@@ -0,0 +1,168 @@
+//! Explicit NEON implementation for aarch64.
+//!
+//! Mirrors the portable `wide` implementation operation by operation, written with the
+//! `core::arch::aarch64` intrinsics instead. The results are bit-identical to the portable
+//! path, which makes it a reference to validate the code `wide` generates on ARM.
+//!
+//! Enabled with the `neon` feature, on aarch64 targets only.
+
+use core::arch::aarch64::*;
+use core::f32::consts::{FRAC_PI_2, PI};
+
+use crate::{E2, N, RE};
+
+/// Calculates the total length of a polyline with NEON intrinsics.
+///
+/// Same as [`crate::length`], coordinates are expected in decimal degrees
+/// `[longitude_array, latitude_array]` and the total length is returned in meters.
+///
+/// ```rust
+/// # use simd_ruler::neon;
+/// let lons = [-73.9857, -73.9897, -73.9927];
+/// let lats = [40.7484, 40.7411, 40.7394];
+///
+/// let points = [&lons[..], &lats[..]];
+/// assert_eq!(neon::length(&points), simd_ruler::length(&points));
+/// ```
+pub fn length(points: &[&[f32]; 2]) -> f32 {
+    debug_assert_eq!(
+        points[0].len(),
+        points[1].len(),
+        "longitude and latitude arrays differ in length"
+    );
+
+    let n = points[0].len().min(points[1].len());
+    let points = [&points[0][..n], &points[1][..n]];
+
+    if n < 2 {
+        return 0.;
+    }
+
+    let mut total_length = 0.;
+
+    let num_chunks = (n - 1) / N;
+
+    for offset in (0..num_chunks * N).step_by(N) {
+        let origins = [load(points[0], offset), load(points[1], offset)];
+        let destinations = [load(points[0], 1 + offset), load(points[1], 1 + offset)];
+
+        total_length += reduce_add(distance(&origins, &destinations));
+    }
+
+    // sum remaining pairs, if any
+    let rem_pairs = (n - 1) % N;
+
+    if rem_pairs > 0 {
+        let offset = num_chunks * N;
+        let origins = [load(points[0], offset), load(points[1], offset)];
+        let destinations = [load(points[0], 1 + offset), load(points[1], 1 + offset)];
+
+        let lanes = [0., 1., 2., 3.];
+
+        // SAFETY: NEON is a baseline feature of aarch64 and the loaded array holds `N` lanes
+        let distances = unsafe {
+            let mask = vcgtq_f32(vdupq_n_f32(rem_pairs as f32), vld1q_f32(lanes.as_ptr()));
+            vbslq_f32(mask, distance(&origins, &destinations), vdupq_n_f32(0.))
+        };
+
+        total_length += reduce_add(distances);
+    }
+
+    total_length
+}
+
+/// Loads up to `N` consecutive values starting at `offset`, zero filling past the end.
+#[inline(always)]
+fn load(s: &[f32], offset: usize) -> float32x4_t {
+    let tail = s.get(offset..).unwrap_or_default();
+    let len = tail.len().min(N);
+
+    let mut lanes = [0.; N];
+    lanes[..len].copy_from_slice(&tail[..len]);
+
+    // SAFETY: NEON is a baseline feature of aarch64 and `lanes` holds `N` values
+    unsafe { vld1q_f32(lanes.as_ptr()) }
+}
+
+/// Sums the lanes in order, as `wide` does.
+#[inline(always)]
+fn reduce_add(v: float32x4_t) -> f32 {
+    let mut lanes = [0.; N];
+
+    // SAFETY: NEON is a baseline feature of aarch64 and `lanes` holds `N` values
+    unsafe { vst1q_f32(lanes.as_mut_ptr(), v) };
+
+    lanes.iter().sum()
+}
+
+#[inline(always)]
+fn distance(origin: &[float32x4_t; 2], destination: &[float32x4_t; 2]) -> float32x4_t {
+    // SAFETY: NEON is a baseline feature of aarch64
+    unsafe {
+        let c = cos(vmulq_f32(origin[1], vdupq_n_f32(PI / 180.)));
+
+        let one = vdupq_n_f32(1.);
+        let e2 = vdupq_n_f32(E2);
+
+        let w = vdivq_f32(
+            one,
+            vsubq_f32(one, vmulq_f32(e2, vsubq_f32(one, vmulq_f32(c, c)))),
+        );
+        let k = vmulq_f32(vsqrtq_f32(w), vdupq_n_f32(RE));
+
+        let kx = vmulq_f32(k, c);
+        let ky = vmulq_f32(vmulq_f32(k, w), vsubq_f32(one, e2));
+
+        let dx = vmulq_f32(delta_lon(origin[0], destination[0]), kx);
+        let dy = vmulq_f32(vsubq_f32(destination[1], origin[1]), ky);
+
+        vsqrtq_f32(vaddq_f32(vmulq_f32(dx, dx), vmulq_f32(dy, dy)))
+    }
+}
+
+/// Computes the longitude difference in degrees, wrapped across the antimeridian.
+#[inline(always)]
+fn delta_lon(from: float32x4_t, to: float32x4_t) -> float32x4_t {
+    // SAFETY: NEON is a baseline feature of aarch64
+    unsafe {
+        let delta = vsubq_f32(to, from);
+        let full = vdupq_n_f32(360.);
+
+        let wrapped = vsubq_f32(delta, vmulq_f32(full, vrndnq_f32(vdivq_f32(delta, full))));
+
+        vbslq_f32(vcagtq_f32(delta, vdupq_n_f32(180.)), wrapped, delta)
+    }
+}
+
+#[inline(always)]
+fn cos(mut x: float32x4_t) -> float32x4_t {
+    // SAFETY: NEON is a baseline feature of aarch64
+    unsafe {
+        let zero = vdupq_n_f32(0.);
+        let one = vdupq_n_f32(1.);
+        let pi = vdupq_n_f32(PI);
+
+        // reduce to [0, 2π) using periodicity
+        let mut mask = vcltq_f32(x, zero);
+        x = vbslq_f32(mask, vaddq_f32(x, vdupq_n_f32(2. * PI)), x);
+
+        // reduce to [0, π/2] using symmetry
+        mask = vcgtq_f32(x, pi);
+        x = vbslq_f32(mask, vsubq_f32(x, pi), x);
+        let mut sign = vbslq_f32(mask, vdupq_n_f32(-1.), one);
+
+        mask = vcgtq_f32(x, vdupq_n_f32(FRAC_PI_2));
+        x = vbslq_f32(mask, vsubq_f32(pi, x), x);
+        sign = vbslq_f32(mask, vnegq_f32(sign), sign);
+
+        // same polynomial as the portable implementation, evaluated with Horner method
+        let a2 = vdupq_n_f32(-0.4999999);
+        let a4 = vdupq_n_f32(0.04166368);
+
+        let x_sq = vmulq_f32(x, x);
+        vmulq_f32(
+            sign,
+            vaddq_f32(one, vmulq_f32(x_sq, vaddq_f32(a2, vmulq_f32(x_sq, a4)))),
+        )
+    }
+}
@@ -0,0 +1,68 @@
+//! Cross-validates the SIMD, core and `geo` paths of the ruler on the same polyline.
+
+use approx::assert_relative_eq;
+use geo::{Distance, LineString};
+use geo_ruler::CheapRuler;
+use geo_ruler::geo::RulerMeasure;
+
+const RELATIVE_ERROR: f32 = 0.001;
+
+/// A winding walk of `size` points around midtown Manhattan.
+fn generate_polyline(size: usize) -> Vec<[f32; 2]> {
+    let ruler = CheapRuler::WGS84();
+
+    let mut point = [-73.9857, 40.7484];
+    let mut points = Vec::with_capacity(size);
+
+    for i in 0..size {
+        points.push(point);
+
+        let bearing = (i * 37 % 360) as f32;
+        let distance = 20. + (i % 13) as f32 * 15.;
+        point = ruler.destination(&point, &bearing, &distance);
+    }
+
+    points
+}
+
+#[test]
+fn test_simd_core_and_geo_lengths_agree() {
+    let ruler = CheapRuler::WGS84();
+    let measure = RulerMeasure::<f64>::WGS84();
+
+    // lengths covering partial tails, and a prime number of points not divisible by the lanes
+    for size in [2, 3, 4, 5, 8, 9, 1019] {
+        let points = generate_polyline(size);
+
+        let line: LineString<f64> = points
+            .iter()
+            .map(|&[lon, lat]| (lon as f64, lat as f64))
+            .collect();
+
+        let geo_length: f64 = line
+            .lines()
+            .map(|segment| measure.distance(segment.start_point(), segment.end_point()))
+            .sum();
+
+        let core_length: f32 = points
+            .windows(2)
+            .map(|pair| ruler.distance(&pair[0], &pair[1]))
+            .sum();
+
+        let lons: Vec<f32> = points.iter().map(|p| p[0]).collect();
+        let lats: Vec<f32> = points.iter().map(|p| p[1]).collect();
+
+        let simd_length = simd_ruler::length(&[&lons[..], &lats[..]]);
+
+        assert_relative_eq!(
+            core_length,
+            geo_length as f32,
+            max_relative = RELATIVE_ERROR
+        );
+        assert_relative_eq!(
+            simd_length,
+            geo_length as f32,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+}
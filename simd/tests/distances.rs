@@ -0,0 +1,404 @@
+use approx::assert_relative_eq;
+use geo::LineString;
+use geo::line_measures::FrechetDistance;
+use geo_ruler::geo::RulerMeasure;
+use geo_ruler::{Atan2Mode, CheapRuler};
+use simd_ruler::f32x4;
+
+const RELATIVE_ERROR: f32 = 0.01;
+
+#[test]
+fn test_simd_distances_from() {
+    let lats = [
+        40.7411, 40.7500, 40.7516, 40.7532, 40.7540, 40.7550, 40.7590,
+    ];
+    let lons = [
+        -73.9897, -73.9840, -73.9823, -73.9806, -73.9790, -73.9770, -73.9990,
+    ];
+
+    let origin = [-73.9857, 40.7484];
+
+    let mut simd_distances = [0.; 7];
+
+    simd_ruler::distances_from(origin, &[&lons[..], &lats[..]], &mut simd_distances);
+
+    let points: Vec<[f32; 2]> = lons
+        .iter()
+        .zip(lats.iter())
+        .map(|(&lon, &lat)| [lon, lat])
+        .collect();
+
+    let mut reference_distances = [0.; 7];
+
+    CheapRuler::WGS84().distance_to_many(&origin, &points, &mut reference_distances);
+
+    for (simd, reference) in simd_distances.iter().zip(reference_distances.iter()) {
+        assert_relative_eq!(simd, reference, max_relative = RELATIVE_ERROR);
+    }
+
+    // fewer latitudes than longitudes, the points with both are measured the same
+    let mut short_distances = [0.; 7];
+
+    simd_ruler::distances_from(origin, &[&lons[..], &lats[..5]], &mut short_distances);
+
+    assert_eq!(short_distances[..5], simd_distances[..5]);
+}
+
+#[test]
+fn test_simd_within_radius() {
+    let center = [-73.9857, 40.7484];
+    let radius = 2_000.;
+
+    // deterministic pseudo-random points scattered around the center
+    let mut seed = 42u32;
+    let mut random = || {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+    };
+
+    let (lons, lats): (Vec<f32>, Vec<f32>) = (0..1_001)
+        .map(|_| (center[0] + 0.08 * random(), center[1] + 0.06 * random()))
+        .unzip();
+
+    let simd_indices = simd_ruler::within_radius(center, radius, &[&lons[..], &lats[..]]);
+
+    let ruler = CheapRuler::WGS84();
+
+    let reference_indices: Vec<u32> = lons
+        .iter()
+        .zip(lats.iter())
+        .enumerate()
+        .filter(|(_, (lon, lat))| ruler.distance(&center, &[**lon, **lat]) <= radius)
+        .map(|(i, _)| i as u32)
+        .collect();
+
+    assert!(!reference_indices.is_empty());
+    assert!(reference_indices.len() < lons.len());
+    assert_eq!(simd_indices, reference_indices);
+}
+
+#[test]
+fn test_simd_distance_kernel() {
+    let origins = [
+        [-73.9857, 40.7484],
+        [-73.9897, 40.7411],
+        [-73.9927, 40.7394],
+        [-73.9819, 40.7681],
+    ];
+    let destinations = [
+        [-73.9897, 40.7411],
+        [-73.9927, 40.7394],
+        [-73.9819, 40.7681],
+        [-73.9855, 40.7580],
+    ];
+
+    let lanes = |points: &[[f32; 2]; 4], i: usize| f32x4::new(points.map(|point| point[i]));
+
+    let distances = simd_ruler::distance(
+        &[lanes(&origins, 0), lanes(&origins, 1)],
+        &[lanes(&destinations, 0), lanes(&destinations, 1)],
+    );
+
+    let ruler = CheapRuler::WGS84();
+
+    for (i, distance) in distances.to_array().iter().enumerate() {
+        assert_relative_eq!(
+            *distance,
+            ruler.distance(&origins[i], &destinations[i]),
+            max_relative = RELATIVE_ERROR
+        );
+    }
+}
+
+#[test]
+fn test_simd_vectors_from() {
+    let lats = [
+        40.7411, 40.7500, 40.7516, 40.7532, 40.7540, 40.7550, 40.7590, 40.7300, 40.7484,
+    ];
+    let lons = [
+        -73.9897, -73.9840, -73.9823, -73.9806, -73.9790, -73.9770, -73.9990, -73.9700, -74.0000,
+    ];
+
+    let depot = [-73.9857, 40.7484];
+
+    let mut distances = [0.; 9];
+    let mut bearings = [0.; 9];
+
+    simd_ruler::vectors_from(
+        depot,
+        &[&lons[..], &lats[..]],
+        &mut distances,
+        &mut bearings,
+    );
+
+    // the SIMD bearings use the 3rd degree approximation whatever the features select
+    let ruler = CheapRuler::WGS84().with_atan2(Atan2Mode::Deg3);
+
+    for i in 0..lons.len() {
+        let point = [lons[i], lats[i]];
+
+        assert_relative_eq!(
+            distances[i],
+            ruler.distance(&depot, &point),
+            max_relative = RELATIVE_ERROR
+        );
+        assert_relative_eq!(bearings[i], ruler.bearing(&depot, &point), epsilon = 0.1);
+    }
+}
+
+#[test]
+fn test_simd_destinations() {
+    let ruler = CheapRuler::WGS84();
+
+    // a prime number of points, so the last chunk is ragged
+    let n = 13;
+
+    let lons: Vec<f32> = (0..n).map(|i| -73.9857 + 0.001 * i as f32).collect();
+    let lats: Vec<f32> = (0..n).map(|i| 40.7484 - 0.0007 * i as f32).collect();
+
+    let bearings: Vec<f32> = (0..n).map(|i| -180. + 29. * i as f32).collect();
+    let distances: Vec<f32> = (0..n).map(|i| 50. + 125. * i as f32).collect();
+
+    let mut dest_lons = vec![0.; n];
+    let mut dest_lats = vec![0.; n];
+
+    simd_ruler::destinations(
+        &[&lons[..], &lats[..]],
+        &bearings,
+        &distances,
+        [&mut dest_lons, &mut dest_lats],
+    );
+
+    for i in 0..n {
+        let expected = ruler.destination(&[lons[i], lats[i]], &bearings[i], &distances[i]);
+        let actual = [dest_lons[i], dest_lats[i]];
+
+        // compare the displacements, the coordinates themselves are always close
+        assert_relative_eq!(
+            ruler.distance(&[lons[i], lats[i]], &actual),
+            distances[i],
+            max_relative = RELATIVE_ERROR
+        );
+        assert!(ruler.distance(&expected, &actual) < distances[i] * RELATIVE_ERROR);
+    }
+}
+
+#[test]
+fn test_simd_frechet_distance() {
+    let lons = [
+        -73.9897, -73.9880, -73.9866, -73.9857, -73.9845, -73.9855, -73.9840, -73.9822, -73.9810,
+    ];
+    let lats = [
+        40.7411, 40.7435, 40.7460, 40.7484, 40.7520, 40.7580, 40.7602, 40.7627, 40.7644,
+    ];
+
+    // a GPS trace of the same route, offset north east and sampled more sparsely
+    let shifted_lons: Vec<f32> = lons.iter().step_by(2).map(|lon| lon + 0.0004).collect();
+    let shifted_lats: Vec<f32> = lats.iter().step_by(2).map(|lat| lat + 0.0003).collect();
+
+    let route = [&lons[..], &lats[..]];
+    let trace = [&shifted_lons[..], &shifted_lats[..]];
+
+    let line_string = |[lons, lats]: [&[f32]; 2]| -> LineString<f32> {
+        lons.iter()
+            .zip(lats)
+            .map(|(&lon, &lat)| (lon, lat))
+            .collect()
+    };
+
+    let ruler = RulerMeasure::<f32>::WGS84();
+
+    assert_eq!(simd_ruler::frechet_distance(&route, &route), 0.);
+
+    assert_relative_eq!(
+        simd_ruler::frechet_distance(&route, &trace),
+        ruler.frechet_distance(&line_string(route), &line_string(trace)),
+        max_relative = RELATIVE_ERROR
+    );
+
+    assert_eq!(simd_ruler::frechet_distance(&route, &[&[], &[]]), 0.);
+}
+
+#[test]
+fn test_simd_small_inputs() {
+    let lons = [-73.9897, -73.9857, -73.9772];
+    let lats = [40.7411, 40.7484, 40.7527];
+
+    let origin = [-73.9855, 40.7580];
+
+    for n in 0..=3 {
+        let points = [&lons[..n], &lats[..n]];
+
+        let mut distances = vec![f32::NAN; n];
+        let mut bearings = vec![f32::NAN; n];
+        let mut destinations = [vec![f32::NAN; n], vec![f32::NAN; n]];
+        let [dest_lons, dest_lats] = &mut destinations;
+
+        simd_ruler::distances_from(origin, &points, &mut distances);
+        simd_ruler::vectors_from(origin, &points, &mut distances, &mut bearings);
+        simd_ruler::destinations(&points, &bearings, &distances, [dest_lons, dest_lats]);
+
+        assert!(
+            distances
+                .iter()
+                .chain(&bearings)
+                .all(|value| value.is_finite())
+        );
+        assert!(destinations.iter().flatten().all(|value| value.is_finite()));
+
+        assert_eq!(
+            simd_ruler::within_radius(origin, 10_000., &points),
+            (0..n as u32).collect::<Vec<_>>()
+        );
+
+        let length = simd_ruler::length(&points);
+        let length_fast = simd_ruler::length_fast(&points);
+
+        if n < 2 {
+            assert_eq!((length, length_fast), (0., 0.));
+        } else {
+            assert!(length > 0. && length_fast > 0.);
+        }
+
+        assert_eq!(simd_ruler::frechet_distance(&points, &points), 0.);
+    }
+}
+
+#[test]
+fn test_simd_total_turning() {
+    let ruler = CheapRuler::WGS84();
+    let center = [-73.9654, 40.7829]; // Central Park
+
+    // a spiral of fixes turning 25° at each, in lengths covering the ragged tails
+    for n in 0..=24 {
+        let track: Vec<[f32; 2]> = (0..n)
+            .map(|i| ruler.destination(&center, &(25. * i as f32), &(100. + 20. * i as f32)))
+            .collect();
+
+        let lons: Vec<f32> = track.iter().map(|point| point[0]).collect();
+        let lats: Vec<f32> = track.iter().map(|point| point[1]).collect();
+
+        assert_relative_eq!(
+            simd_ruler::total_turning(&[&lons[..], &lats[..]]),
+            ruler.total_turning(&track),
+            epsilon = 1.5
+        );
+    }
+
+    // a closed lap around the park with a repeated fix, clockwise
+    let mut lap: Vec<[f32; 2]> = (0..=36)
+        .map(|i| ruler.destination(&center, &((i % 36) as f32 * 10.), &500.))
+        .collect();
+    lap.insert(5, lap[5]);
+
+    let lons: Vec<f32> = lap.iter().map(|point| point[0]).collect();
+    let lats: Vec<f32> = lap.iter().map(|point| point[1]).collect();
+
+    let turning = simd_ruler::total_turning(&[&lons[..], &lats[..]]);
+
+    assert_relative_eq!(turning, 360., epsilon = 1e-3);
+    assert_relative_eq!(turning, ruler.total_turning(&lap), epsilon = 1e-3);
+}
+
+#[test]
+fn test_simd_distance_matrix() {
+    let ruler = CheapRuler::WGS84();
+
+    let destinations: Vec<[f32; 2]> = (0..7)
+        .map(|i| [-73.9857 + 0.0021 * i as f32, 40.7484 - 0.0013 * i as f32])
+        .collect();
+    let dest_lons: Vec<f32> = destinations.iter().map(|point| point[0]).collect();
+    let dest_lats: Vec<f32> = destinations.iter().map(|point| point[1]).collect();
+
+    // numbers of origins covering full batches of four and ragged tails
+    for n in 0..=9 {
+        let origins: Vec<[f32; 2]> = (0..n)
+            .map(|i| [-73.9897 + 0.0017 * i as f32, 40.7411 + 0.0035 * i as f32])
+            .collect();
+        let lons: Vec<f32> = origins.iter().map(|point| point[0]).collect();
+        let lats: Vec<f32> = origins.iter().map(|point| point[1]).collect();
+
+        let mut out = vec![f32::NAN; n * 7];
+        simd_ruler::distance_matrix(
+            &[&lons[..], &lats[..]],
+            &[&dest_lons[..], &dest_lats[..]],
+            &mut out,
+        );
+
+        for (i, origin) in origins.iter().enumerate() {
+            for (j, destination) in destinations.iter().enumerate() {
+                assert_relative_eq!(
+                    out[i * 7 + j],
+                    ruler.distance(origin, destination),
+                    max_relative = RELATIVE_ERROR
+                );
+            }
+        }
+    }
+
+    // a buffer too short for the last row leaves it untouched
+    let mut out = [f32::NAN; 13];
+    simd_ruler::distance_matrix(
+        &[&dest_lons[..2], &dest_lats[..2]],
+        &[&dest_lons[..], &dest_lats[..]],
+        &mut out,
+    );
+
+    assert!(out[..7].iter().all(|distance| distance.is_finite()));
+    assert!(out[7..].iter().all(|distance| distance.is_nan()));
+}
+
+#[test]
+fn test_simd_antimeridian() {
+    let ruler = CheapRuler::WGS84().with_atan2(Atan2Mode::Deg3);
+
+    // a small square in Fiji straddling the antimeridian, counter-clockwise
+    let ring = [
+        [179.995, -16.505],
+        [-179.995, -16.505],
+        [-179.995, -16.495],
+        [179.995, -16.495],
+        [179.995, -16.505],
+    ];
+
+    let lons: Vec<f32> = ring.iter().map(|p| p[0]).collect();
+    let lats: Vec<f32> = ring.iter().map(|p| p[1]).collect();
+    let points = [&lons[..], &lats[..]];
+
+    let mut distances = [0.; 5];
+    let mut bearings = [0.; 5];
+    simd_ruler::vectors_from(ring[0], &points, &mut distances, &mut bearings);
+
+    for (i, point) in ring.iter().enumerate() {
+        assert_relative_eq!(
+            distances[i],
+            ruler.distance(&ring[0], point),
+            max_relative = RELATIVE_ERROR
+        );
+        assert!(distances[i] < 2_000.);
+
+        if i > 0 && i < 4 {
+            assert_relative_eq!(bearings[i], ruler.bearing(&ring[0], point), epsilon = 0.1);
+        }
+    }
+
+    assert_eq!(
+        simd_ruler::within_radius(ring[0], 2_000., &points),
+        [0, 1, 2, 3, 4]
+    );
+
+    assert_relative_eq!(
+        simd_ruler::length(&points),
+        ruler.length(&ring),
+        max_relative = RELATIVE_ERROR
+    );
+
+    let (area, perimeter) = simd_ruler::polygon_metrics(&points);
+
+    assert_relative_eq!(area, ruler.area(&[&ring]), max_relative = RELATIVE_ERROR);
+    assert_relative_eq!(
+        perimeter,
+        ruler.length(&ring),
+        max_relative = RELATIVE_ERROR
+    );
+}
@@ -1,13 +1,17 @@
 use approx::assert_relative_eq;
+use core::f32::consts::PI;
 use geo_ruler::CheapRuler;
-use simd_ruler;
 
 const RELATIVE_ERROR: f32 = 0.01;
 
 #[test]
 fn test_simd_length_basic() {
-    let lats = vec![40.7484, 40.7500, 40.7516, 40.7532, 40.7540, 40.7550, 40.7590];
-    let lons = vec![-73.9857, -73.9840, -73.9823, -73.9806, -73.9790, -73.9770, -73.9990];
+    let lats = [
+        40.7484, 40.7500, 40.7516, 40.7532, 40.7540, 40.7550, 40.7590,
+    ];
+    let lons = [
+        -73.9857, -73.9840, -73.9823, -73.9806, -73.9790, -73.9770, -73.9990,
+    ];
 
     let points = [&lons[..], &lats[..]];
 
@@ -29,3 +33,130 @@ fn test_simd_length_basic() {
 
     assert_relative_eq!(simd_length, reference_length, max_relative = RELATIVE_ERROR);
 }
+
+#[test]
+fn test_simd_length_many_chunks() {
+    let ruler = CheapRuler::WGS84();
+
+    // 39 segments, several full chunks past the first one plus a partial tail
+    let points: Vec<[f32; 2]> = (0..40)
+        .map(|i| {
+            [
+                -73.9897 + 0.0004 * i as f32,
+                40.7411 + 0.0009 * (i % 5) as f32,
+            ]
+        })
+        .collect();
+
+    let lons: Vec<f32> = points.iter().map(|p| p[0]).collect();
+    let lats: Vec<f32> = points.iter().map(|p| p[1]).collect();
+
+    let simd_length = simd_ruler::length(&[&lons[..], &lats[..]]);
+
+    assert_relative_eq!(simd_length, ruler.length(&points), max_relative = 1e-4);
+}
+
+#[test]
+fn test_simd_length_ragged() {
+    let ruler = CheapRuler::WGS84();
+
+    // lengths covering full chunks, partial tails and the exact chunk boundaries
+    for n in 1..=16 {
+        let lons: Vec<f32> = (0..n).map(|i| -73.9857 + 0.0011 * i as f32).collect();
+        let lats: Vec<f32> = (0..n).map(|i| 40.7484 + 0.0007 * (i % 3) as f32).collect();
+
+        let simd_length = simd_ruler::length(&[&lons[..], &lats[..]]);
+
+        let mut reference_length = 0.;
+
+        for i in 1..n {
+            reference_length += ruler.distance(&[lons[i - 1], lats[i - 1]], &[lons[i], lats[i]])
+        }
+
+        if n < 2 {
+            assert_eq!(simd_length, 0.);
+        } else {
+            assert_relative_eq!(simd_length, reference_length, max_relative = RELATIVE_ERROR);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "longitude and latitude arrays differ in length")
+)]
+fn test_simd_length_mismatched() {
+    let lons = [-73.9857, -73.9840, -73.9823, -73.9806, -73.9790, -73.9770];
+    let lats = [40.7484, 40.7500, 40.7516, 40.7532, 40.7540];
+
+    // in release builds the extra longitude is ignored, instead of paired with a zero
+    assert_eq!(
+        simd_ruler::length(&[&lons[..], &lats[..]]),
+        simd_ruler::length(&[&lons[..lats.len()], &lats[..]])
+    );
+}
+
+#[test]
+fn test_simd_length_fast() {
+    // walking route through Midtown Manhattan, from the Flatiron Building to Central Park
+    let lons = [
+        -73.9897, -73.9880, -73.9866, -73.9857, -73.9845, -73.9855, -73.9840, -73.9822, -73.9810,
+        -73.9797, -73.9783, -73.9770, -73.9751,
+    ];
+    let lats = [
+        40.7411, 40.7435, 40.7460, 40.7484, 40.7520, 40.7580, 40.7602, 40.7627, 40.7644, 40.7662,
+        40.7681, 40.7699, 40.7725,
+    ];
+
+    let points = [&lons[..], &lats[..]];
+
+    let fast_length = simd_ruler::length_fast(&points);
+    let simd_length = simd_ruler::length(&points);
+
+    // coefs at the mean latitude only differ from the per-segment ones by a few parts in a million
+    assert_relative_eq!(fast_length, simd_length, max_relative = 1e-4);
+
+    for n in 0..2 {
+        assert_eq!(simd_ruler::length_fast(&[&lons[..n], &lats[..n]]), 0.);
+    }
+}
+
+#[test]
+fn test_simd_polygon_metrics() {
+    let ruler = CheapRuler::<f32>::WGS84();
+    let center = [-73.9857, 40.7484];
+    let radius = 500.;
+
+    // regular polygons of every ragged tail length, closed and open
+    for steps in 3..=16 {
+        let closed = ruler.circle(&center, &radius, steps);
+        let open = &closed[..steps];
+
+        let angle = 2. * PI / steps as f32;
+        let expected_area = steps as f32 / 2. * radius * radius * angle.sin();
+        let expected_perimeter = ruler.length(&closed);
+
+        for ring in [&closed[..], open] {
+            let lons: Vec<f32> = ring.iter().map(|p| p[0]).collect();
+            let lats: Vec<f32> = ring.iter().map(|p| p[1]).collect();
+
+            let (area, perimeter) = simd_ruler::polygon_metrics(&[&lons[..], &lats[..]]);
+
+            assert_relative_eq!(area, expected_area, max_relative = RELATIVE_ERROR);
+            assert_relative_eq!(perimeter, expected_perimeter, max_relative = RELATIVE_ERROR);
+        }
+    }
+
+    // a single edge is walked there and back, enclosing nothing
+    let (area, perimeter) =
+        simd_ruler::polygon_metrics(&[&[-73.9857, -73.9897], &[40.7484, 40.7411]]);
+    assert_eq!(area, 0.);
+    assert_relative_eq!(
+        perimeter,
+        2. * ruler.distance(&[-73.9857, 40.7484], &[-73.9897, 40.7411]),
+        max_relative = RELATIVE_ERROR
+    );
+
+    assert_eq!(simd_ruler::polygon_metrics(&[&[], &[]]), (0., 0.));
+}
@@ -0,0 +1,33 @@
+//! Validates the explicit NEON path against the portable `wide` path, on aarch64 only.
+#![cfg(all(feature = "neon", target_arch = "aarch64"))]
+
+#[test]
+fn test_neon_length_matches_portable() {
+    // lengths covering full chunks, partial tails and the exact chunk boundaries
+    for n in 0..=16 {
+        let lons: Vec<f32> = (0..n).map(|i| -73.9857 + 0.0011 * i as f32).collect();
+        let lats: Vec<f32> = (0..n).map(|i| 40.7484 - 0.0007 * (i % 3) as f32).collect();
+
+        let points = [&lons[..], &lats[..]];
+
+        assert_eq!(
+            simd_ruler::neon::length(&points),
+            simd_ruler::length(&points),
+            "{n} points"
+        );
+    }
+}
+
+#[test]
+fn test_neon_length_matches_portable_across_latitudes() {
+    // every quadrant of the cosine reduction, southern latitudes included
+    let lats: Vec<f32> = (0..1019).map(|i| -89. + 178. * i as f32 / 1018.).collect();
+    let lons: Vec<f32> = (0..1019).map(|i| -180. + 0.01 * i as f32).collect();
+
+    let points = [&lons[..], &lats[..]];
+
+    assert_eq!(
+        simd_ruler::neon::length(&points),
+        simd_ruler::length(&points)
+    );
+}
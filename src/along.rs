@@ -0,0 +1,255 @@
+//! This module provides `AlongLine`, an iterator of evenly spaced points along a polyline.
+//!
+//! It is the core counterpart of the `geo` `InterpolatePoint::points_along_line`, which
+//! only covers a single segment, and needs neither `geo` nor an allocator.
+
+use crate::CheapRuler;
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+/// An iterator of evenly spaced points along a polyline.
+///
+/// Obtained with [`CheapRuler::points_along_line`]. Each segment is walked from its
+/// first vertex in steps of the maximum distance, so every vertex of the polyline is
+/// yielded and the corners are kept.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::CheapRuler;
+///
+/// let ruler = CheapRuler::<f64>::WGS84();
+///
+/// let route = [
+///     [-73.9897, 40.7411], // Flatiron Building
+///     [-73.9857, 40.7484], // Empire State Building
+///     [-73.9855, 40.7580], // Times Square
+/// ];
+///
+/// let mut points = ruler.points_along_line(&route, &100., true);
+///
+/// assert_eq!(points.next(), Some(route[0]));
+/// assert_eq!(points.last(), Some(route[2]));
+/// ```
+pub struct AlongLine<'a, T: Float> {
+    ruler: &'a CheapRuler<T>,
+    points: &'a [[T; 2]],
+    max_distance: T,
+    /// Index of the first vertex of the current segment
+    segment: usize,
+    /// Index of the next step along the current segment
+    index: usize,
+    /// Number of steps along the current segment
+    steps: usize,
+    /// Step size as a ratio of the length of the current segment
+    step: T,
+    /// Whether to yield the last vertex as the final item
+    include_last: bool,
+}
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Returns an iterator of evenly spaced points along a polyline.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `max_distance`: Maximum distance between consecutive points in the ruler units,
+    ///   a non-positive distance yields the vertices only
+    /// - `include_ends`: Whether to include the first and the last vertex
+    ///
+    /// # Returns
+    ///
+    /// An [`AlongLine`] iterator of points as `[longitude, latitude]` in degrees
+    ///
+    /// See also: [`along`](Self::along), [`interpolate`](Self::interpolate)
+    pub fn points_along_line<'a>(
+        &'a self,
+        points: &'a [[T; 2]],
+        max_distance: &T,
+        include_ends: bool,
+    ) -> AlongLine<'a, T> {
+        let mut along = AlongLine {
+            ruler: self,
+            points,
+            max_distance: *max_distance,
+            segment: 0,
+            index: 0,
+            steps: 0,
+            step: T::zero(),
+            include_last: include_ends && !points.is_empty(),
+        };
+
+        along.start_segment();
+
+        if !include_ends {
+            along.index = 1;
+        }
+
+        along
+    }
+}
+
+impl<T: Float + FloatConst + Debug> AlongLine<'_, T> {
+    /// Resets the step index and computes the steps for the current segment.
+    ///
+    /// The offsets are computed from the index rather than accumulated, so they keep
+    /// moving however small the step is compared to the segment.
+    fn start_segment(&mut self) {
+        self.index = 0;
+        self.steps = 1;
+
+        if let [start, end, ..] = self.points[self.segment.min(self.points.len())..] {
+            let distance = self.ruler.distance(&start, &end);
+
+            if self.max_distance > T::zero() {
+                self.step = self.max_distance / distance;
+                self.steps = (distance / self.max_distance)
+                    .ceil()
+                    .to_usize()
+                    .unwrap_or(1)
+                    .max(1);
+            }
+        }
+    }
+}
+
+impl<T: Float + FloatConst + Debug> Iterator for AlongLine<'_, T> {
+    type Item = [T; 2];
+
+    /// Advances along the current segment, moving on to the next one at its end, then
+    /// potentially yields the last vertex as the final item.
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.segment + 1 < self.points.len() {
+            if self.index < self.steps {
+                let [start, end] = [self.points[self.segment], self.points[self.segment + 1]];
+
+                let offset = match self.index {
+                    0 => T::zero(),
+                    index => T::from(index).unwrap() * self.step,
+                };
+
+                self.index += 1;
+
+                return Some(self.ruler.interpolate(&start, &end, &offset));
+            }
+
+            self.segment += 1;
+            self.start_segment();
+        }
+
+        if self.include_last {
+            self.include_last = false;
+            self.points.last().copied()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::relative_eq;
+    use std::vec::Vec;
+
+    const RELATIVE_ERROR: f64 = 0.001;
+
+    fn assert_evenly_spaced(ruler: &CheapRuler<f64>, points: &[[f64; 2]], max_distance: f64) {
+        for pair in points.windows(2) {
+            let distance = ruler.distance(&pair[0], &pair[1]);
+
+            assert!(
+                distance < max_distance
+                    || relative_eq!(distance, max_distance, max_relative = RELATIVE_ERROR)
+            );
+        }
+    }
+
+    #[test]
+    fn points_along_line_no_ends() {
+        let ruler = CheapRuler::WGS84();
+
+        let empire_state = [-73.9857, 40.7484];
+        let flatiron = [-73.9897, 40.7411];
+
+        let points: Vec<_> = ruler
+            .points_along_line(&[empire_state, flatiron], &100., false)
+            .collect();
+
+        assert_evenly_spaced(&ruler, &points, 100.);
+
+        assert!(!points.contains(&empire_state) && !points.contains(&flatiron));
+    }
+
+    #[test]
+    fn points_along_line_with_ends() {
+        let ruler = CheapRuler::WGS84();
+
+        let empire_state = [-73.9857, 40.7484];
+        let flatiron = [-73.9897, 40.7411];
+
+        let points: Vec<_> = ruler
+            .points_along_line(&[empire_state, flatiron], &100., true)
+            .collect();
+
+        assert_evenly_spaced(&ruler, &points, 100.);
+
+        assert_eq!(points.first(), Some(&empire_state));
+        assert_eq!(points.last(), Some(&flatiron));
+    }
+
+    #[test]
+    fn points_along_polyline() {
+        let ruler = CheapRuler::WGS84();
+
+        let route = [
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9855, 40.7580], // Times Square
+            [-73.9819, 40.7681], // Columbus Circle
+        ];
+
+        let points: Vec<_> = ruler.points_along_line(&route, &100., true).collect();
+
+        assert_evenly_spaced(&ruler, &points, 100.);
+
+        // every vertex is kept, in order
+        let vertices: Vec<_> = points.iter().filter(|p| route.contains(p)).collect();
+        assert_eq!(vertices, route.iter().collect::<Vec<_>>());
+
+        // without the ends, only the first and the last vertex are left out
+        let inner: Vec<_> = ruler.points_along_line(&route, &100., false).collect();
+
+        assert_eq!(inner.len(), points.len() - 2);
+        assert_eq!(inner, points[1..points.len() - 1]);
+
+        // degenerate lines
+        assert_eq!(ruler.points_along_line(&[], &100., true).count(), 0);
+        assert_eq!(
+            ruler
+                .points_along_line(&route[..1], &100., true)
+                .collect::<Vec<_>>(),
+            [route[0]]
+        );
+        assert_eq!(ruler.points_along_line(&route, &0., true).count(), 4);
+    }
+
+    #[test]
+    fn points_along_line_tiny_step() {
+        let ruler = CheapRuler::<f32>::WGS84();
+
+        let line = [[0., 0.], [0.5, 0.]];
+
+        // an accumulated f32 offset stops moving long before the end with such a step
+        let mut points = ruler.points_along_line(&line, &0.001, true);
+        assert!(points.steps > 50_000_000);
+
+        points.index = points.steps - 1;
+
+        let last = points.next().unwrap();
+        assert!(relative_eq!(last[0], line[1][0], max_relative = 1e-6));
+
+        assert_eq!(points.next(), Some(line[1]));
+        assert_eq!(points.next(), None);
+    }
+}
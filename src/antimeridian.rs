@@ -0,0 +1,32 @@
+//! # Antimeridian Module
+//!
+//! This module defines how a `CheapRuler` treats longitude differences across the
+//! antimeridian (±180°).
+
+/// How longitude differences are computed between two points.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::{AntimeridianMode, CheapRuler};
+///
+/// let west_of = [179.99, 0.0];
+/// let east_of = [-179.99, 0.0];
+///
+/// // about 2.2 km, crossing the antimeridian
+/// let short = CheapRuler::<f64>::WGS84().distance(&west_of, &east_of);
+///
+/// // about 40,000 km, the long way around
+/// let long = CheapRuler::<f64>::WGS84()
+///     .with_antimeridian(AntimeridianMode::Raw)
+///     .distance(&west_of, &east_of);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum AntimeridianMode {
+    /// Normalize the longitude difference into [-180°, 180°], taking the short way around.
+    #[default]
+    Wrap,
+    /// Use the raw longitude difference, as given by the coordinates.
+    Raw,
+}
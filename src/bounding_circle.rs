@@ -0,0 +1,193 @@
+//! This module provides the smallest enclosing circle of a point set for `CheapRuler`.
+//!
+//! The circle is computed with Welzl's algorithm in the ruler's locally scaled planar
+//! space, so the radius is expressed in the ruler units rather than in degrees.
+//!
+//! It is available when the `alloc` feature flag is enabled.
+
+use crate::CheapRuler;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+/// A circle in the planar space, as its center and squared radius.
+type Circle<T> = ([T; 2], T);
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Finds the smallest circle enclosing a set of points.
+    ///
+    /// The points are projected around their first point, with the latitude-dependent
+    /// scaling computed at their mean latitude, and the circle is found with the
+    /// iterative form of Welzl's algorithm. This is accurate for point sets spanning a
+    /// few hundred kilometers at most, e.g. to prefilter clusters or collisions.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The points as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The center as `[longitude, latitude]` in degrees and the radius in the ruler
+    /// units, or `None` if there are no points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let landmarks = [
+    ///     [-73.9857, 40.7484], // Empire State Building
+    ///     [-73.9897, 40.7411], // Flatiron Building
+    ///     [-73.9855, 40.7580], // Times Square
+    /// ];
+    ///
+    /// let (center, radius) = ruler.bounding_circle(&landmarks).unwrap();
+    /// ```
+    pub fn bounding_circle(&self, points: &[[T; 2]]) -> Option<([T; 2], T)> {
+        let origin = points.first()?;
+
+        let count = T::from(points.len()).unwrap();
+        let mean_lat = points.iter().fold(T::zero(), |sum, p| sum + p[1]) / count;
+
+        let [kx, ky] = self.coefs_at(&mean_lat);
+
+        let projected: Vec<[T; 2]> = points
+            .iter()
+            .map(|p| {
+                [
+                    self.delta_lon(&origin[0], &p[0]) * kx,
+                    (p[1] - origin[1]) * ky,
+                ]
+            })
+            .collect();
+
+        let ([x, y], radius_sq) = welzl(&projected);
+
+        Some(([origin[0] + x / kx, origin[1] + y / ky], radius_sq.sqrt()))
+    }
+}
+
+/// Finds the smallest circle enclosing planar points, with the iterative form of
+/// Welzl's algorithm.
+fn welzl<T: Float>(points: &[[T; 2]]) -> Circle<T> {
+    let mut circle = (points[0], T::zero());
+
+    for i in 1..points.len() {
+        if contains(&circle, &points[i]) {
+            continue;
+        }
+
+        circle = (points[i], T::zero());
+
+        for j in 0..i {
+            if contains(&circle, &points[j]) {
+                continue;
+            }
+
+            circle = diametral(&points[i], &points[j]);
+
+            for k in 0..j {
+                if !contains(&circle, &points[k]) {
+                    circle = circumscribed(&points[i], &points[j], &points[k]);
+                }
+            }
+        }
+    }
+
+    circle
+}
+
+/// Checks whether a circle contains a point, with a small relative tolerance for
+/// points on its boundary.
+fn contains<T: Float>((center, radius_sq): &Circle<T>, point: &[T; 2]) -> bool {
+    distance_sq(center, point) <= *radius_sq * (T::one() + T::epsilon().sqrt())
+}
+
+fn distance_sq<T: Float>(a: &[T; 2], b: &[T; 2]) -> T {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)
+}
+
+/// The circle whose diameter is the segment between two points.
+fn diametral<T: Float>(a: &[T; 2], b: &[T; 2]) -> Circle<T> {
+    let two = T::one() + T::one();
+    let center = [(a[0] + b[0]) / two, (a[1] + b[1]) / two];
+
+    (center, distance_sq(&center, a))
+}
+
+/// The circle through three points, or the widest diametral circle if they are collinear.
+fn circumscribed<T: Float>(a: &[T; 2], b: &[T; 2], c: &[T; 2]) -> Circle<T> {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+
+    let d = (T::one() + T::one()) * (bx * cy - by * cx);
+
+    if d == T::zero() {
+        return [diametral(a, b), diametral(a, c), diametral(b, c)]
+            .into_iter()
+            .fold((*a, T::zero()), |widest, circle| {
+                if circle.1 > widest.1 { circle } else { widest }
+            });
+    }
+
+    let (b_sq, c_sq) = (bx * bx + by * by, cx * cx + cy * cy);
+
+    let ux = (cy * b_sq - by * c_sq) / d;
+    let uy = (bx * c_sq - cx * b_sq) / d;
+
+    ([a[0] + ux, a[1] + uy], ux * ux + uy * uy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bounding_circle() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let points = [
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9855, 40.7580], // Times Square
+            [-73.9819, 40.7681], // Columbus Circle
+            [-73.9772, 40.7527], // Grand Central
+            [-73.9680, 40.7489], // United Nations
+            [-73.9934, 40.7505], // Penn Station
+            [-73.9787, 40.7587], // Rockefeller Center
+        ];
+
+        let (center, radius) = ruler.bounding_circle(&points).unwrap();
+
+        // distances are scaled at the center instead of the mean latitude, so allow for
+        // a small relative difference
+        for point in &points {
+            assert!(ruler.distance(&center, point) <= radius * (1. + 1e-4));
+        }
+
+        // the circle is tight, at least two points lie on its boundary
+        let on_boundary = points
+            .iter()
+            .filter(|point| (ruler.distance(&center, point) - radius).abs() < radius * 1e-4)
+            .count();
+        assert!(on_boundary >= 2);
+
+        // a single point, and two points on a meridian
+        assert_eq!(ruler.bounding_circle(&points[..1]), Some((points[0], 0.)));
+
+        let (center, radius) = ruler
+            .bounding_circle(&[[-73.9857, 40.74], [-73.9857, 40.76]])
+            .unwrap();
+        assert_relative_eq!(center[1], 40.75, max_relative = 1e-9);
+        assert_relative_eq!(
+            radius,
+            ruler.distance(&[-73.9857, 40.74], &[-73.9857, 40.76]) / 2.,
+            max_relative = 1e-3
+        );
+
+        assert_eq!(ruler.bounding_circle(&[]), None);
+    }
+}
@@ -0,0 +1,85 @@
+//! # Config Module
+//!
+//! This module defines `RulerConfig`, the runtime configuration of a `CheapRuler`, and
+//! `Atan2Mode`, the `atan2` approximation used for bearings.
+
+use crate::{AntimeridianMode, Units, constants};
+use num_traits::Float;
+
+/// The `atan2` implementation used to compute bearings.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::{Atan2Mode, CheapRuler};
+///
+/// // exact bearings, whatever the enabled features
+/// let ruler = CheapRuler::<f64>::WGS84().with_atan2(Atan2Mode::Std);
+///
+/// let bearing = ruler.bearing(&[-73.9857, 40.7484], &[-73.9897, 40.7411]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Atan2Mode {
+    /// The implementation selected by the `atan2_deg3` and `atan2_deg5` features.
+    #[default]
+    Features,
+    /// 3rd degree polynomial approximation, maximum error of about 0.6°.
+    Deg3,
+    /// 5th degree polynomial approximation, maximum error of about 0.035°.
+    Deg5,
+    /// 11th degree polynomial approximation, maximum error of about 0.0001°.
+    Deg11,
+    /// The standard library `atan2`.
+    Std,
+}
+
+/// The runtime configuration of a `CheapRuler`.
+///
+/// Gathers the ellipsoid, the units, the `atan2` approximation and the antimeridian
+/// handling, so they can be mixed freely regardless of the enabled features. The
+/// default configuration matches [`CheapRuler::WGS84`](crate::CheapRuler::WGS84).
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::{Atan2Mode, CheapRuler, RulerConfig, Units};
+///
+/// let config = RulerConfig {
+///     units: Units::NauticalMiles,
+///     atan2: Atan2Mode::Deg5,
+///     ..RulerConfig::default()
+/// };
+///
+/// let ruler = CheapRuler::<f64>::from_config(&config);
+///
+/// let distance = ruler.distance(&[-73.9857, 40.7484], &[-73.9897, 40.7411]); // nautical miles
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RulerConfig<T> {
+    /// Equatorial semi-axis of the ellipsoid (in meters)
+    pub major: T,
+    /// Polar semi-axis of the ellipsoid (in meters)
+    pub minor: T,
+    /// The distance units used for both inputs and outputs
+    pub units: Units,
+    /// The `atan2` implementation used for bearings
+    pub atan2: Atan2Mode,
+    /// Whether to wrap longitude differences across the antimeridian
+    pub antimeridian: AntimeridianMode,
+}
+
+impl<T: Float> Default for RulerConfig<T> {
+    fn default() -> Self {
+        let major = T::from(constants::WGS84_RE).unwrap();
+        let minor = major * (T::one() - T::from(constants::WGS84_E2).unwrap()).sqrt();
+
+        Self {
+            major,
+            minor,
+            units: Units::default(),
+            atan2: Atan2Mode::default(),
+            antimeridian: AntimeridianMode::default(),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! This module provides the convex hull of a point set for `CheapRuler`.
+//!
+//! The hull is computed with Andrew's monotone chain algorithm in the ruler's locally
+//! scaled planar space, anchored at the centroid of the points, so that the result does
+//! not depend on the shrinking of longitude degrees towards the poles.
+//!
+//! It is available when the `alloc` feature flag is enabled.
+
+use crate::CheapRuler;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Computes the convex hull of a set of points.
+    ///
+    /// The points are projected around their centroid, with the latitude-dependent
+    /// scaling computed at the centroid, and the hull is found with Andrew's monotone
+    /// chain algorithm. Points on the edges of the hull are not part of the result.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The points as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The hull vertices as `[longitude, latitude]` in degrees, counter-clockwise starting
+    /// from the westernmost one, without repeating the first vertex. Sets with less than
+    /// three distinct points are returned as their distinct points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let fixes = [
+    ///     [-73.9857, 40.7484],
+    ///     [-73.9897, 40.7411],
+    ///     [-73.9870, 40.7480], // inside the footprint
+    ///     [-73.9927, 40.7494],
+    ///     [-73.9855, 40.7580],
+    /// ];
+    ///
+    /// let footprint = ruler.convex_hull(&fixes);
+    /// assert_eq!(footprint.len(), 4);
+    /// ```
+    pub fn convex_hull(&self, points: &[[T; 2]]) -> Vec<[T; 2]> {
+        let Some(first) = points.first() else {
+            return Vec::new();
+        };
+
+        let count = T::from(points.len()).unwrap();
+        let (dlon, lat) = points
+            .iter()
+            .fold((T::zero(), T::zero()), |(dlon, lat), p| {
+                (dlon + self.delta_lon(&first[0], &p[0]), lat + p[1])
+            });
+        let centroid = [first[0] + dlon / count, lat / count];
+
+        let [kx, ky] = self.coefs(&centroid);
+
+        let mut projected: Vec<([T; 2], usize)> = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let x = self.delta_lon(&centroid[0], &p[0]) * kx;
+                let y = (p[1] - centroid[1]) * ky;
+                ([x, y], i)
+            })
+            .collect();
+
+        projected.sort_by(|(a, _), (b, _)| {
+            a[0].partial_cmp(&b[0])
+                .unwrap_or(Ordering::Equal)
+                .then(a[1].partial_cmp(&b[1]).unwrap_or(Ordering::Equal))
+        });
+        projected.dedup_by(|(a, _), (b, _)| a == b);
+
+        if projected.len() < 3 {
+            return projected.iter().map(|(_, i)| points[*i]).collect();
+        }
+
+        let turns_left = |hull: &[([T; 2], usize)], [bx, by]: [T; 2]| {
+            let [ox, oy] = hull[hull.len() - 2].0;
+            let [ax, ay] = hull[hull.len() - 1].0;
+
+            (ax - ox) * (by - oy) - (ay - oy) * (bx - ox) > T::zero()
+        };
+
+        let mut hull: Vec<([T; 2], usize)> = Vec::with_capacity(projected.len() + 1);
+
+        // lower chain going east
+        for vertex in &projected {
+            while hull.len() >= 2 && !turns_left(&hull, vertex.0) {
+                hull.pop();
+            }
+            hull.push(*vertex);
+        }
+
+        // upper chain going back west, keeping the lower chain
+        let floor = hull.len() + 1;
+
+        for vertex in projected.iter().rev().skip(1) {
+            while hull.len() >= floor && !turns_left(&hull, vertex.0) {
+                hull.pop();
+            }
+            hull.push(*vertex);
+        }
+
+        // the upper chain ends back at the first vertex
+        hull.pop();
+
+        hull.iter().map(|(_, i)| points[*i]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convex_hull() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // a grid of GPS fixes around Bryant Park plus some scattered inside, only the
+        // corners are hull vertices
+        let mut points = Vec::new();
+        for lon in [-73.986, -73.985, -73.984] {
+            for lat in [40.752, 40.753, 40.754] {
+                points.push([lon, lat]);
+            }
+        }
+        points.push([-73.9855, 40.7535]);
+        points.push([-73.9845, 40.7525]);
+
+        assert_eq!(
+            ruler.convex_hull(&points),
+            [
+                [-73.986, 40.752],
+                [-73.984, 40.752],
+                [-73.984, 40.754],
+                [-73.986, 40.754],
+            ]
+        );
+
+        // across the antimeridian the hull is still the local one
+        let across = [
+            [179.9, -0.1],
+            [-179.9, -0.1],
+            [180., 0.],
+            [-179.9, 0.1],
+            [179.9, 0.1],
+        ];
+
+        assert_eq!(
+            ruler.convex_hull(&across),
+            [[179.9, -0.1], [-179.9, -0.1], [-179.9, 0.1], [179.9, 0.1]]
+        );
+
+        // degenerate sets
+        assert!(ruler.convex_hull(&[]).is_empty());
+        assert_eq!(
+            ruler.convex_hull(&[[-73.986, 40.752], [-73.986, 40.752]]),
+            [[-73.986, 40.752]]
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! # Error Module
+//!
+//! This module defines the error type returned by the checked (`try_*`) constructors
+//! and methods of the geo-ruler library.
+
+use core::fmt;
+
+/// Errors returned by the checked constructors and methods of `CheapRuler`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RulerError {
+    /// The ellipsoid semi-axes are not finite, strictly positive values.
+    InvalidEllipsoid,
+    /// The origin is not finite, or lies on a pole where longitude is undefined.
+    InvalidOrigin,
+    /// The bearing is infinite or NaN.
+    InvalidBearing,
+    /// The distance is negative, infinite or NaN.
+    InvalidDistance,
+    /// A coordinate is not finite, or its latitude is beyond a pole.
+    InvalidCoordinate {
+        /// The index of the offending point.
+        index: usize,
+    },
+    /// The output buffer cannot hold all the results.
+    BufferTooSmall {
+        /// The length the buffer needs to have.
+        required: usize,
+    },
+}
+
+impl fmt::Display for RulerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulerError::InvalidEllipsoid => {
+                write!(f, "ellipsoid semi-axes must be finite and positive")
+            }
+            RulerError::InvalidOrigin => {
+                write!(f, "origin must be finite and not lie on a pole")
+            }
+            RulerError::InvalidBearing => write!(f, "bearing must be finite"),
+            RulerError::InvalidDistance => {
+                write!(f, "distance must be finite and non-negative")
+            }
+            RulerError::InvalidCoordinate { index } => {
+                write!(
+                    f,
+                    "point {index} must be finite with a latitude between -90 and 90 degrees"
+                )
+            }
+            RulerError::BufferTooSmall { required } => {
+                write!(
+                    f,
+                    "output buffer is too small, {required} elements required"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for RulerError {}
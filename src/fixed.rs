@@ -0,0 +1,226 @@
+//! This module provides `FixedRuler`, a `CheapRuler` with its scaling fixed at one latitude.
+//!
+//! A `CheapRuler` computes the latitude-dependent scaling at the origin of every
+//! measurement. When all the points of a workload lie within one latitude band, e.g. a
+//! spatial join within a city, the scaling can be computed once for the whole band and
+//! the measurements are reduced to a few multiplications.
+
+use crate::CheapRuler;
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+/// A ruler whose latitude-dependent scaling is computed once, at a fixed latitude.
+///
+/// Obtained with [`CheapRuler::fixed`], it measures like
+/// [`CheapRuler::distance_at_ref_lat`] without recomputing the scaling on every call.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::CheapRuler;
+///
+/// let ruler = CheapRuler::<f64>::WGS84();
+///
+/// let stations = [
+///     [-73.9855, 40.7580], // Times Square
+///     [-73.9772, 40.7527], // Grand Central
+/// ];
+/// let venues = [
+///     [-73.9857, 40.7484], // Empire State Building
+///     [-73.9934, 40.7505], // Madison Square Garden
+///     [-73.9787, 40.7587], // Rockefeller Center
+/// ];
+///
+/// let midtown = ruler.fixed(&40.75);
+///
+/// let mut distances = [0.; 6];
+/// midtown.distance_matrix(&stations, &venues, &mut distances);
+///
+/// // the distance from Grand Central to the Rockefeller Center
+/// assert_eq!(distances[5], midtown.distance(&stations[1], &venues[2]));
+/// ```
+pub struct FixedRuler<'a, T: Float> {
+    ruler: &'a CheapRuler<T>,
+    coefs: [T; 2],
+}
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Creates a ruler with the scaling fixed at a latitude.
+    ///
+    /// The ruler keeps the units and the antimeridian mode of this one.
+    ///
+    /// # Parameters
+    ///
+    /// - `lat`: Reference latitude in degrees, e.g. the middle of the area of interest
+    ///
+    /// # Returns
+    ///
+    /// A [`FixedRuler`] borrowing this ruler
+    pub fn fixed(&self, lat: &T) -> FixedRuler<'_, T> {
+        FixedRuler {
+            ruler: self,
+            coefs: self.coefs_at(lat),
+        }
+    }
+
+    /// Creates a ruler with the scaling fixed at the mean latitude of a set of points.
+    ///
+    /// Works best when the points are geographically clustered, e.g. a city dataset, as
+    /// the scaling drifts away from the exact one with the distance in latitude from the
+    /// mean.
+    ///
+    /// Like [`fixed`](Self::fixed), the returned ruler borrows this one instead of being
+    /// an associated constructor, so it keeps its ellipsoid, units and antimeridian mode.
+    /// An empty set of points has no mean latitude, hence the `Option`.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The points of the region as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// A [`FixedRuler`] borrowing this ruler, or `None` if there are no points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let stations = [
+    ///     [-73.9855, 40.7580], // Times Square
+    ///     [-73.9772, 40.7527], // Grand Central
+    ///     [-73.9934, 40.7505], // Penn Station
+    /// ];
+    ///
+    /// let midtown = ruler.for_region(&stations).unwrap();
+    ///
+    /// let distance = midtown.distance(&stations[0], &stations[1]);
+    /// ```
+    ///
+    /// See also: [`fixed`](Self::fixed)
+    pub fn for_region(&self, points: &[[T; 2]]) -> Option<FixedRuler<'_, T>> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let sum = points.iter().fold(T::zero(), |sum, point| sum + point[1]);
+
+        Some(self.fixed(&(sum / T::from(points.len()).unwrap())))
+    }
+}
+
+impl<T: Float + FloatConst + Debug> FixedRuler<'_, T> {
+    /// Calculates the distance between two points.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: First point as `[longitude, latitude]` in degrees
+    /// - `destination`: Second point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance between the points in the ruler units
+    pub fn distance(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.ruler.distance_with(&self.coefs, origin, destination)
+    }
+
+    /// Calculates the distances from every origin to every destination.
+    ///
+    /// # Parameters
+    ///
+    /// - `origins`: Starting points as `[longitude, latitude]` in degrees
+    /// - `destinations`: Target points as `[longitude, latitude]` in degrees
+    /// - `out`: Output buffer receiving the distances in the ruler units, row by row, so
+    ///   the distance from origin `i` to destination `j` is at `i * destinations.len() + j`
+    ///
+    /// Only the rows fitting entirely in `out` are written.
+    pub fn distance_matrix(&self, origins: &[[T; 2]], destinations: &[[T; 2]], out: &mut [T]) {
+        if destinations.is_empty() {
+            return;
+        }
+
+        for (origin, row) in origins.iter().zip(out.chunks_exact_mut(destinations.len())) {
+            for (destination, distance) in destinations.iter().zip(row) {
+                *distance = self.distance(origin, destination);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn distance_matrix() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let origins = [
+            [-73.9855, 40.7580], // Times Square
+            [-73.9772, 40.7527], // Grand Central
+            [-73.9934, 40.7505], // Penn Station
+        ];
+        let destinations = [
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9897, 40.7411], // Flatiron Building
+        ];
+
+        let midtown = ruler.fixed(&40.75);
+
+        let mut out = [f64::NAN; 6];
+        midtown.distance_matrix(&origins, &destinations, &mut out);
+
+        for (i, origin) in origins.iter().enumerate() {
+            for (j, destination) in destinations.iter().enumerate() {
+                assert_eq!(
+                    out[i * 2 + j],
+                    ruler.distance_at_ref_lat(&40.75, origin, destination)
+                );
+                assert_relative_eq!(
+                    out[i * 2 + j],
+                    ruler.distance(origin, destination),
+                    max_relative = 1e-3
+                );
+            }
+        }
+
+        // a buffer too short for the last row leaves it untouched
+        let mut out = [f64::NAN; 5];
+        midtown.distance_matrix(&origins, &destinations, &mut out);
+
+        assert!(out[..4].iter().all(|distance| distance.is_finite()));
+        assert!(out[4].is_nan());
+    }
+
+    #[test]
+    fn for_region() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // landmarks spread over Manhattan, from the Battery to Harlem
+        let landmarks = [
+            [-74.0170, 40.7033], // Battery Park
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9855, 40.7580], // Times Square
+            [-73.9819, 40.7681], // Columbus Circle
+            [-73.9496, 40.8116], // Apollo Theater
+        ];
+
+        let manhattan = ruler.for_region(&landmarks).unwrap();
+
+        for origin in &landmarks {
+            for destination in &landmarks {
+                assert_relative_eq!(
+                    manhattan.distance(origin, destination),
+                    ruler.distance(origin, destination),
+                    max_relative = 1e-3
+                );
+            }
+        }
+
+        assert!(ruler.for_region(&[]).is_none());
+    }
+}
@@ -33,17 +33,51 @@
 //! let destination = ruler.destination(empire_state, 45.0, 100.0);
 //! ```
 //!
+//! Since `RulerMeasure` implements `Distance`, it also works with the generic algorithms
+//! of geo that are built on top of it, such as `Length` and `LengthMeasurable`:
+//!
+//! ```
+//! use geo::{line_string, line_measures::LengthMeasurable, Distance};
+//! use geo_ruler::geo::RulerMeasure;
+//!
+//! let route = line_string![
+//!     (x: -73.9897, y: 40.7411), // Flatiron Building
+//!     (x: -73.9857, y: 40.7484), // Empire State Building
+//!     (x: -73.9855, y: 40.7580), // Times Square
+//! ];
+//!
+//! let ruler = RulerMeasure::WGS84();
+//!
+//! // Sums the distances of the segments
+//! let length = route.length(&ruler);
+//!
+//! let segments: f64 = route
+//!     .lines()
+//!     .map(|line| ruler.distance(line.start_point(), line.end_point()))
+//!     .sum();
+//! assert_eq!(length, segments);
+//! ```
+//!
 //! # Feature Flags
 //!
-//! This crate provides optional feature flags to use alternative `atan2` implementations:
+//! Bearings use the `atan2` implementation selected by the features, in this order:
 //!
-//! - `atan2_deg3`: Use a 3rd degree polynomial approximation (faster but less accurate)
-//! - `atan2_deg5`: Use a 5th degree polynomial approximation (better accuracy with slight performance cost)
+//! - `atan2_deg5`: A 5th degree polynomial approximation (better accuracy with slight performance cost)
+//! - `atan2_deg3`: A 3rd degree polynomial approximation (faster but less accurate), a default feature
+//! - Rust's default `atan2`, only without both features, e.g. with `default-features = false`
 //!
-//! Without any features, Rust's default `atan2` implementation is used.
+//! A [`CheapRuler`] can override the features at runtime, see
+//! [`CheapRuler::with_atan2`] and [`CheapRuler::with_bearing_tolerance`], while
+//! `RulerMeasure` and `RulerRhumbMeasure` always follow the features.
 
-use crate::{CheapRuler, MaybeFromf32};
-use geo::{Bearing, CoordFloat, Destination, Distance, InterpolatePoint, Point, point};
+use crate::{AntimeridianMode, CheapRuler, MaybeFromf32, RulerError, Units};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use geo::{
+    Bearing, BoundingRect, Coord, CoordFloat, CoordsIter, Destination, Distance, Geometry,
+    GeometryCollection, InterpolatePoint, Line, LineString, LinesIter, MapCoords, MultiLineString,
+    Point, Polygon, Rect, point,
+};
 use num_traits::FloatConst;
 
 /// A fast approximation algorithm for geodesic calculations on Earth's surface.
@@ -55,10 +89,46 @@ use num_traits::FloatConst;
 /// is more important than absolute precision.
 ///
 /// Based on [Mapbox's Cheap Ruler](https://blog.mapbox.com/fast-geodesic-approximations-with-cheap-ruler-106f229ad016).
+///
+/// # Precision
+///
+/// The measure is generic over the coordinate type, but `f32` coordinates have only 24
+/// bits of mantissa: near ±180° of longitude consecutive `f32` values are ~1.5e-5° apart,
+/// which is about 1.7 meters at the equator. Distances between nearby points are then
+/// quantized to that resolution, regardless of how precise the ruler itself is.
+///
+/// Prefer `RulerMeasure<f64>` and keep coordinates in `f64` from the source data, using
+/// [`promote`] to convert geometries that are already stored as `f32` before measuring
+/// them, e.g. to accumulate long lengths in double precision.
+#[derive(Debug, Clone)]
 pub struct RulerMeasure<F: CoordFloat>(CheapRuler<F>);
 
+/// Converts a single precision geometry into a double precision one.
+///
+/// Promoting does not recover the precision lost when the coordinates were rounded to
+/// `f32`, but it lets the measurements themselves run in `f64`. See the precision notes
+/// of [`RulerMeasure`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Distance, Point};
+/// use geo_ruler::geo::{promote, RulerMeasure};
+///
+/// let empire_state: Point<f32> = point!(x: -73.9857, y: 40.7484);
+/// let flatiron: Point<f32> = point!(x: -73.9897, y: 40.7411);
+///
+/// let distance = RulerMeasure::<f64>::WGS84().distance(promote(&empire_state), promote(&flatiron));
+/// ```
+pub fn promote<G: MapCoords<f32, f64>>(geometry: &G) -> G::Output {
+    geometry.map_coords(|Coord { x, y }| Coord {
+        x: x.into(),
+        y: y.into(),
+    })
+}
+
 impl<F: CoordFloat + FloatConst + MaybeFromf32> RulerMeasure<F> {
-    /// Creates a new `Ruler` with custom ellipsoid parameters.
+    /// Creates a new `RulerMeasure` with custom ellipsoid parameters.
     ///
     /// This method allows you to create a ruler for measuring distances on planets
     /// or celestial bodies other than Earth, or when using a different Earth model.
@@ -88,12 +158,89 @@ impl<F: CoordFloat + FloatConst + MaybeFromf32> RulerMeasure<F> {
     pub fn new(major: F, minor: F) -> Self {
         Self(CheapRuler::new(&major, &minor))
     }
+
+    /// Creates a new `RulerMeasure` for a sphere with the given radius.
+    ///
+    /// Use this when only a mean radius is known, e.g. to match geo's `HaversineMeasure`.
+    ///
+    /// # Parameters
+    ///
+    /// - `radius`: The radius of the sphere in meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, Distance};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// // GRS80 mean radius, the same used by geo's Haversine
+    /// let ruler = RulerMeasure::<f64>::sphere(6_371_008.8);
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    /// let flatiron = point!(x: -73.9897, y: 40.7411);
+    ///
+    /// let distance = ruler.distance(empire_state, flatiron);
+    /// ```
+    ///
+    /// See also: [`new`](#method.new)
+    pub fn sphere(radius: F) -> Self {
+        Self(CheapRuler::sphere(&radius))
+    }
+
+    /// Returns a ruler measuring distances in the given units.
+    ///
+    /// Both the returned distances and the distance arguments, e.g. for `destination` or
+    /// `points_along_line`, are expressed in these units.
+    ///
+    /// # Parameters
+    ///
+    /// - `units`: The distance units to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, Distance};
+    /// use geo_ruler::{Units, geo::RulerMeasure};
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    /// let flatiron = point!(x: -73.9897, y: 40.7411);
+    ///
+    /// let distance = RulerMeasure::WGS84()
+    ///     .with_units(Units::Feet)
+    ///     .distance(empire_state, flatiron); // distance in feet
+    /// ```
+    pub fn with_units(self, units: Units) -> Self {
+        Self(self.0.with_units(units))
+    }
+
+    /// Sets how longitude differences are computed across the antimeridian.
+    ///
+    /// See [`CheapRuler::with_antimeridian`], with the default [`AntimeridianMode::Wrap`]
+    /// interpolated points also take the short way around and are wrapped into [-180°, 180°].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, InterpolatePoint};
+    /// use geo_ruler::{AntimeridianMode, geo::RulerMeasure};
+    ///
+    /// let fiji = point!(x: 179.0, y: -17.0);
+    /// let samoa = point!(x: -172.0, y: -13.8);
+    ///
+    /// // halfway around the world, through Africa
+    /// let midpoint = RulerMeasure::WGS84()
+    ///     .with_antimeridian(AntimeridianMode::Raw)
+    ///     .point_at_ratio_between(fiji, samoa, 0.5);
+    /// ```
+    pub fn with_antimeridian(self, mode: AntimeridianMode) -> Self {
+        Self(self.0.with_antimeridian(mode))
+    }
 }
 
 impl<F: CoordFloat + FloatConst + From<f32>> RulerMeasure<F> {
     /// The standard WGS84 ellipsoid parameters for Earth measurements.
     ///
-    /// This constant provides a pre-configured `Ruler` using the WGS84 reference ellipsoid,
+    /// This constant provides a pre-configured `RulerMeasure` using the WGS84 reference ellipsoid,
     /// which is the standard used in GPS and most modern mapping applications.
     ///
     /// # Examples
@@ -277,16 +424,18 @@ impl<F: CoordFloat + FloatConst + MaybeFromf32> InterpolatePoint<F> for RulerMea
         distance_from_start: F,
     ) -> Point<F> {
         let bearing = self.0.bearing(&start.into(), &end.into());
-        self.0
-            .destination(&start.into(), &bearing, &distance_from_start)
-            .into()
+        let [lon, lat] = self
+            .0
+            .destination(&start.into(), &bearing, &distance_from_start);
+
+        point!(x: self.0.wrap_lon(&lon), y: lat)
     }
 
     /// Returns a point along a path between two points, at a specified ratio of the total path length.
     ///
     /// For the Cheap Ruler implementation, this uses a simple linear interpolation between
     /// the coordinates rather than a true geodesic calculation, which works well for
-    /// city-scale distances.
+    /// city-scale distances. See [`CheapRuler::interpolate`] for the antimeridian handling.
     ///
     /// # Parameters
     ///
@@ -318,10 +467,9 @@ impl<F: CoordFloat + FloatConst + MaybeFromf32> InterpolatePoint<F> for RulerMea
         end: Point<F>,
         ratio_from_start: F,
     ) -> Point<F> {
-        let dx = (end.x() - start.x()) * ratio_from_start;
-        let dy = (end.y() - start.y()) * ratio_from_start;
-
-        point!(x : start.x() + dx, y: start.y() + dy)
+        self.0
+            .interpolate(&start.into(), &end.into(), &ratio_from_start)
+            .into()
     }
 
     /// Returns an iterator of evenly spaced points along a line between two points.
@@ -373,268 +521,3604 @@ impl<F: CoordFloat + FloatConst + MaybeFromf32> InterpolatePoint<F> for RulerMea
     }
 }
 
-/// Helper iterator for generating evenly spaced points along a line
-struct LinePointInterpolator<'ruler, F: CoordFloat> {
-    /// Starting point of the line
-    start: Point<F>,
-    /// Ending point of the line
-    end: Point<F>,
-    /// Current offset ratio along the line (0.0 to 1.0)
-    offset: F,
-    /// Step size for each iteration as a ratio of the total distance
-    step: F,
-    /// Reference to the ruler for calculations
-    ruler: &'ruler RulerMeasure<F>,
-    /// Whether to include the endpoint as the final item
-    include_last: bool,
-}
-
-impl<'ruler, F: CoordFloat + FloatConst + MaybeFromf32> LinePointInterpolator<'ruler, F> {
-    /// Creates a new line point iterator.
+impl<F: CoordFloat + FloatConst + MaybeFromf32> RulerMeasure<F> {
+    /// Returns a `Vec` of evenly spaced points along a line between two points.
+    ///
+    /// Same as [`points_along_line`](#method.points_along_line), but the number of points
+    /// is computed up front so the result is allocated once, with the exact capacity.
     ///
     /// # Parameters
     ///
-    /// - `start`: The starting point
-    /// - `end`: The ending point
-    /// - `ruler`: Reference to the ruler for calculations
-    /// - `step`: Step size as a ratio of the total distance
-    /// - `include_ends`: Whether to include start and end points
-    fn new(
+    /// - `start`: Starting point with coordinates in degrees (longitude, latitude)
+    /// - `end`: Ending point with coordinates in degrees (longitude, latitude)
+    /// - `max_distance`: Maximum distance between consecutive points in meters
+    /// - `include_ends`: Whether to include the start and end points in the result
+    ///
+    /// # Returns
+    ///
+    /// A vector with the points along the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484); // Empire State Building
+    /// let flatiron = point!(x: -73.9897, y: 40.7411);  // Flatiron Building
+    ///
+    /// let points = RulerMeasure::WGS84().collect_points_along_line(empire_state, flatiron, 50.0, true);
+    ///
+    /// assert_eq!(points.first().unwrap(), &empire_state);
+    /// assert_eq!(points.last().unwrap(), &flatiron);
+    /// ```
+    pub fn collect_points_along_line(
+        &self,
         start: Point<F>,
         end: Point<F>,
-        ruler: &'ruler RulerMeasure<F>,
-        step: F,
+        max_distance: F,
         include_ends: bool,
-    ) -> Self {
-        let offset = match include_ends {
-            true => F::zero(),
-            false => step,
-        };
-
-        Self {
-            start,
-            end,
-            ruler,
-            include_last: include_ends,
-            offset,
-            step,
-        }
-    }
-
-    /// Returns point along the line at the current offset and advances the offset.
-    fn advance(&mut self) -> Point<F> {
-        let current = self
-            .ruler
-            .point_at_ratio_between(self.start, self.end, self.offset);
+    ) -> Vec<Point<F>> {
+        let distance = self.0.distance(&start.into(), &end.into());
+        let step = max_distance / distance;
 
-        self.offset = self.offset + self.step;
+        let interpolator = LinePointInterpolator::new(start, end, self, step, include_ends);
 
-        current
-    }
+        let mut points = Vec::with_capacity(interpolator.remaining());
+        points.extend(interpolator);
 
-    /// Should be called at the end of the line to potentially
-    /// include the end point.
-    fn stop(&mut self) -> Option<Point<F>> {
-        if self.include_last {
-            self.include_last = false;
-            Some(self.end)
-        } else {
-            None
-        }
+        points
     }
-}
 
-impl<F: CoordFloat + FloatConst + MaybeFromf32> Iterator for LinePointInterpolator<'_, F> {
-    type Item = Point<F>;
+    /// Writes evenly spaced points along a line between two points into a buffer.
+    ///
+    /// Same as [`points_along_line`](#method.points_along_line), but the points are
+    /// written into a caller provided buffer, so no allocation is needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Starting point with coordinates in degrees (longitude, latitude)
+    /// - `end`: Ending point with coordinates in degrees (longitude, latitude)
+    /// - `max_distance`: Maximum distance between consecutive points in meters
+    /// - `include_ends`: Whether to include the start and end points in the result
+    /// - `out`: Output buffer receiving the points along the path
+    ///
+    /// # Returns
+    ///
+    /// The number of points written at the start of `out`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RulerError::BufferTooSmall`] with the required length if `out` cannot
+    /// hold all the points, in which case nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, Point};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484); // Empire State Building
+    /// let flatiron = point!(x: -73.9897, y: 40.7411);  // Flatiron Building
+    ///
+    /// let mut buffer = [Point::new(0.0, 0.0); 32];
+    ///
+    /// let count = RulerMeasure::WGS84()
+    ///     .points_along_line_into(empire_state, flatiron, 50.0, true, &mut buffer)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(buffer[0], empire_state);
+    /// assert_eq!(buffer[count - 1], flatiron);
+    /// ```
+    ///
+    /// See also: [`collect_points_along_line`](#method.collect_points_along_line)
+    pub fn points_along_line_into(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        max_distance: F,
+        include_ends: bool,
+        out: &mut [Point<F>],
+    ) -> Result<usize, RulerError> {
+        let distance = self.0.distance(&start.into(), &end.into());
+        let step = max_distance / distance;
 
-    /// Advances along the line until reaching the end, then potentially
-    /// yields the end point as the final item.
-    fn next(&mut self) -> Option<Self::Item> {
-        (self.offset < F::one())
-            .then(|| self.advance())
-            .or_else(|| self.stop())
-    }
-}
+        let interpolator = LinePointInterpolator::new(start, end, self, step, include_ends);
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use approx::{assert_relative_eq, relative_eq};
-    use geo::{Geodesic, GeodesicMeasure};
+        let required = interpolator.remaining();
 
-    const RELATIVE_ERROR: f64 = 0.01;
-    const EPSILON: f64 = 0.01;
+        if required > out.len() {
+            return Err(RulerError::BufferTooSmall { required });
+        }
 
-    #[test]
-    fn distance() {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
-        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let mut count = 0;
 
-        let distance_geodesic = Geodesic.distance(empire_state, flatiron);
-        let distance_ruler = RulerMeasure::WGS84().distance(empire_state, flatiron);
+        for (slot, point) in out.iter_mut().zip(interpolator) {
+            *slot = point;
+            count += 1;
+        }
 
-        assert_relative_eq!(
-            distance_geodesic,
-            distance_ruler,
-            max_relative = RELATIVE_ERROR
-        );
+        Ok(count)
     }
 
-    #[test]
-    fn destination() {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
-        let distance = 100.;
-        let bearing = 45.;
-
-        let geodesic_point = Geodesic.destination(empire_state, bearing, distance);
-        let ruler_point = RulerMeasure::WGS84().destination(empire_state, bearing, distance);
+    /// Refills a `Vec` with evenly spaced points along a line between two points.
+    ///
+    /// Same as [`collect_points_along_line`](#method.collect_points_along_line), but the
+    /// buffer is cleared and refilled instead of allocated, so a buffer reused across
+    /// calls, e.g. in a rendering loop, only grows when more points than ever are needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Starting point with coordinates in degrees (longitude, latitude)
+    /// - `end`: Ending point with coordinates in degrees (longitude, latitude)
+    /// - `max_distance`: Maximum distance between consecutive points in meters
+    /// - `include_ends`: Whether to include the start and end points in the result
+    /// - `buf`: Buffer receiving the points along the path, its previous content is dropped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484); // Empire State Building
+    /// let flatiron = point!(x: -73.9897, y: 40.7411);  // Flatiron Building
+    /// let times_square = point!(x: -73.9855, y: 40.7580); // Times Square
+    ///
+    /// let ruler = RulerMeasure::WGS84();
+    /// let mut buffer = Vec::new();
+    ///
+    /// for destination in [flatiron, times_square] {
+    ///     ruler.points_along_line_collect_into(empire_state, destination, 50.0, true, &mut buffer);
+    ///
+    ///     assert_eq!(buffer.last().unwrap(), &destination);
+    /// }
+    /// ```
+    ///
+    /// See also: [`points_along_line_into`](#method.points_along_line_into)
+    pub fn points_along_line_collect_into(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        max_distance: F,
+        include_ends: bool,
+        buf: &mut Vec<Point<F>>,
+    ) {
+        let distance = self.0.distance(&start.into(), &end.into());
+        let step = max_distance / distance;
 
-        let error = Geodesic.distance(geodesic_point, ruler_point) / distance;
+        let interpolator = LinePointInterpolator::new(start, end, self, step, include_ends);
 
-        assert_relative_eq!(0., error, epsilon = EPSILON, max_relative = RELATIVE_ERROR)
+        buf.clear();
+        buf.reserve(interpolator.remaining());
+        buf.extend(interpolator);
     }
 
-    #[test]
-    fn bearing() {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
-        let flatiron = point!(x: -73.9897, y: 40.7411);
-
-        assert_relative_eq!(
-            Geodesic.bearing(empire_state, flatiron).rem_euclid(360.),
-            RulerMeasure::WGS84()
-                .bearing(empire_state, flatiron)
-                .rem_euclid(360.),
+    /// Returns evenly spaced points along a whole line string.
+    ///
+    /// Unlike [`points_along_line`](#method.points_along_line), which covers a single
+    /// segment, the points are placed every `max_distance` along the entire path, with
+    /// the spacing carried over from one segment to the next instead of restarting at
+    /// every vertex.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `max_distance`: Distance along the path between consecutive points in meters, a
+    ///   non-positive distance yields the ends only
+    /// - `include_ends`: Whether to include the first and last coordinates of the line
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the points along the path, at `max_distance`, `2 * max_distance`,
+    /// ... meters from the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let route = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ];
+    ///
+    /// // a marker every 250 meters of the walk
+    /// let markers: Vec<_> = RulerMeasure::WGS84()
+    ///     .points_along_line_string(&route, 250.0, false)
+    ///     .collect();
+    /// ```
+    ///
+    /// See also: [`split_by_length`](#method.split_by_length)
+    pub fn points_along_line_string<'a>(
+        &'a self,
+        line: &'a LineString<F>,
+        max_distance: F,
+        include_ends: bool,
+    ) -> impl Iterator<Item = Point<F>> + 'a {
+        let step = if max_distance > F::zero() {
+            max_distance
+        } else {
+            F::infinity()
+        };
+
+        let mut segments = line.lines().map(|segment| {
+            (
+                segment,
+                self.0.distance(&segment.start.into(), &segment.end.into()),
+            )
+        });
+        let mut current = segments.next();
+
+        // distance along the path to the start of the current segment, and index of the next
+        // point, at `index * step` rather than accumulated so it keeps moving in f32
+        let mut walked = F::zero();
+        let mut index = usize::from(!include_ends);
+
+        let mut first = include_ends.then(|| line.0.first()).flatten();
+        let mut last = include_ends.then(|| line.0.last()).flatten();
+
+        core::iter::from_fn(move || {
+            if line.0.len() < 2 {
+                return first.take().map(|coord| Point(*coord));
+            }
+
+            while let Some((segment, length)) = current {
+                let target = match index {
+                    0 => F::zero(),
+                    index => <F as num_traits::NumCast>::from(index).unwrap() * step,
+                };
+
+                if target < walked + length {
+                    let ratio = (target - walked) / length;
+                    index += 1;
+
+                    return Some(
+                        self.0
+                            .interpolate(&segment.start.into(), &segment.end.into(), &ratio)
+                            .into(),
+                    );
+                }
+
+                walked = walked + length;
+                current = segments.next();
+            }
+
+            last.take().map(|coord| Point(*coord))
+        })
+    }
+
+    /// Returns evenly spaced points along a line between two points, in metric steps.
+    ///
+    /// Unlike [`points_along_line`](#method.points_along_line), which interpolates
+    /// linearly in longitude and latitude, the points are placed at equal distances, each
+    /// step measured with the scaling halfway in latitude along it. The spacing stays
+    /// uniform on long diagonal or north-south lines, where the scaling changes along
+    /// the way.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Starting point with coordinates in degrees (longitude, latitude)
+    /// - `end`: Ending point with coordinates in degrees (longitude, latitude)
+    /// - `max_distance`: Maximum distance between consecutive points in meters, a
+    ///   non-positive distance yields the ends only
+    /// - `include_ends`: Whether to include the start and end points in the result
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields points along the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let new_york = point!(x: -74.0060, y: 40.7128);
+    /// let boston = point!(x: -71.0589, y: 42.3601);
+    ///
+    /// // a point every 10 km along the way
+    /// let points: Vec<_> = RulerMeasure::WGS84()
+    ///     .points_along_line_metric(new_york, boston, 10_000.0, true)
+    ///     .collect();
+    ///
+    /// assert_eq!(points.first(), Some(&new_york));
+    /// assert_eq!(points.last(), Some(&boston));
+    /// ```
+    ///
+    /// See also: [`points_along_line`](#method.points_along_line)
+    pub fn points_along_line_metric<'a>(
+        &'a self,
+        start: Point<F>,
+        end: Point<F>,
+        max_distance: F,
+        include_ends: bool,
+    ) -> impl Iterator<Item = Point<F>> + 'a {
+        let end: [F; 2] = end.into();
+
+        // the distance from a point to the end, with the scaling halfway in latitude to
+        // another point
+        let to_end = move |from: &[F; 2], towards: &[F; 2]| {
+            let coefs = self
+                .0
+                .coefs_at(&((from[1] + towards[1]) / (F::one() + F::one())));
+            self.0.distance_with(&coefs, from, &end)
+        };
+
+        let start: [F; 2] = start.into();
+        let total = to_end(&start, &end);
+
+        let steps = if max_distance > F::zero() {
+            (total / max_distance).ceil().max(F::one())
+        } else {
+            F::one()
+        };
+        let mut left = steps.to_usize().unwrap_or(1);
+
+        let mut current = start;
+        let mut first = include_ends.then_some(start.into());
+        let mut last = include_ends.then_some(end.into());
+
+        core::iter::from_fn(move || {
+            if let Some(start) = first.take() {
+                return Some(start);
+            }
+
+            if left > 1 {
+                // the remaining distance split evenly over the remaining steps, the step
+                // placed with the scaling halfway along it
+                let remaining = to_end(&current, &end);
+                let step = remaining / <F as num_traits::NumCast>::from(left).unwrap();
+
+                let next = self.0.interpolate(&current, &end, &(step / remaining));
+
+                let ratio = step / to_end(&current, &next);
+                current = self.0.interpolate(&current, &end, &ratio);
+                left -= 1;
+
+                return Some(current.into());
+            }
+
+            last.take()
+        })
+    }
+}
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> RulerMeasure<F> {
+    /// Returns a new coordinate having traveled the given distance along the given bearing
+    /// from origin.
+    ///
+    /// Same as [`destination`](#method.destination), taking and returning a `Coord` to
+    /// avoid the conversions to and from `Point` in coordinate based pipelines.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting coordinate in degrees (longitude, latitude)
+    /// - `bearing`: Direction to travel in degrees (0° = North, 90° = East, etc.)
+    /// - `distance`: Distance to travel in meters
+    ///
+    /// # Returns
+    ///
+    /// A new coordinate in degrees (longitude, latitude)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::coord;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = coord! { x: -73.9857, y: 40.7484 };
+    ///
+    /// // 100 meters northeast of the Empire State Building
+    /// let destination = RulerMeasure::WGS84().destination_coord(empire_state, 45.0, 100.0);
+    /// ```
+    pub fn destination_coord(&self, origin: Coord<F>, bearing: F, distance: F) -> Coord<F> {
+        self.0
+            .destination(&origin.into(), &bearing, &distance)
+            .into()
+    }
+
+    /// Measures how well a destination closes back onto its origin.
+    ///
+    /// Travels from the origin with [`destination`](#method.destination), then measures
+    /// the [`distance`](#method.distance) back from the destination to the origin. The
+    /// way back uses the scaling at the latitude of the destination, so the mismatch
+    /// grows with the distance and serves as a self-diagnostic of the approximation.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point with coordinates in degrees (longitude, latitude)
+    /// - `bearing`: Direction to travel in degrees (0° = North, 90° = East, etc.)
+    /// - `distance`: Distance to travel in meters
+    ///
+    /// # Returns
+    ///
+    /// The relative difference between the distance back and the distance traveled, or
+    /// zero if the distance traveled is not positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    ///
+    /// let error = RulerMeasure::WGS84().round_trip_error(empire_state, 30.0, 5_000.0);
+    ///
+    /// assert!(error < 1e-3);
+    /// ```
+    ///
+    /// See also: [`distance_with_error`](#method.distance_with_error)
+    pub fn round_trip_error(&self, origin: Point<F>, bearing: F, distance: F) -> F {
+        if distance <= F::zero() {
+            return F::zero();
+        }
+
+        let origin = origin.into();
+        let destination = self.0.destination(&origin, &bearing, &distance);
+        let back = self.0.distance(&destination, &origin);
+
+        (back - distance).abs() / distance
+    }
+
+    /// Translates every coordinate of a geometry by a metric offset.
+    ///
+    /// Each vertex is shifted using the scaling factors at its own latitude, so
+    /// the offset is applied in meters regardless of where the geometry lies.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: Any `geo` geometry with coordinates in degrees (longitude, latitude)
+    /// - `dx`: Distance to shift east in meters (negative values shift west)
+    /// - `dy`: Distance to shift north in meters (negative values shift south)
+    ///
+    /// # Returns
+    ///
+    /// A new geometry of the same type with every coordinate translated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let block = polygon![
+    ///     (x: -73.9857, y: 40.7484),
+    ///     (x: -73.9847, y: 40.7484),
+    ///     (x: -73.9847, y: 40.7494),
+    ///     (x: -73.9857, y: 40.7494),
+    /// ];
+    ///
+    /// // Move the block 50 meters north
+    /// let moved = RulerMeasure::WGS84().translate(&block, 0.0, 50.0);
+    /// ```
+    pub fn translate<G: MapCoords<F, F, Output = G>>(&self, geometry: &G, dx: F, dy: F) -> G {
+        geometry.map_coords(|coord| self.0.offset(&coord.into(), &dx, &dy).into())
+    }
+
+    /// Rotates a geometry about a pivot point by an angle, preserving metric proportions.
+    ///
+    /// Coordinates are converted to local east and north offsets in meters from the
+    /// pivot, using the scaling factors at the pivot, rotated, and converted back. Unlike
+    /// rotating raw longitudes and latitudes, shapes are not skewed away from the equator.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: Any `geo` geometry with coordinates in degrees (longitude, latitude)
+    /// - `angle`: Rotation angle in degrees, positive values rotate counter-clockwise
+    /// - `pivot`: The point to rotate about, with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// A new geometry of the same type with every coordinate rotated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, line_string};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    /// let street = line_string![(x: -73.9857, y: 40.7484), (x: -73.9897, y: 40.7411)];
+    ///
+    /// // Turn the street a quarter counter-clockwise around the Empire State Building
+    /// let rotated = RulerMeasure::WGS84().rotate(&street, 90.0, empire_state);
+    /// ```
+    pub fn rotate<G: MapCoords<F, F, Output = G>>(
+        &self,
+        geometry: &G,
+        angle: F,
+        pivot: Point<F>,
+    ) -> G {
+        let (sin, cos) = angle.to_radians().sin_cos();
+
+        self.transform_about(geometry, pivot, |[x, y]| {
+            [x * cos - y * sin, x * sin + y * cos]
+        })
+    }
+
+    /// Scales a geometry about a pivot point by a factor, preserving metric proportions.
+    ///
+    /// Coordinates are converted to local east and north offsets in meters from the
+    /// pivot, using the scaling factors at the pivot, scaled, and converted back, so every
+    /// distance to the pivot is multiplied by the factor in meters rather than in degrees.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: Any `geo` geometry with coordinates in degrees (longitude, latitude)
+    /// - `factor`: Scale factor, values below one shrink the geometry towards the pivot
+    /// - `about`: The point to scale about, with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// A new geometry of the same type with every coordinate scaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, polygon};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let block = polygon![
+    ///     (x: -73.9857, y: 40.7484),
+    ///     (x: -73.9847, y: 40.7484),
+    ///     (x: -73.9847, y: 40.7494),
+    ///     (x: -73.9857, y: 40.7494),
+    /// ];
+    ///
+    /// // Shrink the block to 90% about its center
+    /// let center = point!(x: -73.9852, y: 40.7489);
+    /// let shrunk = RulerMeasure::WGS84().scale_metric(&block, 0.9, center);
+    /// ```
+    ///
+    /// See also: [`rotate`](#method.rotate)
+    pub fn scale_metric<G: MapCoords<F, F, Output = G>>(
+        &self,
+        geometry: &G,
+        factor: F,
+        about: Point<F>,
+    ) -> G {
+        self.transform_about(geometry, about, |[x, y]| [x * factor, y * factor])
+    }
+
+    /// Applies a transformation to the metric offsets of every coordinate from a pivot point.
+    ///
+    /// The offsets are computed with the scaling factors at the pivot, taking the short way
+    /// across the antimeridian, transformed, and converted back into degrees.
+    fn transform_about<G: MapCoords<F, F, Output = G>>(
+        &self,
+        geometry: &G,
+        pivot: Point<F>,
+        transform: impl Fn([F; 2]) -> [F; 2],
+    ) -> G {
+        let [kx, ky] = self.0.coefs(&pivot.into());
+
+        geometry.map_coords(|coord| {
+            let offset = [
+                self.0.delta_lon(&pivot.x(), &coord.x) * kx,
+                (coord.y - pivot.y()) * ky,
+            ];
+
+            let [x, y] = transform(offset);
+
+            Coord {
+                x: self.0.wrap_lon(&(pivot.x() + x / kx)),
+                y: pivot.y() + y / ky,
+            }
+        })
+    }
+
+    /// Calculates the length of a `Line`.
+    ///
+    /// Convenience wrapper over [`distance`](#method.distance) from the start to the end
+    /// of the line.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The length of the line in meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, Line};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let line = Line::new(coord!(x: -73.9857, y: 40.7484), coord!(x: -73.9897, y: 40.7411));
+    ///
+    /// let length = RulerMeasure::WGS84().line_length(&line);
+    /// ```
+    ///
+    /// See also: [`line_bearing`](#method.line_bearing)
+    pub fn line_length(&self, line: &Line<F>) -> F {
+        self.0.distance(&line.start.into(), &line.end.into())
+    }
+
+    /// Calculates the bearing of a `Line`, from its start to its end.
+    ///
+    /// Convenience wrapper over [`bearing`](#method.bearing) from the start to the end
+    /// of the line.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The bearing in degrees, same as [`bearing`](#method.bearing)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, Line};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let line = Line::new(coord!(x: -73.9857, y: 40.7484), coord!(x: -73.9897, y: 40.7411));
+    ///
+    /// let bearing = RulerMeasure::WGS84().line_bearing(&line);
+    /// ```
+    ///
+    /// See also: [`line_length`](#method.line_length)
+    pub fn line_bearing(&self, line: &Line<F>) -> F {
+        self.bearing(line.start.into(), line.end.into())
+    }
+
+    /// Returns a point along a `Line`, at a specified ratio of its length.
+    ///
+    /// Convenience wrapper over [`point_at_ratio_between`](#method.point_at_ratio_between)
+    /// using the start and end of the line.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line with coordinates in degrees (longitude, latitude)
+    /// - `ratio_from_start`: Ratio of the distance from start (0.0 = start, 1.0 = end)
+    ///
+    /// # Returns
+    ///
+    /// A point that is at the specified ratio along the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, Line};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let line = Line::new(coord!(x: -73.9857, y: 40.7484), coord!(x: -73.9897, y: 40.7411));
+    ///
+    /// // Find the midpoint between Empire State and Flatiron
+    /// let midpoint = RulerMeasure::WGS84().point_at_ratio_on_line(&line, 0.5);
+    /// ```
+    ///
+    /// See also: [`point_at_distance_on_line`](#method.point_at_distance_on_line)
+    pub fn point_at_ratio_on_line(&self, line: &Line<F>, ratio_from_start: F) -> Point<F> {
+        self.point_at_ratio_between(line.start_point(), line.end_point(), ratio_from_start)
+    }
+
+    /// Returns a point along a `Line`, at a fraction of its length.
+    ///
+    /// Mirrors geo's `LineInterpolatePoint` for a `Line`: like
+    /// [`point_at_ratio_on_line`](#method.point_at_ratio_on_line), but the fraction is
+    /// clamped to the line, so the result is never beyond its ends.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line with coordinates in degrees (longitude, latitude)
+    /// - `fraction`: Fraction of the length from the start, clamped between 0.0 and 1.0
+    ///
+    /// # Returns
+    ///
+    /// A point on the line at the specified fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, Line};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let line = Line::new(coord!(x: -73.9857, y: 40.7484), coord!(x: -73.9897, y: 40.7411));
+    ///
+    /// let ruler = RulerMeasure::WGS84();
+    ///
+    /// let quarter = ruler.line_interpolate_point(&line, 0.25);
+    /// assert_eq!(ruler.line_interpolate_point(&line, 2.0), line.end_point());
+    /// ```
+    ///
+    /// See also: [`point_at_ratio_on_line`](#method.point_at_ratio_on_line)
+    pub fn line_interpolate_point(&self, line: &Line<F>, fraction: F) -> Point<F> {
+        let fraction = fraction.max(F::zero()).min(F::one());
+
+        self.point_at_ratio_on_line(line, fraction)
+    }
+
+    /// Returns a point along a `Line`, at a specified distance from its start.
+    ///
+    /// Convenience wrapper over [`point_at_distance_between`](#method.point_at_distance_between)
+    /// using the start and end of the line.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line with coordinates in degrees (longitude, latitude)
+    /// - `distance_from_start`: Distance from start in meters
+    ///
+    /// # Returns
+    ///
+    /// A point that is the specified distance along the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, Line};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let line = Line::new(coord!(x: -73.9857, y: 40.7484), coord!(x: -73.9897, y: 40.7411));
+    ///
+    /// // Find a point 100 meters from Empire State along the path to Flatiron
+    /// let point = RulerMeasure::WGS84().point_at_distance_on_line(&line, 100.0);
+    /// ```
+    ///
+    /// See also: [`point_at_ratio_on_line`](#method.point_at_ratio_on_line)
+    pub fn point_at_distance_on_line(&self, line: &Line<F>, distance_from_start: F) -> Point<F> {
+        self.point_at_distance_between(line.start_point(), line.end_point(), distance_from_start)
+    }
+
+    /// Calculates the minimum distance from a point to any geometry in a collection.
+    ///
+    /// Points are measured directly, while linear and areal geometries are measured to
+    /// their closest segment (for polygons, the closest segment of any of their rings).
+    /// Nested collections are searched recursively.
+    ///
+    /// # Parameters
+    ///
+    /// - `collection`: The geometries to search, with coordinates in degrees (longitude, latitude)
+    /// - `point`: The query point with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The distance in meters to the nearest geometry, or infinity if the collection is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, line_string, Geometry, GeometryCollection};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let collection = GeometryCollection::new_from(vec![
+    ///     Geometry::Point(point!(x: -73.9897, y: 40.7411)), // Flatiron Building
+    ///     Geometry::LineString(line_string![
+    ///         (x: -73.9819, y: 40.7681), // Broadway & 59th
+    ///         (x: -73.9855, y: 40.7580), // Times Square
+    ///     ]),
+    /// ]);
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    ///
+    /// let distance = RulerMeasure::WGS84().distance_to_collection(&collection, empire_state);
+    /// ```
+    pub fn distance_to_collection(&self, collection: &GeometryCollection<F>, point: Point<F>) -> F {
+        collection
+            .iter()
+            .map(|geometry| self.distance_to_geometry(geometry, point))
+            .fold(F::infinity(), F::min)
+    }
+
+    /// Finds the nearest point of any geometry to a query point, e.g. to snap a position
+    /// to whatever feature is closest.
+    ///
+    /// Dispatches on the geometry type: points are candidates themselves, while linear and
+    /// areal geometries are projected onto their closest segment (for polygons, the closest
+    /// segment of any of their rings, so their boundary). Collections are searched
+    /// recursively.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: The geometry with coordinates in degrees (longitude, latitude)
+    /// - `point`: The query point with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The nearest point and its distance to the query point in meters, or `None` if the
+    /// geometry is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, point, Geometry};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let broadway = Geometry::LineString(line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ]);
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    ///
+    /// let (snapped, distance) = RulerMeasure::WGS84()
+    ///     .nearest_point_on_geometry(&broadway, empire_state)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// See also: [`closest_point_detailed`](#method.closest_point_detailed),
+    /// [`distance_to_collection`](#method.distance_to_collection)
+    pub fn nearest_point_on_geometry(
+        &self,
+        geometry: &Geometry<F>,
+        point: Point<F>,
+    ) -> Option<(Point<F>, F)> {
+        let origin = point.into();
+
+        let nearest = match geometry {
+            Geometry::Point(p) => Some(((*p).into(), self.0.distance(&origin, &(*p).into()))),
+            Geometry::MultiPoint(points) => points
+                .iter()
+                .map(|p| ((*p).into(), self.0.distance(&origin, &(*p).into())))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal)),
+            Geometry::Line(line) => self.nearest_on_lines(line, &origin),
+            Geometry::LineString(line) => self.nearest_on_lines(line, &origin),
+            Geometry::MultiLineString(lines) => self.nearest_on_lines(lines, &origin),
+            Geometry::Polygon(polygon) => self.nearest_on_lines(polygon, &origin),
+            Geometry::MultiPolygon(polygons) => self.nearest_on_lines(polygons, &origin),
+            Geometry::Rect(rect) => self.nearest_on_lines(rect, &origin),
+            Geometry::Triangle(triangle) => self.nearest_on_lines(triangle, &origin),
+            Geometry::GeometryCollection(collection) => {
+                return collection
+                    .iter()
+                    .filter_map(|geometry| self.nearest_point_on_geometry(geometry, point))
+                    .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+            }
+        };
+
+        nearest.map(|(closest, distance): ([F; 2], F)| (closest.into(), distance))
+    }
+
+    /// Simplifies a line string using the Douglas–Peucker algorithm with a metric tolerance.
+    ///
+    /// Unlike geo's `Simplify`, which measures the tolerance in coordinate units (degrees),
+    /// vertices are compared using the ruler's cross-track distance.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `tolerance`: Maximum distance of a removed vertex to the simplified line in meters
+    ///
+    /// # Returns
+    ///
+    /// The simplified line string, always keeping the first and last coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let street = line_string![
+    ///     (x: -73.9900, y: 40.7400),
+    ///     (x: -73.9880, y: 40.74001), // GPS jitter of about a meter
+    ///     (x: -73.9860, y: 40.7400),
+    /// ];
+    ///
+    /// let simplified = RulerMeasure::WGS84().simplify(&street, 5.0);
+    ///
+    /// assert_eq!(simplified.0.len(), 2);
+    /// ```
+    ///
+    /// See also: [`simplify_vw`](#method.simplify_vw)
+    pub fn simplify(&self, line: &LineString<F>, tolerance: F) -> LineString<F> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+
+        self.0.simplify(&points, &tolerance).into()
+    }
+
+    /// Simplifies a line string with a metric tolerance, without introducing
+    /// self-intersections.
+    ///
+    /// Same as [`simplify`](#method.simplify), but vertices whose removal would make the
+    /// simplified line string cross itself are kept, e.g. for rendering boundaries.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `tolerance`: Maximum distance of a removed vertex to the simplified line in meters
+    ///
+    /// # Returns
+    ///
+    /// The simplified line string, always keeping the first and last coordinates.
+    ///
+    /// See also: [`CheapRuler::simplify_preserve_topology`]
+    pub fn simplify_preserve_topology(&self, line: &LineString<F>, tolerance: F) -> LineString<F> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+
+        self.0
+            .simplify_preserve_topology(&points, &tolerance)
+            .into()
+    }
+
+    /// Simplifies a line string using the Visvalingam–Whyatt algorithm with a metric threshold.
+    ///
+    /// Unlike geo's `SimplifyVw`, which measures areas in squared coordinate units
+    /// (degrees²), the effective area of each vertex is computed by the ruler.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `min_area`: Minimum effective area of a vertex to be kept in square meters
+    ///
+    /// # Returns
+    ///
+    /// The simplified line string, always keeping the first and last coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let street = line_string![
+    ///     (x: -73.9900, y: 40.7400),
+    ///     (x: -73.9880, y: 40.74001), // GPS jitter of about a meter
+    ///     (x: -73.9860, y: 40.7400),
+    /// ];
+    ///
+    /// let simplified = RulerMeasure::WGS84().simplify_vw(&street, 1_000.0);
+    ///
+    /// assert_eq!(simplified.0.len(), 2);
+    /// ```
+    ///
+    /// See also: [`simplify`](#method.simplify)
+    pub fn simplify_vw(&self, line: &LineString<F>, min_area: F) -> LineString<F> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+
+        self.0.simplify_area(&points, &min_area).into()
+    }
+
+    /// Calculates the total length of the segments of any linear or areal geometry.
+    ///
+    /// Line strings are measured as open paths, while polygon rings, rectangles and
+    /// triangles are closed, so their closing segment is included and the result is
+    /// the perimeter, including the perimeter of any interior ring. Segments of
+    /// different parts are never joined together.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: The geometry with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The sum of the segment lengths in meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, polygon};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let ruler = RulerMeasure::WGS84();
+    ///
+    /// let route = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    /// ];
+    ///
+    /// let block = polygon![
+    ///     (x: -73.9857, y: 40.7484),
+    ///     (x: -73.9847, y: 40.7484),
+    ///     (x: -73.9847, y: 40.7494),
+    ///     (x: -73.9857, y: 40.7494),
+    /// ];
+    ///
+    /// let length = ruler.total_length(&route);
+    /// let perimeter = ruler.total_length(&block);
+    /// ```
+    pub fn total_length<'a, G: LinesIter<'a, Scalar = F>>(&self, geometry: &'a G) -> F {
+        geometry
+            .lines_iter()
+            .map(|line| self.0.distance(&line.start.into(), &line.end.into()))
+            .fold(F::zero(), |total, distance| total + distance)
+    }
+
+    /// Builds a circle around a center point as a polygon.
+    ///
+    /// See [`CheapRuler::circle`], the exterior ring is closed and wound counter-clockwise as
+    /// `geo` expects for exterior rings.
+    ///
+    /// # Parameters
+    ///
+    /// - `center`: The center with coordinates in degrees (longitude, latitude)
+    /// - `radius`: Radius of the circle in meters
+    /// - `steps`: Number of distinct vertices, at least 3 are used
+    ///
+    /// # Returns
+    ///
+    /// A polygon approximating the circle, without interior rings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    ///
+    /// // Everything within 500 meters of the Empire State Building
+    /// let area = RulerMeasure::WGS84().circle(empire_state, 500.0, 64);
+    /// ```
+    pub fn circle(&self, center: Point<F>, radius: F, steps: usize) -> Polygon<F> {
+        let mut ring = self.0.circle(&center.into(), &radius, steps);
+        ring.reverse();
+
+        Polygon::new(ring.into(), Vec::new())
+    }
+
+    /// Adds vertices to a line string so that no segment is longer than a given distance.
+    ///
+    /// See [`CheapRuler::densify`], the original vertices are kept and the added ones take
+    /// the short way across the antimeridian, like [`points_along_line`](#method.points_along_line).
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `max_distance`: Maximum length of a segment in meters, the line string is returned
+    ///   unchanged if it is not positive and finite
+    ///
+    /// # Returns
+    ///
+    /// The densified line string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let broadway = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ];
+    ///
+    /// // A vertex at least every 100 meters, e.g. before reprojecting
+    /// let densified = RulerMeasure::WGS84().densify(&broadway, 100.0);
+    /// assert!(densified.0.len() > broadway.0.len());
+    /// ```
+    pub fn densify(&self, line: &LineString<F>, max_distance: F) -> LineString<F> {
+        if !(max_distance > F::zero() && max_distance.is_finite()) {
+            return line.clone();
+        }
+
+        let mut densified = Vec::with_capacity(line.0.len());
+
+        for segment in line.lines() {
+            let [start, end] = [segment.start.into(), segment.end.into()];
+
+            let parts = (self.0.distance(&start, &end) / max_distance)
+                .ceil()
+                .to_usize()
+                .unwrap_or(1)
+                .max(1);
+
+            let count = <F as num_traits::NumCast>::from(parts).unwrap();
+
+            for part in 0..parts {
+                let ratio = <F as num_traits::NumCast>::from(part).unwrap() / count;
+
+                densified.push(self.0.interpolate(&start, &end, &ratio));
+            }
+        }
+
+        densified.extend(line.0.last().map(|&coord| <[F; 2]>::from(coord)));
+
+        densified.into()
+    }
+
+    /// Builds a line string parallel to another one, at a given distance to its side.
+    ///
+    /// See [`CheapRuler::offset_line`], every vertex is moved along the bisector of its
+    /// two segments.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `distance`: Distance to the left of the direction of travel in meters, negative
+    ///   values offset to the right
+    ///
+    /// # Returns
+    ///
+    /// The offset line string, with one vertex for each vertex of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let fifth_avenue = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    /// ];
+    ///
+    /// // The east sidewalk, 15 meters to the right
+    /// let sidewalk = RulerMeasure::WGS84().offset_line(&fifth_avenue, -15.0);
+    /// ```
+    pub fn offset_line(&self, line: &LineString<F>, distance: F) -> LineString<F> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+
+        self.0.offset_line(&points, &distance).into()
+    }
+
+    /// Clips a line string to a rectangle.
+    ///
+    /// See [`CheapRuler::clip_to_bbox`], every part of the line inside the rectangle
+    /// becomes a line string of the result.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `rect`: The clipping rectangle with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The parts of the line inside the rectangle, empty if the line lies entirely outside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, line_string, Rect};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let broadway = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ];
+    ///
+    /// let tile = Rect::new(coord!(x: -74.0, y: 40.74), coord!(x: -73.98, y: 40.75));
+    ///
+    /// let clipped = RulerMeasure::WGS84().clip_to_rect(&broadway, &tile);
+    /// assert_eq!(clipped.0.len(), 1);
+    /// ```
+    pub fn clip_to_rect(&self, line: &LineString<F>, rect: &Rect<F>) -> MultiLineString<F> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+        let bbox = [rect.min().x, rect.min().y, rect.max().x, rect.max().y];
+
+        self.0
+            .clip_to_bbox(&points, &bbox)
+            .into_iter()
+            .map(LineString::from)
+            .collect()
+    }
+
+    /// Splits a line string into consecutive parts of a fixed length.
+    ///
+    /// See [`CheapRuler::split_by_length`], the split points are interpolated on the line
+    /// and every part starts where the previous one ends.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `length`: The length of each part in meters
+    ///
+    /// # Returns
+    ///
+    /// The parts of the line, all of them `length` long except possibly the last one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let broadway = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ];
+    ///
+    /// // Kilometer sections, the last one is shorter
+    /// let sections = RulerMeasure::WGS84().split_by_length(&broadway, 1_000.0);
+    /// assert_eq!(sections.0.len(), 2);
+    /// ```
+    pub fn split_by_length(&self, line: &LineString<F>, length: F) -> MultiLineString<F> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+
+        self.0
+            .split_by_length(&points, &length)
+            .into_iter()
+            .map(LineString::from)
+            .collect()
+    }
+
+    /// Finds the closest point of a line string to a query point, with its distance and
+    /// the segment it lies on.
+    ///
+    /// Unlike geo's `ClosestPoint`, which only returns the point, this also reports what
+    /// map matching needs. See [`CheapRuler::point_on_line`].
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The line string with coordinates in degrees (longitude, latitude)
+    /// - `point`: The query point with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The closest point, its distance to the query point in meters and the index of the
+    /// segment it lies on, or `None` if the line string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, point};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let route = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ];
+    ///
+    /// let fix = point!(x: -73.9850, y: 40.7530);
+    ///
+    /// let (snapped, distance, segment) = RulerMeasure::WGS84()
+    ///     .closest_point_detailed(&route, fix)
+    ///     .unwrap();
+    /// assert_eq!(segment, 1);
+    /// ```
+    pub fn closest_point_detailed(
+        &self,
+        line: &LineString<F>,
+        point: Point<F>,
+    ) -> Option<(Point<F>, F, usize)> {
+        let points: Vec<[F; 2]> = line.coords().map(|c| (*c).into()).collect();
+
+        self.0
+            .point_on_line(&point.into(), &points)
+            .map(|(closest, distance, index)| (closest.into(), distance, index))
+    }
+
+    /// Calculates the minimum distance between two line strings, e.g. how close two routes get.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: The first line string with coordinates in degrees (longitude, latitude)
+    /// - `b`: The second line string with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The minimum distance in meters between any two segments, zero if the line strings
+    /// cross, or infinity if any of them is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let broadway = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9855, y: 40.7580), // Times Square
+    /// ];
+    /// let park_avenue = line_string![
+    ///     (x: -73.9772, y: 40.7527), // Grand Central
+    ///     (x: -73.9724, y: 40.7592), // 57th Street
+    /// ];
+    ///
+    /// let gap = RulerMeasure::WGS84().distance_between_line_strings(&broadway, &park_avenue);
+    /// ```
+    ///
+    /// See also: [`closest_point_detailed`](#method.closest_point_detailed)
+    pub fn distance_between_line_strings(&self, a: &LineString<F>, b: &LineString<F>) -> F {
+        let a: Vec<[F; 2]> = a.coords().map(|c| (*c).into()).collect();
+        let b: Vec<[F; 2]> = b.coords().map(|c| (*c).into()).collect();
+
+        self.0.distance_between_lines(&a, &b)
+    }
+
+    /// Calculates the distance between two points along with a bound on its error.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point with coordinates in degrees (longitude, latitude)
+    /// - `destination`: Ending point with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the distance in meters and an estimated bound in meters on its absolute
+    /// error compared to the geodesic distance, e.g. to carry error bars downstream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let empire_state = point!(x: -73.9857, y: 40.7484);
+    /// let flatiron = point!(x: -73.9897, y: 40.7411);
+    ///
+    /// let (distance, error) = RulerMeasure::WGS84().distance_with_error(empire_state, flatiron);
+    /// ```
+    ///
+    /// See also: [`CheapRuler::distance_with_error`]
+    pub fn distance_with_error(&self, origin: Point<F>, destination: Point<F>) -> (F, F) {
+        self.0
+            .distance_with_error(&origin.into(), &destination.into())
+    }
+
+    /// Computes the bounding box of a geometry.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: The geometry with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The bounding rectangle in degrees, or `None` if the geometry is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let route = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    /// ];
+    ///
+    /// let bbox = RulerMeasure::WGS84().bbox(&route);
+    /// ```
+    ///
+    /// See also: [`could_be_within`](#method.could_be_within)
+    pub fn bbox<G>(&self, geometry: &G) -> Option<Rect<F>>
+    where
+        G: BoundingRect<F>,
+        G::Output: Into<Option<Rect<F>>>,
+    {
+        geometry.bounding_rect().into()
+    }
+
+    /// Computes the bounding box of a geometry expanded by a metric margin.
+    ///
+    /// The margin is converted into degrees conservatively, with the scaling where a
+    /// degree is shortest, so every vertex of the geometry is at least `margin` inside
+    /// the expanded box, e.g. to query the surroundings of a route before buffering it.
+    ///
+    /// # Parameters
+    ///
+    /// - `geometry`: The geometry with coordinates in degrees (longitude, latitude)
+    /// - `margin`: Distance to expand the bounding box by in every direction in meters
+    ///
+    /// # Returns
+    ///
+    /// The expanded bounding rectangle in degrees, or `None` if the geometry is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let route = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    /// ];
+    ///
+    /// // everything within 200 meters of the route
+    /// let envelope = RulerMeasure::WGS84().envelope_with_margin(&route, 200.0);
+    /// ```
+    ///
+    /// See also: [`bbox`](#method.bbox), [`could_be_within`](#method.could_be_within)
+    pub fn envelope_with_margin<G>(&self, geometry: &G, margin: F) -> Option<Rect<F>>
+    where
+        G: BoundingRect<F>,
+        G::Output: Into<Option<Rect<F>>>,
+    {
+        self.bbox(geometry).map(|rect| {
+            let [min_x, min_y, max_x, max_y] = self.0.buffer_bbox(
+                &[rect.min().x, rect.min().y, rect.max().x, rect.max().y],
+                &margin,
+            );
+
+            Rect::new(Coord { x: min_x, y: min_y }, Coord { x: max_x, y: max_y })
+        })
+    }
+
+    /// Quickly checks whether two geometries could be within a distance of each other.
+    ///
+    /// Buffers the bounding box of the first geometry by the distance and checks it
+    /// against the bounding box of the second. This never rejects a pair that is
+    /// actually within the distance, but may accept pairs that are farther apart, so it
+    /// is meant as a prefilter before an exact distance check.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: First geometry with coordinates in degrees (longitude, latitude)
+    /// - `b`: Second geometry with coordinates in degrees (longitude, latitude)
+    /// - `distance`: The maximum distance in meters
+    ///
+    /// # Returns
+    ///
+    /// `false` if the geometries are certainly farther apart than the distance, or if
+    /// either of them is empty, `true` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, line_string};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let times_square = point!(x: -73.9855, y: 40.7580);
+    /// let route = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    /// ];
+    ///
+    /// let ruler = RulerMeasure::WGS84();
+    ///
+    /// assert!(ruler.could_be_within(&times_square, &route, 1500.0));
+    /// assert!(!ruler.could_be_within(&times_square, &route, 100.0));
+    /// ```
+    ///
+    /// See also: [`bbox`](#method.bbox)
+    pub fn could_be_within<A, B>(&self, a: &A, b: &B, distance: F) -> bool
+    where
+        A: BoundingRect<F>,
+        A::Output: Into<Option<Rect<F>>>,
+        B: BoundingRect<F>,
+        B::Output: Into<Option<Rect<F>>>,
+    {
+        let corners = |rect: Rect<F>| [rect.min().x, rect.min().y, rect.max().x, rect.max().y];
+
+        match (self.bbox(a), self.bbox(b)) {
+            (Some(a), Some(b)) => self
+                .0
+                .bbox_intersects(&self.0.buffer_bbox(&corners(a), &distance), &corners(b)),
+            _ => false,
+        }
+    }
+
+    /// Checks whether the Hausdorff distance between two geometries is within a threshold.
+    ///
+    /// The Hausdorff distance is the largest distance from a vertex of either geometry to
+    /// the nearest vertex of the other one. Instead of computing it in full, the check
+    /// moves on as soon as a near enough vertex is found, and stops at the first vertex
+    /// without one, so obviously different traces are rejected quickly.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: First geometry with coordinates in degrees (longitude, latitude)
+    /// - `b`: Second geometry with coordinates in degrees (longitude, latitude)
+    /// - `threshold`: The maximum Hausdorff distance in meters
+    ///
+    /// # Returns
+    ///
+    /// `true` if every vertex of each geometry is within the threshold of a vertex of the
+    /// other one, `false` otherwise, or if only one of them is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// let trace = line_string![
+    ///     (x: -73.9897, y: 40.7411), // Flatiron Building
+    ///     (x: -73.9857, y: 40.7484), // Empire State Building
+    /// ];
+    /// let replay = line_string![
+    ///     (x: -73.9896, y: 40.7412),
+    ///     (x: -73.9858, y: 40.7483),
+    /// ];
+    ///
+    /// let ruler = RulerMeasure::WGS84();
+    ///
+    /// assert!(ruler.hausdorff_within(&trace, &replay, 20.0));
+    /// assert!(!ruler.hausdorff_within(&trace, &replay, 5.0));
+    /// ```
+    ///
+    /// See also: [`could_be_within`](#method.could_be_within)
+    pub fn hausdorff_within<A, B>(&self, a: &A, b: &B, threshold: F) -> bool
+    where
+        A: CoordsIter<Scalar = F>,
+        B: CoordsIter<Scalar = F>,
+    {
+        let a: Vec<[F; 2]> = a.coords_iter().map(Into::into).collect();
+        let b: Vec<[F; 2]> = b.coords_iter().map(Into::into).collect();
+
+        let covered = |from: &[[F; 2]], to: &[[F; 2]]| {
+            from.iter().all(|point| {
+                let coefs = self.0.coefs(point);
+
+                to.iter()
+                    .any(|other| self.0.distance_with(&coefs, point, other) <= threshold)
+            })
+        };
+
+        covered(&a, &b) && covered(&b, &a)
+    }
+
+    /// Calculates the minimum distance from a point to the boundary of a polygon.
+    ///
+    /// The boundary includes the exterior ring and every interior ring (holes), so the
+    /// distance is positive both for points inside and outside the polygon.
+    ///
+    /// # Parameters
+    ///
+    /// - `polygon`: The polygon with coordinates in degrees (longitude, latitude)
+    /// - `point`: The query point with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The distance in meters to the closest point of any ring, zero for points on the
+    /// boundary, or infinity if the polygon is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, polygon};
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// // Bryant Park
+    /// let park = polygon![
+    ///     (x: -73.9850, y: 40.7530),
+    ///     (x: -73.9815, y: 40.7545),
+    ///     (x: -73.9830, y: 40.7565),
+    ///     (x: -73.9865, y: 40.7550),
+    /// ];
+    ///
+    /// let center = point!(x: -73.9840, y: 40.7548);
+    ///
+    /// let distance_to_edge = RulerMeasure::WGS84().distance_to_polygon_boundary(&park, center);
+    /// ```
+    pub fn distance_to_polygon_boundary(&self, polygon: &Polygon<F>, point: Point<F>) -> F {
+        self.distance_to_lines(polygon, &point.into())
+    }
+
+    /// Calculates the area of a polygon, whatever the winding of its rings.
+    ///
+    /// # Parameters
+    ///
+    /// - `polygon`: The polygon with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The area of the exterior ring minus the area of the holes, in square meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_ruler::geo::RulerMeasure;
+    ///
+    /// // Bryant Park, without the New York Public Library
+    /// let park = polygon!(
+    ///     exterior: [
+    ///         (x: -73.9853, y: 40.7545),
+    ///         (x: -73.9818, y: 40.7531),
+    ///         (x: -73.9829, y: 40.7516),
+    ///         (x: -73.9865, y: 40.7530),
+    ///     ],
+    ///     interiors: [[
+    ///         (x: -73.9836, y: 40.7522),
+    ///         (x: -73.9826, y: 40.7518),
+    ///         (x: -73.9830, y: 40.7513),
+    ///         (x: -73.9840, y: 40.7517),
+    ///     ]],
+    /// );
+    ///
+    /// let area = RulerMeasure::WGS84().area_unsigned(&park); // square meters
+    /// ```
+    ///
+    /// See also: [`area_signed`](#method.area_signed), [`CheapRuler::area`]
+    pub fn area_unsigned(&self, polygon: &Polygon<F>) -> F {
+        let rings: Vec<Vec<[F; 2]>> = core::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .map(|ring| ring.coords().map(|c| (*c).into()).collect())
+            .collect();
+        let rings: Vec<&[[F; 2]]> = rings.iter().map(Vec::as_slice).collect();
+
+        self.0.area(&rings)
+    }
+
+    /// Calculates the signed area of a polygon, following the winding of its exterior ring.
+    ///
+    /// Holes are subtracted whatever their own winding, so only the sign depends on the
+    /// orientation, as with `geo`'s `GeodesicArea::geodesic_area_signed`.
+    ///
+    /// # Parameters
+    ///
+    /// - `polygon`: The polygon with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// The [`area_unsigned`](#method.area_unsigned) in square meters, positive if the
+    /// exterior ring is wound counter-clockwise and negative if clockwise
+    ///
+    /// See also: [`CheapRuler::oriented_area`]
+    pub fn area_signed(&self, polygon: &Polygon<F>) -> F {
+        let exterior: Vec<[F; 2]> = polygon.exterior().coords().map(|c| (*c).into()).collect();
+        let area = self.area_unsigned(polygon);
+
+        if self.0.is_ccw(&exterior) {
+            area
+        } else {
+            -area
+        }
+    }
+
+    /// Calculates the minimum distance from a point to a geometry, dispatching on its type.
+    fn distance_to_geometry(&self, geometry: &Geometry<F>, point: Point<F>) -> F {
+        let origin = point.into();
+
+        match geometry {
+            Geometry::Point(p) => self.0.distance(&origin, &(*p).into()),
+            Geometry::MultiPoint(points) => points
+                .iter()
+                .map(|p| self.0.distance(&origin, &(*p).into()))
+                .fold(F::infinity(), F::min),
+            Geometry::Line(line) => self.distance_to_lines(line, &origin),
+            Geometry::LineString(line) => self.distance_to_lines(line, &origin),
+            Geometry::MultiLineString(lines) => self.distance_to_lines(lines, &origin),
+            Geometry::Polygon(polygon) => self.distance_to_lines(polygon, &origin),
+            Geometry::MultiPolygon(polygons) => self.distance_to_lines(polygons, &origin),
+            Geometry::Rect(rect) => self.distance_to_lines(rect, &origin),
+            Geometry::Triangle(triangle) => self.distance_to_lines(triangle, &origin),
+            Geometry::GeometryCollection(collection) => {
+                self.distance_to_collection(collection, point)
+            }
+        }
+    }
+
+    /// Finds the nearest point on the segments of a geometry and its distance, computing
+    /// the latitude-dependent scaling once at the point.
+    fn nearest_on_lines<'a, G: LinesIter<'a, Scalar = F>>(
+        &self,
+        geometry: &'a G,
+        point: &[F; 2],
+    ) -> Option<([F; 2], F)> {
+        let coefs = self.0.coefs(point);
+
+        geometry
+            .lines_iter()
+            .map(|line| {
+                let [start, end] = [line.start.into(), line.end.into()];
+                let (t, [x, y]) = self.0.project(&coefs, point, &start, &end);
+
+                (
+                    self.0.interpolate(&start, &end, &t),
+                    (x.powi(2) + y.powi(2)).sqrt(),
+                )
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+    }
+
+    /// Calculates the minimum distance from a point to the segments of a geometry,
+    /// computing the latitude-dependent scaling once at the point.
+    fn distance_to_lines<'a, G: LinesIter<'a, Scalar = F>>(
+        &self,
+        geometry: &'a G,
+        point: &[F; 2],
+    ) -> F {
+        let coefs = self.0.coefs(point);
+
+        geometry
+            .lines_iter()
+            .map(|line| {
+                let (_, [x, y]) =
+                    self.0
+                        .project(&coefs, point, &line.start.into(), &line.end.into());
+                (x.powi(2) + y.powi(2)).sqrt()
+            })
+            .fold(F::infinity(), F::min)
+    }
+}
+
+/// A rhumb line variant of [`RulerMeasure`], for constant bearing navigation.
+///
+/// Implements the same `geo` traits as `geo`'s `Rhumb` measure, using the ruler scaling
+/// computed halfway in latitude, see [`CheapRuler::rhumb_distance`]. Distances are the
+/// same both ways and the bearing back is the opposite one, and like `RulerMeasure` it is
+/// meant for city-scale distances.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Bearing, Destination, Distance};
+/// use geo_ruler::geo::RulerRhumbMeasure;
+///
+/// let battery_park = point!(x: -74.0170, y: 40.7033);
+/// let liberty_island = point!(x: -74.0445, y: 40.6892);
+///
+/// let ruler = RulerRhumbMeasure::WGS84();
+///
+/// // Hold this heading for this distance to reach Liberty Island
+/// let heading = ruler.bearing(battery_park, liberty_island);
+/// let distance = ruler.distance(battery_park, liberty_island);
+///
+/// let arrival = ruler.destination(battery_park, heading, distance);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RulerRhumbMeasure<F: CoordFloat>(CheapRuler<F>);
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> RulerRhumbMeasure<F> {
+    /// Creates a new rhumb ruler with custom ellipsoid parameters.
+    ///
+    /// # Parameters
+    ///
+    /// - `major`: The semi-major axis (equatorial radius) of the ellipsoid in meters
+    /// - `minor`: The semi-minor axis (polar radius) of the ellipsoid in meters
+    ///
+    /// See also: [`RulerMeasure::new`]
+    pub fn new(major: F, minor: F) -> Self {
+        Self(CheapRuler::new(&major, &minor))
+    }
+
+    /// Returns a rhumb ruler measuring distances in the given units.
+    ///
+    /// See also: [`RulerMeasure::with_units`]
+    pub fn with_units(self, units: Units) -> Self {
+        Self(self.0.with_units(units))
+    }
+}
+
+impl<F: CoordFloat + FloatConst + From<f32>> RulerRhumbMeasure<F> {
+    /// A rhumb ruler using the WGS84 reference ellipsoid.
+    ///
+    /// See also: [`RulerMeasure::WGS84`]
+    #[allow(non_snake_case)]
+    pub fn WGS84() -> Self {
+        Self(CheapRuler::<F>::WGS84())
+    }
+}
+
+impl<F: CoordFloat + FloatConst + From<f32>> Default for RulerRhumbMeasure<F> {
+    fn default() -> Self {
+        Self(CheapRuler::WGS84())
+    }
+}
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> Distance<F, Point<F>, Point<F>>
+    for RulerRhumbMeasure<F>
+{
+    /// Calculates the length of the rhumb line between two points, in meters.
+    ///
+    /// See also: [`CheapRuler::rhumb_distance`]
+    fn distance(&self, origin: Point<F>, destination: Point<F>) -> F {
+        self.0.rhumb_distance(&origin.into(), &destination.into())
+    }
+}
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> Bearing<F> for RulerRhumbMeasure<F> {
+    /// Calculates the constant bearing of the rhumb line from one point to another, in
+    /// degrees clockwise from north.
+    ///
+    /// See also: [`CheapRuler::rhumb_bearing`]
+    fn bearing(&self, origin: Point<F>, destination: Point<F>) -> F {
+        self.0.rhumb_bearing(&origin.into(), &destination.into())
+    }
+}
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> Destination<F> for RulerRhumbMeasure<F> {
+    /// Returns the point reached traveling the given distance, in meters, at a constant
+    /// bearing, in degrees clockwise from north.
+    ///
+    /// See also: [`CheapRuler::rhumb_destination`]
+    fn destination(&self, origin: Point<F>, bearing: F, distance: F) -> Point<F> {
+        self.0
+            .rhumb_destination(&origin.into(), &bearing, &distance)
+            .into()
+    }
+}
+
+/// A polygon paired with a ruler, e.g. to be used as a geofence.
+///
+/// Containment is checked in the ruler's local planar approximation, with longitudes
+/// wrapped across the antimeridian. Unlike `geo`'s `Contains`, points on the boundary of
+/// the polygon, including the boundary of its holes, are contained.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon};
+/// use geo_ruler::geo::{RulerMeasure, RulerPolygon};
+///
+/// let bryant_park = RulerPolygon::new(
+///     polygon![
+///         (x: -73.9853, y: 40.7545),
+///         (x: -73.9818, y: 40.7531),
+///         (x: -73.9829, y: 40.7516),
+///         (x: -73.9865, y: 40.7530),
+///     ],
+///     RulerMeasure::WGS84(),
+/// );
+///
+/// assert!(bryant_park.contains(point!(x: -73.9840, y: 40.7531)));
+/// assert!(!bryant_park.contains(point!(x: -73.9857, y: 40.7484)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RulerPolygon<F: CoordFloat> {
+    polygon: Polygon<F>,
+    ruler: RulerMeasure<F>,
+}
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> RulerPolygon<F> {
+    /// Creates a new polygon measured with the given ruler.
+    ///
+    /// # Parameters
+    ///
+    /// - `polygon`: The polygon with coordinates in degrees (longitude, latitude)
+    /// - `ruler`: The ruler used for the planar approximation
+    pub fn new(polygon: Polygon<F>, ruler: RulerMeasure<F>) -> Self {
+        Self { polygon, ruler }
+    }
+
+    /// Returns the wrapped polygon.
+    pub fn polygon(&self) -> &Polygon<F> {
+        &self.polygon
+    }
+
+    /// Checks whether the polygon contains a point.
+    ///
+    /// # Parameters
+    ///
+    /// - `point`: The point with coordinates in degrees (longitude, latitude)
+    ///
+    /// # Returns
+    ///
+    /// `true` if the point is inside the exterior ring and outside every hole, or on
+    /// any of their edges.
+    pub fn contains(&self, point: Point<F>) -> bool {
+        let point = point.into();
+        let ruler = &self.ruler.0;
+        let ring =
+            |line: &LineString<F>| ruler.locate_in_ring(&point, line.coords().map(|c| (*c).into()));
+
+        ring(self.polygon.exterior()).is_none_or(|inside| inside)
+            && self
+                .polygon
+                .interiors()
+                .iter()
+                .all(|hole| ring(hole).is_none_or(|inside| !inside))
+    }
+}
+
+/// Helper iterator for generating evenly spaced points along a line
+struct LinePointInterpolator<'ruler, F: CoordFloat> {
+    /// Starting point of the line
+    start: Point<F>,
+    /// Ending point of the line
+    end: Point<F>,
+    /// Index of the next step, its offset ratio along the line is `index * step`
+    index: usize,
+    /// Number of steps before reaching the end of the line
+    steps: usize,
+    /// Step size for each iteration as a ratio of the total distance
+    step: F,
+    /// Reference to the ruler for calculations
+    ruler: &'ruler RulerMeasure<F>,
+    /// Whether to include the endpoint as the final item
+    include_last: bool,
+}
+
+impl<'ruler, F: CoordFloat + FloatConst + MaybeFromf32> LinePointInterpolator<'ruler, F> {
+    /// Creates a new line point iterator.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: The starting point
+    /// - `end`: The ending point
+    /// - `ruler`: Reference to the ruler for calculations
+    /// - `step`: Step size as a ratio of the total distance, a non-positive step yields
+    ///   the start point only
+    /// - `include_ends`: Whether to include start and end points
+    fn new(
+        start: Point<F>,
+        end: Point<F>,
+        ruler: &'ruler RulerMeasure<F>,
+        step: F,
+        include_ends: bool,
+    ) -> Self {
+        // the offsets are computed from the index rather than accumulated, so they keep
+        // moving however small the step, and the number of points is known up front
+        let steps = if step > F::zero() {
+            let parts = F::one() / step;
+
+            // a line split in exactly `n` parts by a rounded step still has `n` steps
+            let rounded = parts.round();
+            let tolerance = parts * F::epsilon() * <F as num_traits::NumCast>::from(4).unwrap();
+
+            let parts = if (parts - rounded).abs() <= tolerance {
+                rounded
+            } else {
+                parts.ceil()
+            };
+
+            parts.to_usize().unwrap_or(1).max(1)
+        } else {
+            1
+        };
+
+        Self {
+            start,
+            end,
+            ruler,
+            include_last: include_ends,
+            index: usize::from(!include_ends),
+            steps,
+            step,
+        }
+    }
+
+    /// Returns point along the line at the current offset and advances the offset.
+    fn advance(&mut self) -> Point<F> {
+        let offset = match self.index {
+            0 => F::zero(),
+            index => <F as num_traits::NumCast>::from(index).unwrap() * self.step,
+        };
+
+        self.index += 1;
+
+        self.ruler
+            .point_at_ratio_between(self.start, self.end, offset)
+    }
+
+    /// Returns the number of points left to yield, including the end point if needed.
+    fn remaining(&self) -> usize {
+        self.steps.saturating_sub(self.index) + usize::from(self.include_last)
+    }
+
+    /// Should be called at the end of the line to potentially
+    /// include the end point.
+    fn stop(&mut self) -> Option<Point<F>> {
+        if self.include_last {
+            self.include_last = false;
+            Some(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F: CoordFloat + FloatConst + MaybeFromf32> Iterator for LinePointInterpolator<'_, F> {
+    type Item = Point<F>;
+
+    /// Advances along the line until reaching the end, then potentially
+    /// yields the end point as the final item.
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.index < self.steps)
+            .then(|| self.advance())
+            .or_else(|| self.stop())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use approx::{assert_relative_eq, relative_eq};
+    use core::f64::consts::PI;
+    use geo::line_measures::LengthMeasurable;
+    use geo::{
+        Centroid, Geodesic, GeodesicArea, GeodesicMeasure, HaversineMeasure, Length, Orient, Rhumb,
+        line_string, orient::Direction, polygon,
+    };
+
+    const RELATIVE_ERROR: f64 = 0.01;
+    const EPSILON: f64 = 0.01;
+
+    #[test]
+    fn distance() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+
+        let distance_geodesic = Geodesic.distance(empire_state, flatiron);
+        let distance_ruler = RulerMeasure::WGS84().distance(empire_state, flatiron);
+
+        assert_relative_eq!(
+            distance_geodesic,
+            distance_ruler,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn destination() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let distance = 100.;
+        let bearing = 45.;
+
+        let geodesic_point = Geodesic.destination(empire_state, bearing, distance);
+        let ruler_point = RulerMeasure::WGS84().destination(empire_state, bearing, distance);
+
+        let error = Geodesic.distance(geodesic_point, ruler_point) / distance;
+
+        assert_relative_eq!(0., error, epsilon = EPSILON, max_relative = RELATIVE_ERROR)
+    }
+
+    #[test]
+    fn bearing() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+
+        assert_relative_eq!(
+            Geodesic.bearing(empire_state, flatiron).rem_euclid(360.),
+            RulerMeasure::WGS84()
+                .bearing(empire_state, flatiron)
+                .rem_euclid(360.),
+            max_relative = RELATIVE_ERROR
+        )
+    }
+
+    #[test]
+    fn interpolate_distance() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let distance = 100.;
+
+        let geodesic_point = Geodesic.point_at_distance_between(empire_state, flatiron, distance);
+        let ruler_point =
+            RulerMeasure::WGS84().point_at_distance_between(empire_state, flatiron, distance);
+
+        let error = Geodesic.distance(geodesic_point, ruler_point) / distance;
+
+        assert_relative_eq!(0., error, epsilon = EPSILON, max_relative = RELATIVE_ERROR)
+    }
+
+    #[test]
+    fn interpolate_ratio() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let ratio = 0.15;
+
+        let geodesic_point = Geodesic.point_at_ratio_between(empire_state, flatiron, ratio);
+        let ruler_point =
+            RulerMeasure::WGS84().point_at_ratio_between(empire_state, flatiron, ratio);
+
+        let error = Geodesic.distance(geodesic_point, ruler_point);
+
+        assert_relative_eq!(0., error, epsilon = EPSILON, max_relative = RELATIVE_ERROR)
+    }
+
+    #[test]
+    fn interpolate_along_no_ends() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let max_distance = 100.;
+
+        let ruler = RulerMeasure::WGS84();
+
+        let mut points = ruler.points_along_line(empire_state, flatiron, max_distance, false);
+
+        let Some(mut prev) = points.next() else {
+            panic!("points is empty");
+        };
+
+        let mut found_empire_state = prev == empire_state;
+        let mut found_flatiron = prev == flatiron;
+
+        for curr in points {
+            let distance = Geodesic.distance(prev, curr);
+
+            if curr == empire_state {
+                found_empire_state = true;
+            }
+
+            if curr == flatiron {
+                found_flatiron = true;
+            }
+
+            prev = curr;
+
+            assert!(
+                distance < max_distance
+                    || relative_eq!(distance, max_distance, max_relative = RELATIVE_ERROR),
+            )
+        }
+
+        assert!(!found_flatiron && !found_empire_state);
+    }
+
+    #[test]
+    fn interpolate_along_with_ends() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let max_distance = 100.;
+
+        let ruler = RulerMeasure::WGS84();
+
+        let mut points = ruler.points_along_line(empire_state, flatiron, max_distance, true);
+
+        let Some(mut prev) = points.next() else {
+            panic!("points is empty");
+        };
+
+        let mut found_empire_state = prev == empire_state;
+        let mut found_flatiron = prev == flatiron;
+
+        for curr in points {
+            let distance = Geodesic.distance(prev, curr);
+
+            if curr == empire_state {
+                found_empire_state = true;
+            }
+
+            if curr == flatiron {
+                found_flatiron = true;
+            }
+
+            prev = curr;
+
+            assert!(
+                distance < max_distance
+                    || relative_eq!(distance, max_distance, max_relative = RELATIVE_ERROR),
+            )
+        }
+
+        assert!(found_flatiron && found_empire_state);
+    }
+
+    #[test]
+    fn non_standard_model() {
+        let olympus_mons = point!(x: -226.2, y: 18.65);
+        let karzok_crater = point!(x: -226.192, y: 18.2292);
+
+        let mars_equatorial_radius = 3_396_200.;
+        let mars_polar_radius = 3_376_200.;
+        let mars_flattening = 0.00589;
+
+        let mars_geoid = GeodesicMeasure::new(mars_equatorial_radius, mars_flattening);
+        let mars_ruler = RulerMeasure::new(mars_equatorial_radius, mars_polar_radius);
+
+        let distance_geodesic = mars_geoid.distance(olympus_mons, karzok_crater);
+        let distance_ruler = mars_ruler.distance(olympus_mons, karzok_crater);
+
+        assert_relative_eq!(
+            distance_geodesic,
+            distance_ruler,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn sphere() {
+        let radius = 3_389_500.; // Mars mean radius
+
+        let ruler = RulerMeasure::sphere(radius);
+        let haversine = HaversineMeasure::new(radius);
+
+        let olympus_mons = point!(x: -226.2, y: 18.65);
+        let karzok_crater = point!(x: -226.192, y: 18.2292);
+
+        assert_relative_eq!(
+            ruler.distance(olympus_mons, karzok_crater),
+            haversine.distance(olympus_mons, karzok_crater),
+            max_relative = RELATIVE_ERROR
+        );
+
+        for bearing in [0., 45., 90., 135.] {
+            let destination = haversine.destination(olympus_mons, bearing, 10_000.);
+
+            assert_relative_eq!(
+                ruler.distance(olympus_mons, destination),
+                10_000.,
+                max_relative = RELATIVE_ERROR
+            );
+        }
+    }
+
+    #[test]
+    fn translate() {
+        let block = polygon![
+            (x: -73.9857, y: 40.7484),
+            (x: -73.9847, y: 40.7484),
+            (x: -73.9847, y: 40.7494),
+            (x: -73.9857, y: 40.7494),
+        ];
+        let distance = 50.;
+        let bearing: f64 = 30.;
+
+        let (dx, dy) = (
+            distance * bearing.to_radians().sin(),
+            distance * bearing.to_radians().cos(),
+        );
+
+        let ruler = RulerMeasure::WGS84();
+        let moved = ruler.translate(&block, dx, dy);
+
+        let before = block.centroid().unwrap();
+        let after = moved.centroid().unwrap();
+
+        assert_relative_eq!(
+            Geodesic.distance(before, after),
+            distance,
+            max_relative = RELATIVE_ERROR
+        );
+        assert_relative_eq!(
+            Geodesic.bearing(before, after),
+            bearing,
             max_relative = RELATIVE_ERROR
-        )
+        );
+    }
+
+    #[test]
+    fn translate_line_string_east() {
+        let ruler = RulerMeasure::WGS84();
+
+        // a route spanning enough latitude for the scaling to differ between vertices
+        let route = line_string![
+            (x: -73.9903, y: 40.7359),
+            (x: -73.9855, y: 40.7580),
+            (x: -73.9819, y: 40.7681),
+        ];
+
+        let moved = ruler.translate(&route, 100., 0.);
+
+        for (before, after) in route.points().zip(moved.points()) {
+            assert_eq!(after.y(), before.y());
+            assert_relative_eq!(
+                Geodesic.distance(before, after),
+                100.,
+                max_relative = RELATIVE_ERROR
+            );
+            assert_relative_eq!(ruler.distance(before, after), 100., max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn rotate() {
+        let ruler = RulerMeasure::WGS84();
+
+        // a 100 x 100 meters square at a high latitude, where degrees are far from square
+        let center = point!(x: 25.0, y: 70.0);
+        let corner = |dx: f64, dy: f64| ruler.0.offset(&center.into(), &dx, &dy);
+
+        let square = polygon![
+            corner(-50., -50.).into(),
+            corner(50., -50.).into(),
+            corner(50., 50.).into(),
+            corner(-50., 50.).into(),
+        ];
+
+        let rotated = ruler.rotate(&square, 90., center);
+
+        let sides = |polygon: &Polygon<f64>| -> Vec<f64> {
+            polygon
+                .exterior()
+                .lines()
+                .map(|line| ruler.distance(line.start_point(), line.end_point()))
+                .collect()
+        };
+
+        for (side, rotated_side) in sides(&square).iter().zip(sides(&rotated)) {
+            assert_relative_eq!(*side, 100., max_relative = RELATIVE_ERROR);
+            assert_relative_eq!(rotated_side, 100., max_relative = RELATIVE_ERROR);
+        }
+
+        // a quarter turn counter-clockwise moves each corner to the next one
+        let corners = &square.exterior().0;
+        let rotated_corners = &rotated.exterior().0;
+
+        for i in 0..4 {
+            assert_relative_eq!(rotated_corners[i], corners[(i + 1) % 4], epsilon = 1e-9);
+        }
+
+        // the pivot itself does not move
+        assert_eq!(ruler.rotate(&center, 37., center), center);
+
+        // across the antimeridian a point east of the pivot ends up north of it
+        let pivot = point!(x: 179.99, y: 0.);
+        let east = point!(x: -179.99, y: 0.);
+
+        let rotated = ruler.rotate(&east, 90., pivot);
+
+        assert_relative_eq!(rotated.x(), pivot.x(), epsilon = 1e-9);
+        assert!(rotated.y() > 0. && rotated.y() < 0.1);
+        assert_relative_eq!(
+            ruler.distance(pivot, rotated),
+            ruler.distance(pivot, east),
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn scale_metric() {
+        let ruler = RulerMeasure::WGS84();
+
+        // a 100 x 100 meters square at a high latitude, where degrees are far from square
+        let center = point!(x: 25.0, y: 70.0);
+        let corner = |dx: f64, dy: f64| ruler.0.offset(&center.into(), &dx, &dy);
+
+        let square = polygon![
+            corner(-50., -50.).into(),
+            corner(50., -50.).into(),
+            corner(50., 50.).into(),
+            corner(-50., 50.).into(),
+        ];
+
+        let scaled = ruler.scale_metric(&square, 2., center);
+
+        for line in scaled.exterior().lines() {
+            let side = ruler.distance(line.start_point(), line.end_point());
+            assert_relative_eq!(side, 200., max_relative = RELATIVE_ERROR);
+        }
+
+        for (coord, expected) in scaled.exterior().0.iter().zip([
+            corner(-100., -100.),
+            corner(100., -100.),
+            corner(100., 100.),
+            corner(-100., 100.),
+        ]) {
+            assert_relative_eq!(*coord, expected.into(), epsilon = 1e-9);
+        }
+
+        // the pivot itself does not move
+        assert_eq!(ruler.scale_metric(&center, 0.5, center), center);
+
+        // across the antimeridian the scaled point stays on the short way
+        let pivot = point!(x: 179.99, y: 0.);
+        let east = point!(x: -179.99, y: 0.);
+
+        let scaled = ruler.scale_metric(&east, 2., pivot);
+
+        assert_relative_eq!(scaled, point!(x: -179.97, y: 0.), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn distance_to_collection() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let times_square = point!(x: -73.9855, y: 40.7580);
+        let broadway_59th = point!(x: -73.9819, y: 40.7681);
+
+        let ruler = RulerMeasure::WGS84();
+
+        let near_point = GeometryCollection::new_from(vec![
+            Geometry::Point(flatiron),
+            Geometry::LineString(line_string![times_square.0, broadway_59th.0]),
+        ]);
+
+        assert_relative_eq!(
+            ruler.distance_to_collection(&near_point, empire_state),
+            Geodesic.distance(empire_state, flatiron),
+            max_relative = RELATIVE_ERROR
+        );
+
+        let near_line = GeometryCollection::new_from(vec![
+            Geometry::Point(broadway_59th),
+            Geometry::LineString(line_string![flatiron.0, times_square.0]),
+        ]);
+
+        let distance = ruler.distance_to_collection(&near_line, empire_state);
+
+        assert!(distance < ruler.distance(empire_state, flatiron));
+        assert!(distance < ruler.distance(empire_state, times_square));
+        assert_relative_eq!(
+            distance,
+            ruler.0.distance_to_line(
+                &empire_state.into(),
+                &[flatiron.into(), times_square.into()]
+            ),
+            max_relative = RELATIVE_ERROR
+        );
+
+        assert_eq!(
+            ruler.distance_to_collection(&GeometryCollection::default(), empire_state),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn generic_length() {
+        let route = line_string![
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9857, y: 40.7484),
+            (x: -73.9855, y: 40.7580),
+            (x: -73.9819, y: 40.7681),
+        ];
+
+        let ruler = RulerMeasure::<f64>::WGS84();
+
+        assert_relative_eq!(
+            route.length(&ruler),
+            route.length(&Geodesic),
+            max_relative = RELATIVE_ERROR
+        );
+
+        let segment = Line::new(route.0[0], route.0[1]);
+        assert_eq!(
+            segment.length(&ruler),
+            ruler.distance(segment.start_point(), segment.end_point())
+        );
+    }
+
+    #[test]
+    fn circle() {
+        let ruler = RulerMeasure::WGS84();
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+
+        let circle = ruler.circle(empire_state, 500., 128);
+
+        assert!(circle.exterior().is_closed());
+        assert!(circle.interiors().is_empty());
+
+        assert_relative_eq!(
+            circle.geodesic_area_unsigned(),
+            PI * 500_f64.powi(2),
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn densify() {
+        let ruler = RulerMeasure::WGS84();
+
+        let broadway = line_string![
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9877, y: 40.7480),
+            (x: -73.9855, y: 40.7580),
+        ];
+
+        let densified = ruler.densify(&broadway, 100.);
+
+        assert!(densified.0.len() > broadway.0.len());
+        assert!(broadway.0.iter().all(|coord| densified.0.contains(coord)));
+        assert!(
+            densified
+                .lines()
+                .all(|line| ruler.distance(line.start.into(), line.end.into()) <= 100. + EPSILON)
+        );
+        assert_relative_eq!(
+            ruler.total_length(&densified),
+            ruler.total_length(&broadway),
+            max_relative = 1e-5
+        );
+
+        assert_eq!(ruler.densify(&broadway, 0.), broadway);
+    }
+
+    #[test]
+    fn densify_across_antimeridian() {
+        let ruler = RulerMeasure::WGS84();
+
+        let line: LineString<f64> = line_string![(x: 179., y: 0.), (x: -179., y: 0.)];
+
+        let densified = ruler.densify(&line, 20_000.);
+
+        assert!(densified.0.len() > 10);
+        assert_eq!(densified.0.first(), line.0.first());
+        assert_eq!(densified.0.last(), line.0.last());
+
+        for coord in &densified.0 {
+            assert!(coord.x.abs() >= 179. && coord.x.abs() <= 180.);
+        }
+
+        assert!(
+            densified
+                .lines()
+                .all(|line| ruler.distance(line.start.into(), line.end.into()) <= 20_000. + EPSILON)
+        );
+    }
+
+    #[test]
+    fn offset_line() {
+        let ruler = RulerMeasure::WGS84();
+
+        let broadway = line_string![
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9877, y: 40.7480),
+            (x: -73.9855, y: 40.7580),
+        ];
+
+        let left = ruler.offset_line(&broadway, 20.);
+        let right = ruler.offset_line(&broadway, -20.);
+
+        assert_eq!(left.0.len(), broadway.0.len());
+
+        // the middle of each offset segment is 20 meters away from the original line
+        for offset in [&left, &right] {
+            for line in offset.lines() {
+                let middle = ruler.point_at_ratio_between(line.start.into(), line.end.into(), 0.5);
+
+                let (_, distance, _) = ruler.closest_point_detailed(&broadway, middle).unwrap();
+                assert_relative_eq!(distance, 20., max_relative = RELATIVE_ERROR);
+            }
+        }
+
+        // the sides are told apart from the direction of travel, west is to the left
+        assert!(left.0.iter().zip(&right.0).all(|(l, r)| l.x < r.x));
+    }
+
+    #[test]
+    fn clip_to_rect() {
+        let ruler = RulerMeasure::WGS84();
+
+        let line = line_string![(x: 2., y: 5.), (x: 2., y: 15.), (x: 8., y: 15.), (x: 8., y: 5.)];
+        let rect = Rect::new(Coord { x: 0., y: 0. }, Coord { x: 10., y: 10. });
+
+        assert_eq!(
+            ruler.clip_to_rect(&line, &rect),
+            MultiLineString::new(vec![
+                line_string![(x: 2., y: 5.), (x: 2., y: 10.)],
+                line_string![(x: 8., y: 10.), (x: 8., y: 5.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn line_interpolate_point() {
+        let ruler = RulerMeasure::WGS84();
+
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let line = Line::new(empire_state, flatiron);
+
+        for fraction in [0., 0.5, 1.] {
+            assert_eq!(
+                ruler.line_interpolate_point(&line, fraction),
+                ruler.point_at_ratio_between(empire_state, flatiron, fraction)
+            );
+        }
+
+        assert_eq!(ruler.line_interpolate_point(&line, 0.), empire_state);
+        assert_eq!(ruler.line_interpolate_point(&line, 1.), flatiron);
+
+        // fractions outside the line are clamped to its ends
+        assert_eq!(ruler.line_interpolate_point(&line, -0.5), empire_state);
+        assert_eq!(ruler.line_interpolate_point(&line, 1.5), flatiron);
+    }
+
+    #[test]
+    fn closest_point_detailed() {
+        let ruler = RulerMeasure::WGS84();
+
+        // up 5th Avenue and then across 42nd Street
+        let route = line_string![
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9857, y: 40.7484),
+            (x: -73.9817, y: 40.7540),
+            (x: -73.9772, y: 40.7527),
+        ];
+
+        for (fix, nearest) in [
+            (point!(x: -73.9890, y: 40.7430), 0),
+            (point!(x: -73.9840, y: 40.7536), 1),
+            (point!(x: -73.9790, y: 40.7540), 2),
+        ] {
+            let (snapped, distance, segment) = ruler.closest_point_detailed(&route, fix).unwrap();
+
+            assert_eq!(segment, nearest);
+
+            // the reported segment is the nearest one, and the distance is to its closest point
+            for (i, line) in route.lines().enumerate() {
+                let to_line =
+                    ruler
+                        .0
+                        .cross_track_distance(&fix.into(), &line.start.into(), &line.end.into());
+                assert!(i == segment || to_line > distance);
+            }
+            assert_relative_eq!(ruler.distance(fix, snapped), distance, max_relative = 1e-9);
+        }
+
+        assert_eq!(
+            ruler.closest_point_detailed(&LineString::new(vec![]), point!(x: 0., y: 0.)),
+            None
+        );
+    }
+
+    #[test]
+    fn distance_between_line_strings() {
+        let ruler = RulerMeasure::WGS84();
+
+        // two parallel routes, 200 meters apart
+        let route = line_string![
+            (x: -73.9900, y: 40.7500),
+            (x: -73.9850, y: 40.7500),
+            (x: -73.9800, y: 40.7500),
+        ];
+        let parallel = ruler.translate(&route, 0., 200.);
+
+        assert_relative_eq!(
+            ruler.distance_between_line_strings(&route, &parallel),
+            200.,
+            max_relative = 1e-3
+        );
+
+        // two crossing routes, with no vertex in common
+        let crossing = line_string![
+            (x: -73.9870, y: 40.7450),
+            (x: -73.9830, y: 40.7550),
+        ];
+
+        assert_eq!(ruler.distance_between_line_strings(&route, &crossing), 0.);
+    }
+
+    #[test]
+    fn split_by_length() {
+        let ruler = RulerMeasure::WGS84();
+
+        // along Broadway from Union Square to Columbus Circle
+        let route = line_string![
+            (x: -73.9903, y: 40.7359),
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9877, y: 40.7480),
+            (x: -73.9855, y: 40.7580),
+            (x: -73.9819, y: 40.7681),
+        ];
+
+        let sections = ruler.split_by_length(&route, 1_000.);
+        let (last, full) = sections.0.split_last().unwrap();
+
+        assert_eq!(full.len(), 3);
+        for section in full {
+            assert_relative_eq!(ruler.total_length(section), 1_000., max_relative = 1e-6);
+        }
+        assert!(ruler.total_length(last) < 1_000.);
+    }
+
+    #[test]
+    fn rhumb() {
+        let ruler = RulerRhumbMeasure::WGS84();
+
+        let battery_park = point!(x: -74.0170, y: 40.7033);
+
+        // compare against geo's rhumb lines over the harbor and up the Hudson
+        for (bearing, distance) in [(0., 500.), (45., 2_000.), (200., 5_000.), (340., 20_000.)] {
+            let expected = Rhumb.destination(battery_park, bearing, distance);
+            let destination = ruler.destination(battery_park, bearing, distance);
+
+            assert_relative_eq!(
+                Rhumb.distance(destination, expected),
+                0.,
+                epsilon = distance * RELATIVE_ERROR
+            );
+
+            assert_relative_eq!(
+                ruler.distance(battery_park, expected),
+                distance,
+                max_relative = RELATIVE_ERROR
+            );
+
+            // ruler bearings are within (-180°, 180°], geo's within [0°, 360°)
+            let difference = (ruler.bearing(battery_park, expected)
+                - Rhumb.bearing(battery_park, expected))
+            .rem_euclid(360.);
+            assert!(difference.min(360. - difference) < 1.);
+        }
+    }
+
+    #[test]
+    fn ruler_polygon() {
+        // U shaped block, open to the north, with a courtyard in the western arm
+        let block = RulerPolygon::new(
+            polygon![
+                exterior: [
+                    (x: -73.990, y: 40.750),
+                    (x: -73.980, y: 40.750),
+                    (x: -73.980, y: 40.760),
+                    (x: -73.983, y: 40.760),
+                    (x: -73.983, y: 40.753),
+                    (x: -73.987, y: 40.753),
+                    (x: -73.987, y: 40.760),
+                    (x: -73.990, y: 40.760),
+                ],
+                interiors: [[
+                    (x: -73.989, y: 40.755),
+                    (x: -73.988, y: 40.755),
+                    (x: -73.988, y: 40.756),
+                    (x: -73.989, y: 40.756),
+                ]],
+            ],
+            RulerMeasure::WGS84(),
+        );
+
+        // inside both arms and the base
+        assert!(block.contains(point!(x: -73.9885, y: 40.758)));
+        assert!(block.contains(point!(x: -73.9815, y: 40.758)));
+        assert!(block.contains(point!(x: -73.985, y: 40.751)));
+
+        // in the notch between the arms, outside the bounds and in the courtyard
+        assert!(!block.contains(point!(x: -73.985, y: 40.757)));
+        assert!(!block.contains(point!(x: -73.995, y: 40.755)));
+        assert!(!block.contains(point!(x: -73.9885, y: 40.7555)));
+
+        // on the southern edge, the notch edge and the courtyard edge
+        assert!(block.contains(point!(x: -73.985, y: 40.750)));
+        assert!(block.contains(point!(x: -73.985, y: 40.753)));
+        assert!(block.contains(point!(x: -73.9885, y: 40.755)));
+
+        assert_eq!(block.polygon().interiors().len(), 1);
+
+        let copy = block.clone();
+        assert_eq!(copy.polygon(), block.polygon());
+        assert!(alloc::format!("{copy:?}").starts_with("RulerPolygon"));
+    }
+
+    #[test]
+    fn interpolate_across_antimeridian() {
+        let ruler = RulerMeasure::WGS84();
+
+        let (west, east): (Point<f64>, _) = (point!(x: 179., y: 0.), point!(x: -179., y: 0.));
+
+        let points: Vec<_> = ruler.points_along_line(west, east, 20_000., true).collect();
+
+        assert!(points.len() > 10);
+        assert_eq!(points.first(), Some(&west));
+        assert_eq!(points.last(), Some(&east));
+
+        for point in &points {
+            assert!(point.x().abs() >= 179. && point.x().abs() <= 180.);
+        }
+
+        for pair in points.windows(2) {
+            assert!(ruler.distance(pair[0], pair[1]) <= 20_000. * (1. + RELATIVE_ERROR));
+        }
+
+        let past = ruler.point_at_distance_between(west, east, 200_000.);
+        assert!(past.x() < -179. && past.x() > -179.5);
+
+        let raw = RulerMeasure::WGS84().with_antimeridian(AntimeridianMode::Raw);
+        assert_eq!(
+            raw.point_at_ratio_between(west, east, 0.5),
+            point!(x: 0., y: 0.)
+        );
+    }
+
+    #[test]
+    fn closest_point_across_antimeridian() {
+        let ruler = RulerMeasure::WGS84();
+
+        let line = line_string![(x: 179.9, y: 10.), (x: -179.9, y: 10.)];
+        let fix = point!(x: -179.95, y: 10.01);
+
+        let (snapped, distance, segment) = ruler.closest_point_detailed(&line, fix).unwrap();
+
+        assert_eq!(segment, 0);
+        assert_relative_eq!(snapped, point!(x: -179.95, y: 10.), epsilon = 1e-9);
+
+        let (nearest, nearest_distance) = ruler
+            .nearest_point_on_geometry(&Geometry::LineString(line), fix)
+            .unwrap();
+
+        assert_relative_eq!(nearest, snapped, epsilon = 1e-9);
+        assert_relative_eq!(nearest_distance, distance, max_relative = 1e-9);
+        assert_relative_eq!(distance, ruler.distance(fix, snapped), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn total_length() {
+        let ruler = RulerMeasure::WGS84();
+
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let times_square = point!(x: -73.9855, y: 40.7580);
+
+        let route = line_string![flatiron.0, empire_state.0, times_square.0];
+
+        assert_relative_eq!(
+            ruler.total_length(&route),
+            ruler.distance(flatiron, empire_state) + ruler.distance(empire_state, times_square),
+            max_relative = 1e-12
+        );
+
+        // the same vertices as a polygon also include the closing segment
+        let triangle = Polygon::new(route.clone(), vec![]);
+
+        assert_relative_eq!(
+            ruler.total_length(&triangle),
+            ruler.total_length(&route) + ruler.distance(times_square, flatiron),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            ruler.total_length(&triangle),
+            Geodesic.length(&route) + Geodesic.distance(times_square, flatiron),
+            max_relative = RELATIVE_ERROR
+        );
+
+        // holes add their own perimeter, without joining the rings
+        let hole = line_string![
+            (x: -73.9870, y: 40.7470),
+            (x: -73.9865, y: 40.7480),
+            (x: -73.9875, y: 40.7480),
+        ];
+        let with_hole = Polygon::new(route.clone(), vec![hole.clone()]);
+
+        assert_relative_eq!(
+            ruler.total_length(&with_hole),
+            ruler.total_length(&triangle) + ruler.total_length(&Polygon::new(hole, vec![])),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn collection_length() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        let times_square = point!(x: -73.9855, y: 40.7580);
+        let broadway_59th = point!(x: -73.9819, y: 40.7681);
+
+        let route = line_string![flatiron.0, empire_state.0, times_square.0];
+        let detours = MultiLineString::new(vec![
+            line_string![times_square.0, broadway_59th.0],
+            line_string![empire_state.0, broadway_59th.0],
+        ]);
+
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::LineString(route.clone()),
+            Geometry::Point(empire_state),
+            Geometry::MultiLineString(detours.clone()),
+        ]);
+
+        let ruler = RulerMeasure::WGS84();
+
+        let ruler_length: f64 = collection
+            .iter()
+            .map(|geometry| match geometry {
+                Geometry::LineString(line_string) => ruler.length(line_string),
+                Geometry::MultiLineString(multi_line_string) => ruler.length(multi_line_string),
+                _ => 0.,
+            })
+            .sum();
+
+        let geodesic_length = Geodesic.length(&route) + Geodesic.length(&detours);
+
+        assert!(ruler_length > 0.);
+        assert_relative_eq!(ruler_length, geodesic_length, max_relative = RELATIVE_ERROR);
+    }
+
+    #[test]
+    fn envelope_with_margin() {
+        let ruler = RulerMeasure::WGS84();
+
+        // up Broadway, then a vertex far north where a degree of longitude is much shorter
+        let route = line_string![
+            (x: -73.9903, y: 40.7359),
+            (x: -73.9855, y: 40.7580),
+            (x: -73.9819, y: 40.7681),
+            (x: 25.0, y: 70.0),
+        ];
+        let margin = 250.;
+
+        let envelope = ruler.envelope_with_margin(&route, margin).unwrap();
+
+        for vertex in route.points() {
+            let sides = [
+                point!(x: envelope.min().x, y: vertex.y()),
+                point!(x: envelope.max().x, y: vertex.y()),
+                point!(x: vertex.x(), y: envelope.min().y),
+                point!(x: vertex.x(), y: envelope.max().y),
+            ];
+
+            for side in sides {
+                assert!(ruler.distance(vertex, side) >= margin);
+            }
+        }
+
+        assert_eq!(
+            ruler.envelope_with_margin(&LineString::<f64>::new(vec![]), margin),
+            None
+        );
     }
 
     #[test]
-    fn interpolate_distance() {
+    fn hausdorff_within() {
+        let ruler = RulerMeasure::WGS84();
+
+        let trace = line_string![
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9877, y: 40.7448),
+            (x: -73.9857, y: 40.7484),
+            (x: -73.9855, y: 40.7580),
+        ];
+        // a replay of the trace, noisy and sampled at different vertices
+        let replay = line_string![
+            (x: -73.9896, y: 40.7413),
+            (x: -73.9866, y: 40.7467),
+            (x: -73.9856, y: 40.7530),
+            (x: -73.9854, y: 40.7579),
+        ];
+
+        // the full Hausdorff distance, from every vertex to its nearest one on the other side
+        let directed = |from: &LineString<f64>, to: &LineString<f64>| {
+            from.points()
+                .map(|point| {
+                    to.points()
+                        .map(|other| ruler.distance(point, other))
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0., f64::max)
+        };
+        let hausdorff = directed(&trace, &replay).max(directed(&replay, &trace));
+
+        assert!(ruler.hausdorff_within(&trace, &replay, hausdorff));
+        assert!(ruler.hausdorff_within(&replay, &trace, hausdorff));
+        assert!(ruler.hausdorff_within(&trace, &replay, hausdorff * (1. + 1e-9)));
+        assert!(!ruler.hausdorff_within(&trace, &replay, hausdorff * (1. - 1e-9)));
+        assert!(!ruler.hausdorff_within(&replay, &trace, hausdorff * (1. - 1e-9)));
+
+        // only one direction exceeds a threshold between the two directed distances
+        let shorter = directed(&trace, &replay).min(directed(&replay, &trace));
+        assert!(shorter < hausdorff);
+        assert!(!ruler.hausdorff_within(&trace, &replay, shorter));
+        assert!(!ruler.hausdorff_within(&replay, &trace, shorter));
+
+        let empty = LineString::<f64>::new(vec![]);
+
+        assert!(ruler.hausdorff_within(&empty, &empty, 0.));
+        assert!(!ruler.hausdorff_within(&trace, &empty, 1e9));
+    }
+
+    #[test]
+    fn points_along_line_string() {
+        let ruler = RulerMeasure::WGS84();
+
+        // up 5th Avenue, across 42nd Street and up Park Avenue
+        let route = line_string![
+            (x: -73.9897, y: 40.7411),
+            (x: -73.9817, y: 40.7540),
+            (x: -73.9772, y: 40.7527),
+            (x: -73.9724, y: 40.7592),
+        ];
+        let vertices: Vec<[f64; 2]> = route.coords().map(|c| (*c).into()).collect();
+
+        let length = ruler.total_length(&route);
+        let spacing = 150.;
+
+        let points: Vec<_> = ruler
+            .points_along_line_string(&route, spacing, false)
+            .collect();
+
+        assert_eq!(points.len(), (length / spacing).floor() as usize);
+
+        // every point is a whole number of spacings along the path, across the corners
+        for (i, point) in points.iter().enumerate() {
+            let along = ruler
+                .0
+                .along(&vertices, &(spacing * (i + 1) as f64))
+                .unwrap();
+
+            assert_relative_eq!(point.x(), along[0], epsilon = 1e-9);
+            assert_relative_eq!(point.y(), along[1], epsilon = 1e-9);
+        }
+
+        // the points are spread over all three segments
+        for segment in route.lines() {
+            let on_segment = points.iter().any(|point| {
+                ruler.0.cross_track_distance(
+                    &(*point).into(),
+                    &segment.start.into(),
+                    &segment.end.into(),
+                ) < 1e-6
+            });
+            assert!(on_segment);
+        }
+
+        let with_ends: Vec<_> = ruler
+            .points_along_line_string(&route, spacing, true)
+            .collect();
+
+        assert_eq!(with_ends.len(), points.len() + 2);
+        assert_eq!(with_ends.first(), route.points().next().as_ref());
+        assert_eq!(with_ends.last(), route.points().next_back().as_ref());
+        assert_eq!(with_ends[1..with_ends.len() - 1], points);
+
+        assert_eq!(
+            ruler
+                .points_along_line_string(&route, 0., true)
+                .collect::<Vec<_>>()
+                .len(),
+            2
+        );
+
+        // many points in f32 are placed from their index, not accumulated
+        let ruler = RulerMeasure::<f32>::WGS84();
+        let route = line_string![(x: 0., y: 0.), (x: 0.005, y: 0.), (x: 0.01, y: 0.)];
+
+        let spacing = ruler.total_length(&route) / 100_000.5;
+
+        assert_eq!(
+            ruler
+                .points_along_line_string(&route, spacing, false)
+                .count(),
+            100_000
+        );
+    }
+
+    #[test]
+    fn could_be_within() {
+        let ruler = RulerMeasure::WGS84();
+
+        // deterministic pseudo-random coordinates
+        let mut seed: u64 = 42;
+        let mut random = move |min: f64, max: f64| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            min + (max - min) * ((seed >> 11) as f64 / (1u64 << 53) as f64)
+        };
+
+        let mut accepted = 0;
+
+        for _ in 0..2_000 {
+            let lat = random(-80., 80.);
+            let lon = random(-170., 170.);
+
+            let point = point!(x: lon + random(-0.05, 0.05), y: lat + random(-0.05, 0.05));
+            let line = line_string![
+                (x: lon + random(-0.05, 0.05), y: lat + random(-0.05, 0.05)),
+                (x: lon + random(-0.05, 0.05), y: lat + random(-0.05, 0.05)),
+                (x: lon + random(-0.05, 0.05), y: lat + random(-0.05, 0.05)),
+            ];
+
+            let radius = random(0., 3_000.);
+
+            let distance = line
+                .0
+                .iter()
+                .map(|vertex| ruler.distance(point, Point(*vertex)))
+                .fold(f64::INFINITY, f64::min);
+
+            if distance <= radius {
+                accepted += 1;
+                assert!(ruler.could_be_within(&point, &line, radius));
+                assert!(ruler.could_be_within(&line, &point, radius));
+            }
+        }
+
+        assert!(accepted > 0);
+
+        let empty = line_string![];
+        assert!(!ruler.could_be_within(&empty, &point!(x: 0., y: 0.), 1e6));
+    }
+
+    #[test]
+    fn collect_points_along_line() {
         let empire_state = point!(x: -73.9857, y: 40.7484);
         let flatiron = point!(x: -73.9897, y: 40.7411);
-        let distance = 100.;
 
-        let geodesic_point = Geodesic.point_at_distance_between(empire_state, flatiron, distance);
-        let ruler_point =
-            RulerMeasure::WGS84().point_at_distance_between(empire_state, flatiron, distance);
+        let ruler = RulerMeasure::WGS84();
 
-        let error = Geodesic.distance(geodesic_point, ruler_point) / distance;
+        for max_distance in [1., 10., 33., 100., 1000.] {
+            for include_ends in [true, false] {
+                let points = ruler.collect_points_along_line(
+                    empire_state,
+                    flatiron,
+                    max_distance,
+                    include_ends,
+                );
 
-        assert_relative_eq!(0., error, epsilon = EPSILON, max_relative = RELATIVE_ERROR)
+                let expected = ruler
+                    .points_along_line(empire_state, flatiron, max_distance, include_ends)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(points, expected);
+                assert_eq!(points.capacity(), points.len());
+            }
+        }
     }
 
     #[test]
-    fn interpolate_ratio() {
+    fn points_along_line_tiny_step() {
+        let start = point!(x: 0f32, y: 0.);
+        let end = point!(x: 0.5f32, y: 0.);
+
+        let ruler = RulerMeasure::WGS84();
+
+        // an accumulated f32 offset stops moving long before the end with such a step
+        let mut points = LinePointInterpolator::new(start, end, &ruler, 1e-8, true);
+        assert_eq!(points.remaining(), 100_000_001);
+
+        points.index = points.steps - 1;
+
+        let last = points.next().unwrap();
+        assert_relative_eq!(last.x(), end.x());
+
+        assert_eq!(points.next(), Some(end));
+        assert_eq!(points.next(), None);
+
+        // a non-positive step yields the ends only
+        for step in [0., -1., f32::NAN] {
+            let points = LinePointInterpolator::new(start, end, &ruler, step, true);
+            assert_eq!(points.collect::<Vec<_>>(), [start, end]);
+        }
+    }
+
+    #[test]
+    fn points_along_line_into() {
         let empire_state = point!(x: -73.9857, y: 40.7484);
         let flatiron = point!(x: -73.9897, y: 40.7411);
-        let ratio = 0.15;
 
-        let geodesic_point = Geodesic.point_at_ratio_between(empire_state, flatiron, ratio);
-        let ruler_point =
-            RulerMeasure::WGS84().point_at_ratio_between(empire_state, flatiron, ratio);
+        let ruler = RulerMeasure::WGS84();
 
-        let error = Geodesic.distance(geodesic_point, ruler_point);
+        for include_ends in [true, false] {
+            let expected =
+                ruler.collect_points_along_line(empire_state, flatiron, 50., include_ends);
 
-        assert_relative_eq!(0., error, epsilon = EPSILON, max_relative = RELATIVE_ERROR)
+            let mut exact = vec![Point::new(0., 0.); expected.len()];
+            let count = ruler
+                .points_along_line_into(empire_state, flatiron, 50., include_ends, &mut exact)
+                .unwrap();
+
+            assert_eq!(count, expected.len());
+            assert_eq!(exact, expected);
+
+            let mut undersized = vec![Point::new(0., 0.); expected.len() - 1];
+            assert_eq!(
+                ruler.points_along_line_into(
+                    empire_state,
+                    flatiron,
+                    50.,
+                    include_ends,
+                    &mut undersized
+                ),
+                Err(RulerError::BufferTooSmall {
+                    required: expected.len()
+                })
+            );
+            assert!(undersized.iter().all(|point| *point == Point::new(0., 0.)));
+        }
+
+        // the required length matches the points actually yielded, whatever the step
+        let distance = ruler.distance(empire_state, flatiron);
+
+        for parts in 1..=200 {
+            for include_ends in [true, false] {
+                let expected = match include_ends {
+                    true => parts + 1,
+                    false => parts - 1,
+                };
+
+                let step = 1. / parts as f64;
+                let points =
+                    LinePointInterpolator::new(empire_state, flatiron, &ruler, step, include_ends);
+
+                assert_eq!(points.remaining(), expected);
+                assert_eq!(points.count(), expected);
+
+                let mut buffer = vec![Point::new(0., 0.); expected];
+                let count = ruler
+                    .points_along_line_into(
+                        empire_state,
+                        flatiron,
+                        distance / parts as f64,
+                        include_ends,
+                        &mut buffer,
+                    )
+                    .unwrap();
+
+                assert_eq!(count, expected);
+                assert_eq!(buffer.last() == Some(&flatiron), include_ends);
+            }
+        }
     }
 
     #[test]
-    fn interpolate_along_no_ends() {
+    fn points_along_line_collect_into() {
         let empire_state = point!(x: -73.9857, y: 40.7484);
         let flatiron = point!(x: -73.9897, y: 40.7411);
-        let max_distance = 100.;
+        let times_square = point!(x: -73.9855, y: 40.7580);
 
         let ruler = RulerMeasure::WGS84();
 
-        let mut points = ruler.points_along_line(empire_state, flatiron, max_distance, false);
+        let mut buffer = Vec::new();
 
-        let Some(mut prev) = points.next() else {
-            panic!("points is empty");
-        };
+        // longer and shorter paths in turn, so the buffer both grows and shrinks
+        for (end, max_distance) in [(times_square, 10.), (flatiron, 100.), (times_square, 50.)] {
+            for include_ends in [true, false] {
+                ruler.points_along_line_collect_into(
+                    empire_state,
+                    end,
+                    max_distance,
+                    include_ends,
+                    &mut buffer,
+                );
 
-        let mut found_empire_state = prev == empire_state;
-        let mut found_flatiron = prev == flatiron;
+                assert_eq!(
+                    buffer,
+                    ruler.collect_points_along_line(empire_state, end, max_distance, include_ends)
+                );
+            }
+        }
 
-        for curr in points {
-            let distance = Geodesic.distance(prev, curr);
+        // refilling with fewer points keeps the allocation
+        let capacity = buffer.capacity();
+        ruler.points_along_line_collect_into(empire_state, flatiron, 1000., true, &mut buffer);
 
-            if curr == empire_state {
-                found_empire_state = true;
-            }
+        assert_eq!(buffer.capacity(), capacity);
+        assert_eq!(buffer, [empire_state, flatiron]);
+    }
 
-            if curr == flatiron {
-                found_flatiron = true;
+    #[test]
+    fn simplify() {
+        let ruler = RulerMeasure::WGS84();
+
+        // manual recursive Douglas–Peucker using the ruler's cross-track distance
+        fn douglas_peucker(
+            ruler: &RulerMeasure<f64>,
+            points: &[Coord<f64>],
+            tolerance: f64,
+        ) -> Vec<Coord<f64>> {
+            let (first, last) = (points[0], points[points.len() - 1]);
+
+            let farthest = (1..points.len() - 1)
+                .map(|i| {
+                    let distance = ruler.0.cross_track_distance(
+                        &points[i].into(),
+                        &first.into(),
+                        &last.into(),
+                    );
+                    (i, distance)
+                })
+                .fold(
+                    (0, 0.),
+                    |max, current| if current.1 > max.1 { current } else { max },
+                );
+
+            if farthest.1 > tolerance {
+                let mut left = douglas_peucker(ruler, &points[..=farthest.0], tolerance);
+                let right = douglas_peucker(ruler, &points[farthest.0..], tolerance);
+
+                left.pop();
+                left.extend(right);
+                left
+            } else {
+                vec![first, last]
             }
+        }
 
-            prev = curr;
+        // a zigzag along Broadway with offsets of a few meters
+        let route: LineString<f64> = (0..40)
+            .map(|i| {
+                let offset = if i % 3 == 0 {
+                    0.00005
+                } else {
+                    -0.00002 * (i % 5) as f64
+                };
+                (
+                    -73.9897 + i as f64 * 0.0001 + offset,
+                    40.7411 + i as f64 * 0.0004,
+                )
+            })
+            .collect();
 
-            assert!(
-                distance < max_distance
-                    || relative_eq!(distance, max_distance, max_relative = RELATIVE_ERROR),
-            )
+        for tolerance in [1., 3., 10., 100.] {
+            let expected = douglas_peucker(&ruler, &route.0, tolerance);
+
+            assert_eq!(ruler.simplify(&route, tolerance).0, expected);
         }
 
-        assert!(!found_flatiron && !found_empire_state);
+        let simplified = ruler.simplify_vw(&route, 100.);
+        assert!(simplified.0.len() < route.0.len());
+        assert_eq!(simplified.0.first(), route.0.first());
+        assert_eq!(simplified.0.last(), route.0.last());
     }
 
     #[test]
-    fn interpolate_along_with_ends() {
+    fn destination_coord() {
+        let ruler = RulerMeasure::WGS84();
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+
+        for bearing in [0., 45., 200., -90.] {
+            let expected: Coord<f64> = ruler.destination(empire_state, bearing, 1_000.).into();
+
+            assert_eq!(
+                ruler.destination_coord(empire_state.into(), bearing, 1_000.),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip_error() {
+        let ruler = RulerMeasure::WGS84();
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+
+        let errors: Vec<f64> = [1_000., 10_000., 100_000., 1_000_000.]
+            .map(|distance| ruler.round_trip_error(empire_state, 30., distance))
+            .to_vec();
+
+        assert!(errors[0] < 1e-4);
+        assert!(errors[1] < 1e-3);
+
+        for pair in errors.windows(2) {
+            assert!(pair[1] > pair[0]);
+        }
+
+        assert_eq!(ruler.round_trip_error(empire_state, 30., 0.), 0.);
+    }
+
+    #[test]
+    fn line_length_and_bearing() {
+        let ruler = RulerMeasure::WGS84();
+
         let empire_state = point!(x: -73.9857, y: 40.7484);
         let flatiron = point!(x: -73.9897, y: 40.7411);
-        let max_distance = 100.;
+        let line = Line::new(empire_state, flatiron);
+
+        assert_eq!(
+            ruler.line_length(&line),
+            ruler.distance(empire_state, flatiron)
+        );
+        assert_eq!(
+            ruler.line_bearing(&line),
+            ruler.bearing(empire_state, flatiron)
+        );
 
+        let degenerate = Line::new(empire_state, empire_state);
+        assert_eq!(ruler.line_length(&degenerate), 0.);
+    }
+
+    #[test]
+    fn points_along_line_metric() {
         let ruler = RulerMeasure::WGS84();
 
-        let mut points = ruler.points_along_line(empire_state, flatiron, max_distance, true);
+        // a 45° diagonal of about 550 km, across four degrees of latitude
+        let start = point!(x: -74., y: 40.);
+        let end = point!(x: -70., y: 44.);
 
-        let Some(mut prev) = points.next() else {
-            panic!("points is empty");
+        let spacing = |points: &[Point<f64>]| -> (f64, f64) {
+            points
+                .windows(2)
+                .map(|pair| {
+                    let coefs = ruler.0.coefs_at(&((pair[0].y() + pair[1].y()) / 2.));
+                    ruler
+                        .0
+                        .distance_with(&coefs, &pair[0].into(), &pair[1].into())
+                })
+                .fold((f64::INFINITY, 0.), |(min, max), d| {
+                    (min.min(d), max.max(d))
+                })
         };
 
-        let mut found_empire_state = prev == empire_state;
-        let mut found_flatiron = prev == flatiron;
+        let metric: Vec<_> = ruler
+            .points_along_line_metric(start, end, 10_000., true)
+            .collect();
 
-        for curr in points {
-            let distance = Geodesic.distance(prev, curr);
+        assert_eq!(metric.first(), Some(&start));
+        assert_eq!(metric.last(), Some(&end));
 
-            if curr == empire_state {
-                found_empire_state = true;
-            }
+        let (min, max) = spacing(&metric);
+        assert!(max <= 10_000. && max / min < 1.001);
 
-            if curr == flatiron {
-                found_flatiron = true;
-            }
+        // interpolating linearly in degrees drifts along the way
+        let linear: Vec<_> = ruler.points_along_line(start, end, 10_000., true).collect();
 
-            prev = curr;
+        let (min, max) = spacing(&linear[..linear.len() - 1]);
+        assert!(max / min > 1.01);
 
-            assert!(
-                distance < max_distance
-                    || relative_eq!(distance, max_distance, max_relative = RELATIVE_ERROR),
-            )
-        }
+        // without the ends, and degenerate steps
+        let inner: Vec<_> = ruler
+            .points_along_line_metric(start, end, 10_000., false)
+            .collect();
+        assert_eq!(inner, metric[1..metric.len() - 1]);
 
-        assert!(found_flatiron && found_empire_state);
+        assert_eq!(
+            ruler
+                .points_along_line_metric(start, end, 0., true)
+                .collect::<Vec<_>>(),
+            [start, end]
+        );
+        assert_eq!(
+            ruler
+                .points_along_line_metric(start, end, 0., false)
+                .count(),
+            0
+        );
     }
 
     #[test]
-    fn non_standard_model() {
-        let olympus_mons = point!(x: -226.2, y: 18.65);
-        let karzok_crater = point!(x: -226.192, y: 18.2292);
+    fn nearest_point_on_geometry() {
+        let ruler = RulerMeasure::WGS84();
 
-        let mars_equatorial_radius = 3_396_200.;
-        let mars_polar_radius = 3_376_200.;
-        let mars_flattening = 0.00589;
+        let broadway = Geometry::LineString(line_string![
+            (x: -73.9897, y: 40.7411), // Flatiron Building
+            (x: -73.9855, y: 40.7580), // Times Square
+        ]);
 
-        let mars_geoid = GeodesicMeasure::new(mars_equatorial_radius, mars_flattening);
-        let mars_ruler = RulerMeasure::new(mars_equatorial_radius, mars_polar_radius);
+        let empire_state = point!(x: -73.9857, y: 40.7484);
 
-        let distance_geodesic = mars_geoid.distance(olympus_mons, karzok_crater);
-        let distance_ruler = mars_ruler.distance(olympus_mons, karzok_crater);
+        let (snapped, distance) = ruler
+            .nearest_point_on_geometry(&broadway, empire_state)
+            .unwrap();
+
+        let Geometry::LineString(line) = &broadway else {
+            unreachable!()
+        };
+        let (closest, expected, _) = ruler.closest_point_detailed(line, empire_state).unwrap();
 
+        assert_relative_eq!(snapped, closest, epsilon = 1e-9);
+        assert_relative_eq!(distance, expected, max_relative = 1e-9);
         assert_relative_eq!(
-            distance_geodesic,
-            distance_ruler,
+            distance,
+            ruler.distance(empire_state, snapped),
+            max_relative = 1e-9
+        );
+
+        // a polygon snaps to its boundary, from the inside too
+        let block = Geometry::Polygon(polygon![
+            (x: -73.990, y: 40.750),
+            (x: -73.980, y: 40.750),
+            (x: -73.980, y: 40.755),
+            (x: -73.990, y: 40.755),
+        ]);
+
+        let inside = point!(x: -73.988, y: 40.751);
+        let (snapped, distance) = ruler.nearest_point_on_geometry(&block, inside).unwrap();
+
+        assert_relative_eq!(snapped, point!(x: -73.988, y: 40.750), epsilon = 1e-9);
+        assert_relative_eq!(
+            distance,
+            ruler.distance_to_polygon_boundary(&Polygon::try_from(block.clone()).unwrap(), inside)
+        );
+
+        let outside = point!(x: -73.975, y: 40.752);
+        let (snapped, _) = ruler.nearest_point_on_geometry(&block, outside).unwrap();
+        assert_relative_eq!(snapped, point!(x: -73.980, y: 40.752), epsilon = 1e-9);
+
+        // points and collections
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+        assert_eq!(
+            ruler.nearest_point_on_geometry(&Geometry::Point(flatiron), empire_state),
+            Some((flatiron, ruler.distance(empire_state, flatiron)))
+        );
+
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            Geometry::Point(flatiron),
+            block,
+        ]));
+        let (snapped, _) = ruler
+            .nearest_point_on_geometry(&collection, outside)
+            .unwrap();
+        assert_relative_eq!(snapped, point!(x: -73.980, y: 40.752), epsilon = 1e-9);
+
+        let empty = Geometry::LineString(LineString::<f64>::new(vec![]));
+        assert_eq!(ruler.nearest_point_on_geometry(&empty, empire_state), None);
+    }
+
+    #[test]
+    fn distance_to_polygon_boundary() {
+        let ruler = RulerMeasure::WGS84();
+
+        // a block of about 850 x 550 meters with a square hole in the middle
+        let block = polygon!(
+            exterior: [
+                (x: -73.990, y: 40.750),
+                (x: -73.980, y: 40.750),
+                (x: -73.980, y: 40.755),
+                (x: -73.990, y: 40.755),
+            ],
+            interiors: [
+                [
+                    (x: -73.986, y: 40.7515),
+                    (x: -73.984, y: 40.7515),
+                    (x: -73.984, y: 40.7535),
+                    (x: -73.986, y: 40.7535),
+                ],
+            ],
+        );
+
+        // inside, closest to the southern edge
+        let inside = point!(x: -73.988, y: 40.751);
+        assert_relative_eq!(
+            ruler.distance_to_polygon_boundary(&block, inside),
+            ruler.distance(inside, point!(x: -73.988, y: 40.750)),
+            max_relative = RELATIVE_ERROR
+        );
+
+        // outside, closest to the eastern edge
+        let outside = point!(x: -73.975, y: 40.752);
+        assert_relative_eq!(
+            ruler.distance_to_polygon_boundary(&block, outside),
+            ruler.distance(outside, point!(x: -73.980, y: 40.752)),
+            max_relative = RELATIVE_ERROR
+        );
+
+        // inside the hole, closest to its northern edge
+        let in_hole = point!(x: -73.985, y: 40.7532);
+        assert_relative_eq!(
+            ruler.distance_to_polygon_boundary(&block, in_hole),
+            ruler.distance(in_hole, point!(x: -73.985, y: 40.7535)),
+            max_relative = RELATIVE_ERROR
+        );
+
+        let on_edge = point!(x: -73.985, y: 40.750);
+        assert_eq!(ruler.distance_to_polygon_boundary(&block, on_edge), 0.);
+
+        let on_vertex = point!(x: -73.990, y: 40.755);
+        assert_eq!(ruler.distance_to_polygon_boundary(&block, on_vertex), 0.);
+    }
+
+    #[test]
+    fn area() {
+        let ruler = RulerMeasure::WGS84();
+
+        // a block of about 850 x 550 meters with a square hole in the middle
+        let block = polygon!(
+            exterior: [
+                (x: -73.990, y: 40.750),
+                (x: -73.980, y: 40.750),
+                (x: -73.980, y: 40.755),
+                (x: -73.990, y: 40.755),
+            ],
+            interiors: [
+                [
+                    (x: -73.986, y: 40.7515),
+                    (x: -73.984, y: 40.7515),
+                    (x: -73.984, y: 40.7535),
+                    (x: -73.986, y: 40.7535),
+                ],
+            ],
+        );
+
+        // geo expects the hole wound against the exterior, the ruler does not care
+        let area = ruler.area_unsigned(&block);
+        assert_relative_eq!(
+            area,
+            block.orient(Direction::Default).geodesic_area_unsigned(),
+            max_relative = RELATIVE_ERROR
+        );
+
+        let exterior = Polygon::new(block.exterior().clone(), vec![]);
+        let hole = Polygon::new(block.interiors()[0].clone(), vec![]);
+        assert_relative_eq!(
+            area,
+            ruler.area_unsigned(&exterior) - ruler.area_unsigned(&hole),
+            max_relative = 1e-12
+        );
+
+        // the sign follows the exterior ring, whatever the winding of the hole
+        assert_eq!(ruler.area_signed(&block), area);
+
+        let mut clockwise = block.clone();
+        clockwise.exterior_mut(|ring| ring.0.reverse());
+        clockwise.interiors_mut(|rings| rings[0].0.reverse());
+
+        assert_relative_eq!(ruler.area_unsigned(&clockwise), area, max_relative = 1e-12);
+        assert_relative_eq!(ruler.area_signed(&clockwise), -area, max_relative = 1e-12);
+        assert_relative_eq!(
+            ruler.area_signed(&clockwise),
+            block.orient(Direction::Reversed).geodesic_area_signed(),
             max_relative = RELATIVE_ERROR
         );
     }
+
+    #[test]
+    fn f32_precision_loss() {
+        // two points ~0.56 m apart next to the antimeridian
+        let a = point!(x: 179.999_900, y: 0.);
+        let b = point!(x: 179.999_905, y: 0.);
+
+        let distance = Geodesic.distance(a, b);
+
+        let single = RulerMeasure::<f32>::WGS84().distance(
+            a.map_coords(|Coord { x, y }| Coord {
+                x: x as f32,
+                y: y as f32,
+            }),
+            b.map_coords(|Coord { x, y }| Coord {
+                x: x as f32,
+                y: y as f32,
+            }),
+        );
+
+        let double = RulerMeasure::<f64>::WGS84().distance(a, b);
+
+        assert!((single as f64 - distance).abs() / distance > 0.5);
+        assert_relative_eq!(double, distance, max_relative = RELATIVE_ERROR);
+    }
+
+    #[test]
+    fn interpolate_on_line() {
+        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let flatiron = point!(x: -73.9897, y: 40.7411);
+
+        let line = Line::new(empire_state, flatiron);
+        let ruler = RulerMeasure::WGS84();
+
+        assert_eq!(
+            ruler.point_at_ratio_on_line(&line, 0.5),
+            ruler.point_at_ratio_between(empire_state, flatiron, 0.5)
+        );
+        assert_eq!(
+            ruler.point_at_distance_on_line(&line, 100.),
+            ruler.point_at_distance_between(empire_state, flatiron, 100.)
+        );
+    }
 }
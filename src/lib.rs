@@ -24,27 +24,95 @@
 //! println!("Distance: {:.1} meters", distance);
 //! ```
 //!
+//! # Degenerate Inputs
+//!
+//! Methods taking collections of points never panic on short inputs, and follow the same
+//! conventions across the crate:
+//!
+//! - Measures of a polyline with less than two vertices are zero, e.g. its length
+//! - Queries that need at least one point return `None` on empty inputs, e.g. the bounding
+//!   box or the point along a line, and the distance to an empty line is infinite
+//! - Operations building new vertex lists return inputs too short to change unchanged,
+//!   e.g. simplifying less than three vertices or densifying less than two
+//! - Rings with less than three vertices enclose no area
+//! - Batch methods write one output per input, and nothing for empty inputs
+//! - Spacings that are not positive, or NaN, add no intermediate points, e.g. densifying
+//!   returns the input unchanged and points along a line only yield its vertices
+//!
 //! # Feature Flags
 //!
+//! The crate is `no_std`, the core methods take slices and arrays and never allocate.
+//!
+//! - `std`: Use the standard library float functions, otherwise `libm` is used
 //! - `geo`: Integration with the geo-rs crate ecosystem
 //! - `wasm`: WebAssembly bindings for JavaScript interop
+//! - `alloc`: Methods returning heap allocated collections, such as `Vec` (enabled by `geo`)
+//! - `memoize`: Reuse the scaling factors across back-to-back calls at the same latitude
+//!   (makes `CheapRuler` not `Sync`)
+//! - `fixed_point`: [`MicroRuler`], measuring integer microdegrees without floating point,
+//!   for targets without an FPU
 //! - `atan2_deg3`: Use 3rd degree polynomial approximation for `atan2` (faster)
-//! - `atan2_deg5`: Use 5th degree polynomial approximation for `atan2` (more accurate)
+//! - `atan2_deg5`: Use 5th degree polynomial approximation for `atan2` (more accurate),
+//!   taking precedence over `atan2_deg3` when both are enabled
+//!
+//! The features only set the defaults, see [`RulerConfig`] to choose the units, the
+//! ellipsoid, the `atan2` approximation and the antimeridian handling of each ruler.
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(test)]
+extern crate std;
+
+mod along;
+mod antimeridian;
+mod config;
 mod constants;
+mod error;
+mod fixed;
+mod units;
 
 #[cfg(feature = "wasm")]
 mod wasm;
 
-#[cfg(any(feature = "atan2_deg3", feature = "atan2_deg5"))]
 pub mod math;
 
+mod rhumb;
+
 #[cfg(feature = "geo")]
 pub mod geo;
 
+#[cfg(feature = "fixed_point")]
+mod micro;
+
+#[cfg(feature = "alloc")]
+mod bounding_circle;
+
+#[cfg(feature = "alloc")]
+mod convex_hull;
+
+#[cfg(feature = "alloc")]
+mod polyline;
+
+#[cfg(feature = "alloc")]
+mod simplify;
+
+pub use along::AlongLine;
+pub use antimeridian::AntimeridianMode;
+pub use config::{Atan2Mode, RulerConfig};
+pub use error::RulerError;
+pub use fixed::FixedRuler;
+#[cfg(feature = "fixed_point")]
+pub use micro::MicroRuler;
+#[cfg(feature = "alloc")]
+pub use polyline::Polyline;
+pub use units::Units;
+
 use constants::{WGS84_E2, WGS84_RE};
+#[cfg(feature = "memoize")]
+use core::cell::Cell;
 use core::convert::From;
 use core::fmt::Debug;
 use num_traits::{Float, FloatConst};
@@ -56,9 +124,25 @@ use num_traits::{Float, FloatConst};
 /// latitude. This approach trades some accuracy for significant performance gains.
 ///
 /// The ruler works with any floating-point type that implements the required traits.
+///
+/// Distances are measured in meters by default, see [`with_units`](Self::with_units)
+/// to use other units for both inputs and outputs. Longitude differences take the short
+/// way across the antimeridian by default, see [`with_antimeridian`](Self::with_antimeridian).
+///
+/// With the `memoize` feature the ruler caches its last scaling factors in a `Cell`, so it
+/// is not `Sync` and cannot be shared between threads, e.g. in a `static`. As features are
+/// unified, this applies as soon as any crate in the dependency graph enables it.
+#[derive(Debug, Clone)]
 pub struct CheapRuler<T: Float> {
     re: T,
     e2: T,
+    units: Units,
+    antimeridian: AntimeridianMode,
+    /// `atan2` approximation chosen at runtime, overriding the one selected by the features.
+    atan2: Option<fn(T, T) -> T>,
+    /// Latitude and scaling factors of the last `coefs` computation.
+    #[cfg(feature = "memoize")]
+    cache: Cell<Option<(T, [T; 2])>>,
 }
 
 impl<T: Float + FloatConst + Debug + From<f32>> CheapRuler<T> {
@@ -70,23 +154,246 @@ impl<T: Float + FloatConst + Debug + From<f32>> CheapRuler<T> {
         CheapRuler {
             re: WGS84_RE.into(),
             e2: WGS84_E2.into(),
+            units: Units::Meters,
+            antimeridian: AntimeridianMode::Wrap,
+            atan2: None,
+            #[cfg(feature = "memoize")]
+            cache: Cell::new(None),
         }
     }
 }
 
+impl<T: Float + FloatConst + Debug + From<f32>> CheapRuler<T> {
+    /// Returns a ruler computing bearings with the fastest `atan2` within a tolerance.
+    ///
+    /// Picks, at runtime, the lowest degree polynomial approximation of `atan2` whose
+    /// maximum error does not exceed the tolerance, or the standard library `atan2` for
+    /// tolerances below 0.0001°. This overrides the `atan2_deg3` and `atan2_deg5`
+    /// features for this ruler.
+    ///
+    /// | Tolerance | Approximation |
+    /// |-----------|---------------|
+    /// | ≥ 0.6°    | 3rd degree    |
+    /// | ≥ 0.035°  | 5th degree    |
+    /// | ≥ 0.0001° | 11th degree   |
+    /// | otherwise | `atan2`       |
+    ///
+    /// # Parameters
+    ///
+    /// - `tolerance`: Maximum acceptable error of the bearings, in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// // bearings within a hundredth of a degree
+    /// let ruler = CheapRuler::<f64>::WGS84().with_bearing_tolerance(&0.01);
+    ///
+    /// let bearing = ruler.bearing(&[-73.9857, 40.7484], &[-73.9897, 40.7411]);
+    /// ```
+    pub fn with_bearing_tolerance(self, tolerance: &T) -> Self {
+        let at_least = |degrees: f32| *tolerance >= degrees.into();
+
+        let mode = if at_least(0.6) {
+            Atan2Mode::Deg3
+        } else if at_least(0.035) {
+            Atan2Mode::Deg5
+        } else if at_least(0.0001) {
+            Atan2Mode::Deg11
+        } else {
+            Atan2Mode::Std
+        };
+
+        self.with_atan2(mode)
+    }
+
+    /// Returns a ruler computing bearings with the given `atan2` implementation.
+    ///
+    /// Any mode but [`Atan2Mode::Features`] overrides the `atan2_deg3` and `atan2_deg5`
+    /// features for this ruler.
+    ///
+    /// # Parameters
+    ///
+    /// - `mode`: The `atan2` implementation to use
+    ///
+    /// See also: [`with_bearing_tolerance`](Self::with_bearing_tolerance)
+    pub fn with_atan2(self, mode: Atan2Mode) -> Self {
+        let atan2: Option<fn(T, T) -> T> = match mode {
+            Atan2Mode::Features => None,
+            Atan2Mode::Deg3 => Some(math::atan2_deg3),
+            Atan2Mode::Deg5 => Some(math::atan2_deg5),
+            Atan2Mode::Deg11 => Some(math::atan2_deg11),
+            Atan2Mode::Std => Some(T::atan2),
+        };
+
+        Self { atan2, ..self }
+    }
+
+    /// Creates a new `CheapRuler` from a runtime configuration.
+    ///
+    /// # Parameters
+    ///
+    /// - `config`: The ellipsoid, units, `atan2` implementation and antimeridian handling
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::{AntimeridianMode, CheapRuler, RulerConfig};
+    ///
+    /// // Mars, measuring the long way around the antimeridian
+    /// let ruler = CheapRuler::<f64>::from_config(&RulerConfig {
+    ///     major: 3_396_200.,
+    ///     minor: 3_376_200.,
+    ///     antimeridian: AntimeridianMode::Raw,
+    ///     ..RulerConfig::default()
+    /// });
+    /// ```
+    ///
+    /// See also: [`new`](Self::new), [`with_units`](Self::with_units),
+    /// [`with_atan2`](Self::with_atan2), [`with_antimeridian`](Self::with_antimeridian)
+    pub fn from_config(config: &RulerConfig<T>) -> Self {
+        Self::new(&config.major, &config.minor)
+            .with_units(config.units)
+            .with_atan2(config.atan2)
+            .with_antimeridian(config.antimeridian)
+    }
+}
+
 impl<T: Float + FloatConst + Debug> CheapRuler<T> {
     /// Creates a new `CheapRuler` with custom ellipsoid parameters.
     ///
     /// This constructor allows using different ellipsoid models or even
     /// calculations for other celestial bodies.
     ///
+    /// Prolate bodies, where the polar radius exceeds the equatorial one, are also
+    /// supported: `minor` may be larger than `major`, which yields a negative
+    /// eccentricity squared while the scaling factors stay well-defined.
+    ///
     /// # Parameters
     ///
-    /// - `major`: Semi-major axis of the ellipsoid (in meters)
-    /// - `minor`: Semi-minor axis of the ellipsoid (in meters)
+    /// - `major`: Equatorial semi-axis of the ellipsoid (in meters)
+    /// - `minor`: Polar semi-axis of the ellipsoid (in meters)
+    ///
+    /// See also: [`try_new`](Self::try_new)
     pub fn new(major: &T, minor: &T) -> Self {
         let e2 = T::one() - (minor.powi(2) / major.powi(2));
-        Self { re: *major, e2 }
+        Self {
+            re: *major,
+            e2,
+            units: Units::Meters,
+            antimeridian: AntimeridianMode::Wrap,
+            atan2: None,
+            #[cfg(feature = "memoize")]
+            cache: Cell::new(None),
+        }
+    }
+
+    /// Creates a new `CheapRuler` for a sphere.
+    ///
+    /// Useful when only a mean radius is known, equivalent to [`new`](Self::new) with
+    /// equal semi-axes.
+    ///
+    /// # Parameters
+    ///
+    /// - `radius`: Radius of the sphere (in meters)
+    ///
+    /// See also: [`new`](Self::new)
+    pub fn sphere(radius: &T) -> Self {
+        Self::new(radius, radius)
+    }
+
+    /// Creates a new `CheapRuler` with custom ellipsoid parameters, validating them.
+    ///
+    /// Same as [`new`](Self::new), but fails if the axes are not finite and strictly
+    /// positive, instead of producing a ruler that returns meaningless results.
+    ///
+    /// # Parameters
+    ///
+    /// - `major`: Equatorial semi-axis of the ellipsoid (in meters)
+    /// - `minor`: Polar semi-axis of the ellipsoid (in meters)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RulerError::InvalidEllipsoid`] if either axis is zero, negative, infinite or NaN.
+    pub fn try_new(major: &T, minor: &T) -> Result<Self, RulerError> {
+        let valid = |axis: &T| axis.is_finite() && *axis > T::zero();
+
+        if valid(major) && valid(minor) {
+            Ok(Self::new(major, minor))
+        } else {
+            Err(RulerError::InvalidEllipsoid)
+        }
+    }
+
+    /// Returns a ruler measuring distances in the given units.
+    ///
+    /// Both the returned distances and the distance arguments, such as the one taken
+    /// by [`destination`](Self::destination), are expressed in these units.
+    ///
+    /// # Parameters
+    ///
+    /// - `units`: The distance units to use
+    pub fn with_units(self, units: Units) -> Self {
+        Self {
+            units,
+            #[cfg(feature = "memoize")]
+            cache: Cell::new(None),
+            ..self
+        }
+    }
+
+    /// Returns the distance units used by the ruler.
+    pub fn units(&self) -> Units {
+        self.units
+    }
+
+    /// Returns a ruler handling longitude differences with the given mode.
+    ///
+    /// Affects the longitude difference used by [`distance`](Self::distance) and
+    /// [`bearing`](Self::bearing), along with the methods built on them.
+    ///
+    /// # Parameters
+    ///
+    /// - `mode`: Whether to wrap longitude differences across the antimeridian
+    pub fn with_antimeridian(self, mode: AntimeridianMode) -> Self {
+        Self {
+            antimeridian: mode,
+            ..self
+        }
+    }
+
+    /// Returns the antimeridian handling mode of the ruler.
+    pub fn antimeridian(&self) -> AntimeridianMode {
+        self.antimeridian
+    }
+
+    /// Computes the longitude difference between two longitudes, according to the antimeridian mode.
+    fn delta_lon(&self, from: &T, to: &T) -> T {
+        let delta = *to - *from;
+        let straight = T::PI().to_degrees();
+
+        match self.antimeridian {
+            AntimeridianMode::Wrap if delta.abs() > straight => {
+                let full = straight + straight;
+                delta - full * (delta / full).round()
+            }
+            _ => delta,
+        }
+    }
+
+    /// Wraps a longitude into [-180°, 180°] in [`AntimeridianMode::Wrap`], leaves it
+    /// untouched otherwise.
+    pub(crate) fn wrap_lon(&self, lon: &T) -> T {
+        let straight = T::PI().to_degrees();
+
+        match self.antimeridian {
+            AntimeridianMode::Wrap if lon.abs() > straight => {
+                let full = straight + straight;
+                *lon - full * (*lon / full).round()
+            }
+            _ => *lon,
+        }
     }
 
     /// Calculates the latitude-dependent coefficients for distance calculations.
@@ -101,20 +408,73 @@ impl<T: Float + FloatConst + Debug> CheapRuler<T> {
     /// # Returns
     ///
     /// An array of two scaling factors:
-    /// - `kx`: The longitude scaling factor (converts longitude degrees to meters)
-    /// - `ky`: The latitude scaling factor (converts latitude degrees to meters)
+    /// - `kx`: The longitude scaling factor (converts longitude degrees to the ruler units)
+    /// - `ky`: The latitude scaling factor (converts latitude degrees to the ruler units)
     fn coefs(&self, origin: &[T; 2]) -> [T; 2] {
-        let c = origin[1].to_radians().cos();
+        self.coefs_at(&origin[1])
+    }
+
+    /// Calculates the latitude-dependent coefficients at a given latitude.
+    ///
+    /// See [`coefs`](Self::coefs), this variant takes the reference latitude directly.
+    ///
+    /// With the `memoize` feature, the factors of the last latitude are cached, so
+    /// repeated calls at the same latitude skip the computation.
+    fn coefs_at(&self, lat: &T) -> [T; 2] {
+        #[cfg(feature = "memoize")]
+        if let Some((cached, coefs)) = self.cache.get()
+            && cached == *lat
+        {
+            return coefs;
+        }
+
+        let c = lat.to_radians().cos();
 
         let w = T::one() / (T::one() - self.e2 * (T::one() - c.powi(2)));
-        let k = w.sqrt() * self.re.to_radians();
+        let k = w.sqrt() * self.re.to_radians() / self.units.meters();
 
         let kx = k * c;
         let ky = k * w * (T::one() - self.e2);
 
+        #[cfg(feature = "memoize")]
+        self.cache.set(Some((*lat, [kx, ky])));
+
         [kx, ky]
     }
 
+    /// Calculates the ground resolution of a Web Mercator map at a latitude and zoom level.
+    ///
+    /// Uses the standard resolution of 256 pixel tiles, on a sphere with the equatorial
+    /// radius of the ruler, scaled by the cosine of the latitude.
+    ///
+    /// # Parameters
+    ///
+    /// - `lat`: Latitude in degrees
+    /// - `zoom`: Web Mercator zoom level, 0 for the whole world in a single tile
+    ///
+    /// # Returns
+    ///
+    /// The distance a screen pixel spans on the ground, in the ruler units (meters by default)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// // a street level map of midtown Manhattan, about 1.8 meters per pixel
+    /// let resolution = ruler.meters_per_pixel(&40.7484, 16);
+    /// ```
+    pub fn meters_per_pixel(&self, lat: &T, zoom: u32) -> T {
+        let two = T::one() + T::one();
+        let tile = T::from(256).unwrap();
+
+        let circumference = two * T::PI() * self.re / self.units.meters();
+
+        circumference * lat.to_radians().cos() / (tile * two.powi(zoom as i32))
+    }
+
     /// Calculates the destination point given an origin, bearing, and distance.
     ///
     /// Uses the flat Earth approximation with latitude-dependent scaling to compute
@@ -124,13 +484,135 @@ impl<T: Float + FloatConst + Debug> CheapRuler<T> {
     ///
     /// - `origin`: Starting point as `[longitude, latitude]` in degrees
     /// - `bearing`: Direction of travel in degrees (0° = North, 90° = East)
-    /// - `distance`: Distance to travel in meters
+    /// - `distance`: Distance to travel in the ruler units
     ///
     /// # Returns
     ///
     /// Destination point as `[longitude, latitude]` in degrees
     pub fn destination(&self, origin: &[T; 2], bearing: &T, distance: &T) -> [T; 2] {
-        let [kx, ky] = self.coefs(origin);
+        Self::destination_with(&self.coefs(origin), origin, bearing, distance)
+    }
+
+    /// Interpolates linearly between two points.
+    ///
+    /// In [`AntimeridianMode::Wrap`] the interpolation goes the short way around, across
+    /// the antimeridian if needed, and the resulting longitude is wrapped into [-180°, 180°].
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Starting point as `[longitude, latitude]` in degrees
+    /// - `end`: Ending point as `[longitude, latitude]` in degrees
+    /// - `ratio`: Fraction of the way from start (0.0) to end (1.0)
+    ///
+    /// # Returns
+    ///
+    /// The interpolated point as `[longitude, latitude]` in degrees
+    pub fn interpolate(&self, start: &[T; 2], end: &[T; 2], ratio: &T) -> [T; 2] {
+        let lon = start[0] + self.delta_lon(&start[0], &end[0]) * *ratio;
+        let lat = start[1] + (end[1] - start[1]) * *ratio;
+
+        [self.wrap_lon(&lon), lat]
+    }
+
+    /// Calculates the destination point given an origin as separate coordinates.
+    ///
+    /// Same as [`destination`](Self::destination), for coordinates stored in separate
+    /// longitude and latitude columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `lon`: Longitude of the starting point in degrees
+    /// - `lat`: Latitude of the starting point in degrees
+    /// - `bearing`: Direction of travel in degrees (0° = North, 90° = East)
+    /// - `distance`: Distance to travel in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// Destination point as `[longitude, latitude]` in degrees
+    pub fn destination_ll(&self, lon: &T, lat: &T, bearing: &T, distance: &T) -> [T; 2] {
+        self.destination(&[*lon, *lat], bearing, distance)
+    }
+
+    /// Advances a moving point by dead reckoning.
+    ///
+    /// Same as [`destination`](Self::destination) with the distance covered at a constant
+    /// speed over the elapsed time, e.g. to simulate a vehicle from its heading and speed.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `heading`: Direction of travel in degrees (0° = North, 90° = East)
+    /// - `speed`: Speed in the ruler units per second, e.g. meters per second
+    /// - `dt`: Elapsed time in seconds
+    ///
+    /// # Returns
+    ///
+    /// Position after the elapsed time as `[longitude, latitude]` in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// // a drone leaving the Empire State Building north east at 12 m/s
+    /// let mut position = [-73.9857, 40.7484];
+    ///
+    /// for _ in 0..10 {
+    ///     position = ruler.advance(&position, &45., &12., &0.5);
+    /// }
+    /// ```
+    pub fn advance(&self, origin: &[T; 2], heading: &T, speed: &T, dt: &T) -> [T; 2] {
+        self.destination(origin, heading, &(*speed * *dt))
+    }
+
+    /// Calculates the destination point given an origin, bearing, and distance, validating the inputs.
+    ///
+    /// Same as [`destination`](Self::destination), but fails instead of returning
+    /// meaningless coordinates when the inputs are out of range.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `bearing`: Direction of travel in degrees (0° = North, 90° = East)
+    /// - `distance`: Distance to travel in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// Destination point as `[longitude, latitude]` in degrees
+    ///
+    /// # Errors
+    ///
+    /// - [`RulerError::InvalidOrigin`] if the origin is not finite or its latitude is at or beyond a pole
+    /// - [`RulerError::InvalidBearing`] if the bearing is infinite or NaN
+    /// - [`RulerError::InvalidDistance`] if the distance is negative, infinite or NaN
+    pub fn try_destination(
+        &self,
+        origin: &[T; 2],
+        bearing: &T,
+        distance: &T,
+    ) -> Result<[T; 2], RulerError> {
+        let right = T::FRAC_PI_2().to_degrees();
+
+        if !origin[0].is_finite() || !origin[1].is_finite() || origin[1].abs() >= right {
+            return Err(RulerError::InvalidOrigin);
+        }
+
+        if !bearing.is_finite() {
+            return Err(RulerError::InvalidBearing);
+        }
+
+        if !distance.is_finite() || *distance < T::zero() {
+            return Err(RulerError::InvalidDistance);
+        }
+
+        Ok(self.destination(origin, bearing, distance))
+    }
+
+    /// Calculates the destination point using precomputed scaling factors.
+    fn destination_with(coefs: &[T; 2], origin: &[T; 2], bearing: &T, distance: &T) -> [T; 2] {
+        let [kx, ky] = *coefs;
 
         let (sin, cos) = bearing.to_radians().sin_cos();
 
@@ -140,69 +622,2272 @@ impl<T: Float + FloatConst + Debug> CheapRuler<T> {
         [x, y]
     }
 
-    /// Calculates the distance between two points.
+    /// Offsets a point by the given east and north distances.
     ///
     /// Uses the flat Earth approximation with latitude-dependent scaling computed
-    /// at the origin point for efficient distance calculation.
+    /// at the origin point to convert the metric offsets into degrees.
     ///
     /// # Parameters
     ///
-    /// - `origin`: First point as `[longitude, latitude]` in degrees
-    /// - `destination`: Second point as `[longitude, latitude]` in degrees
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `dx`: Distance to travel east in the ruler units (negative values travel west)
+    /// - `dy`: Distance to travel north in the ruler units (negative values travel south)
     ///
     /// # Returns
     ///
-    /// Distance between the points in meters
-    pub fn distance(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
+    /// Offset point as `[longitude, latitude]` in degrees
+    pub fn offset(&self, origin: &[T; 2], dx: &T, dy: &T) -> [T; 2] {
         let [kx, ky] = self.coefs(origin);
 
-        let dx = (destination[0] - origin[0]) * kx;
-        let dy = (destination[1] - origin[1]) * ky;
-
-        (dx.powi(2) + dy.powi(2)).sqrt()
+        [origin[0] + *dx / kx, origin[1] + *dy / ky]
     }
-}
-
-/// Trait for types that may implement `From<f32>` depending on feature flags.
-///
-/// This trait is used to conditionally require `From<f32>` when using alternative
-/// `atan2` implementations that need to work with f32 constants.
-#[cfg(any(feature = "atan2_deg5", feature = "atan2_deg3"))]
-pub trait MaybeFromf32: From<f32> {}
 
-/// Trait for types that may implement `From<f32>` depending on feature flags.
-#[cfg(not(any(feature = "atan2_deg5", feature = "atan2_deg3")))]
-pub trait MaybeFromf32 {}
+    /// Projects a point onto a plane centered on a reference point.
+    ///
+    /// The coordinates are the east and north distances from the center, scaled at the
+    /// center. This is the azimuthal equidistant projection of the ruler's model: the
+    /// magnitude of the projected point is [`distance`](Self::distance) from the center
+    /// and its direction is [`bearing`](Self::bearing) from the center, e.g. for radar
+    /// style plots.
+    ///
+    /// # Parameters
+    ///
+    /// - `center`: The center of the projection as `[longitude, latitude]` in degrees
+    /// - `point`: The point to project as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The projected point as `[east, north]` in the ruler units
+    ///
+    /// See also: [`from_azimuthal_equidistant`](Self::from_azimuthal_equidistant)
+    pub fn to_azimuthal_equidistant(&self, center: &[T; 2], point: &[T; 2]) -> [T; 2] {
+        let [kx, ky] = self.coefs(center);
 
-#[cfg(any(feature = "atan2_deg5", feature = "atan2_deg3"))]
-impl<T: From<f32>> MaybeFromf32 for T {}
-#[cfg(not(any(feature = "atan2_deg5", feature = "atan2_deg3")))]
-impl<T> MaybeFromf32 for T {}
+        [
+            self.delta_lon(&center[0], &point[0]) * kx,
+            (point[1] - center[1]) * ky,
+        ]
+    }
 
-impl<T: Float + FloatConst + Debug + MaybeFromf32> CheapRuler<T> {
-    /// Calculates the bearing from one point to another.
+    /// Unprojects a point from a plane centered on a reference point.
     ///
-    /// Uses the flat Earth approximation with latitude-dependent scaling to compute
-    /// the initial bearing (forward azimuth) from the origin to the destination.
+    /// Inverse of [`to_azimuthal_equidistant`](Self::to_azimuthal_equidistant), the
+    /// longitude is wrapped according to the [`AntimeridianMode`].
     ///
     /// # Parameters
     ///
-    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
-    /// - `destination`: Target point as `[longitude, latitude]` in degrees
+    /// - `center`: The center of the projection as `[longitude, latitude]` in degrees
+    /// - `projected`: The projected point as `[east, north]` in the ruler units
     ///
     /// # Returns
     ///
-    /// Initial bearing in degrees (0° = North, 90° = East)
-    pub fn bearing(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
-        let [kx, ky] = self.coefs(origin);
+    /// The point as `[longitude, latitude]` in degrees
+    pub fn from_azimuthal_equidistant(&self, center: &[T; 2], projected: &[T; 2]) -> [T; 2] {
+        let [lon, lat] = self.offset(center, &projected[0], &projected[1]);
 
-        let dx = (destination[0] - origin[0]) * kx;
-        let dy = (destination[1] - origin[1]) * ky;
+        [self.wrap_lon(&lon), lat]
+    }
 
-        #[cfg(not(any(feature = "atan2_deg3", feature = "atan2_deg5")))]
-        return dx.atan2(dy).to_degrees();
+    /// Calculates the distance between two points.
+    ///
+    /// Uses the flat Earth approximation with latitude-dependent scaling computed
+    /// at the origin point for efficient distance calculation.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: First point as `[longitude, latitude]` in degrees
+    /// - `destination`: Second point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance between the points in the ruler units
+    pub fn distance(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.distance_with(&self.coefs(origin), origin, destination)
+    }
 
-        #[cfg(any(feature = "atan2_deg5", feature = "atan2_deg3"))]
-        return math::atan2(dx, dy).to_degrees();
+    /// Calculates the distance between two points given as separate coordinates.
+    ///
+    /// Same as [`distance`](Self::distance), for coordinates stored in separate
+    /// longitude and latitude columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `lon1`, `lat1`: First point longitude and latitude in degrees
+    /// - `lon2`, `lat2`: Second point longitude and latitude in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance between the points in the ruler units
+    pub fn distance_ll(&self, lon1: &T, lat1: &T, lon2: &T, lat2: &T) -> T {
+        self.distance(&[*lon1, *lat1], &[*lon2, *lat2])
+    }
+
+    /// Calculates the distance between two points along with a bound on its error.
+    ///
+    /// The ruler takes the latitude-dependent scaling at the origin and treats the
+    /// surface as flat. The bound adds up the two resulting errors: the change of the
+    /// scaling between the origin and the destination, and the curvature of the surface,
+    /// which grows with the cube of the distance and towards the poles. A relative
+    /// single precision rounding term covers the ellipsoid parameters.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: First point as `[longitude, latitude]` in degrees
+    /// - `destination`: Second point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the distance between the points and an estimated bound on its absolute
+    /// error compared to the geodesic distance, both in the ruler units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let empire_state = [-73.9857, 40.7484];
+    /// let flatiron = [-73.9897, 40.7411];
+    ///
+    /// let (distance, error) = ruler.distance_with_error(&empire_state, &flatiron);
+    ///
+    /// // less than ten centimeters over the 880 meters between them
+    /// assert!(error < 0.1);
+    /// ```
+    ///
+    /// See also: [`distance`](Self::distance)
+    pub fn distance_with_error(&self, origin: &[T; 2], destination: &[T; 2]) -> (T, T) {
+        let coefs = self.coefs(origin);
+        let distance = self.distance_with(&coefs, origin, destination);
+
+        let [kx, ky] = coefs;
+        let [ex, ey] = self.coefs(destination);
+
+        let dx = self.delta_lon(&origin[0], &destination[0]) * kx;
+        let dy = (destination[1] - origin[1]) * ky;
+
+        // the exact scaling varies along the way, by no more than between both ends
+        let scaling = (dx * (ex / kx - T::one())).abs() + (dy * (ey / ky - T::one())).abs();
+
+        // flat approximation of a curved surface, with the radius of curvature from `ky`
+        let radius = ky.to_degrees();
+        let tan = origin[1].abs().max(destination[1].abs()).to_radians().tan();
+        let curvature = distance * (distance / radius).powi(2) * (T::one() + tan.powi(2));
+
+        // the ellipsoid parameters are given in single precision
+        let rounding = distance * T::from(f32::EPSILON).unwrap();
+
+        (distance, scaling + curvature + rounding)
+    }
+
+    /// Calculates the distance between two points using precomputed scaling factors.
+    fn distance_with(&self, coefs: &[T; 2], origin: &[T; 2], destination: &[T; 2]) -> T {
+        let [kx, ky] = *coefs;
+
+        let dx = self.delta_lon(&origin[0], &destination[0]) * kx;
+        let dy = (destination[1] - origin[1]) * ky;
+
+        (dx.powi(2) + dy.powi(2)).sqrt()
+    }
+
+    /// Calculates the distances from one origin to many destinations.
+    ///
+    /// The latitude-dependent scaling is computed once at the origin and reused for
+    /// every destination, avoiding the trigonometric setup of repeated
+    /// [`distance`](Self::distance) calls.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Common starting point as `[longitude, latitude]` in degrees
+    /// - `destinations`: Target points as `[longitude, latitude]` in degrees
+    /// - `out`: Output buffer receiving the distance to each destination in the ruler units
+    ///
+    /// Only the first `min(destinations.len(), out.len())` distances are written.
+    pub fn distance_to_many(&self, origin: &[T; 2], destinations: &[[T; 2]], out: &mut [T]) {
+        let coefs = self.coefs(origin);
+
+        for (destination, distance) in destinations.iter().zip(out.iter_mut()) {
+            *distance = self.distance_with(&coefs, origin, destination);
+        }
+    }
+
+    /// Calculates the squared distances from one origin to many destinations.
+    ///
+    /// Same as [`distance_to_many`](Self::distance_to_many) without the square roots, e.g.
+    /// for a radius filter comparing against the squared radius.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Common starting point as `[longitude, latitude]` in degrees
+    /// - `destinations`: Target points as `[longitude, latitude]` in degrees
+    /// - `out`: Output buffer receiving the squared distance to each destination in square
+    ///   ruler units
+    ///
+    /// Only the first `min(destinations.len(), out.len())` squared distances are written.
+    pub fn distances_squared_to_many(
+        &self,
+        origin: &[T; 2],
+        destinations: &[[T; 2]],
+        out: &mut [T],
+    ) {
+        let [kx, ky] = self.coefs(origin);
+
+        for (destination, distance) in destinations.iter().zip(out.iter_mut()) {
+            let dx = self.delta_lon(&origin[0], &destination[0]) * kx;
+            let dy = (destination[1] - origin[1]) * ky;
+
+            *distance = dx.powi(2) + dy.powi(2);
+        }
+    }
+
+    /// Calculates the distance between two points using a fixed reference latitude.
+    ///
+    /// Same as [`distance`](Self::distance), but the latitude-dependent scaling is computed
+    /// at `ref_lat` instead of at the origin. This keeps results consistent across a
+    /// region, e.g. a map tile, where all the points share a representative latitude.
+    ///
+    /// # Parameters
+    ///
+    /// - `ref_lat`: Reference latitude in degrees used for the scaling factors
+    /// - `origin`: First point as `[longitude, latitude]` in degrees
+    /// - `destination`: Second point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance between the points in the ruler units
+    pub fn distance_at_ref_lat(&self, ref_lat: &T, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.distance_with(&self.coefs_at(ref_lat), origin, destination)
+    }
+
+    /// Calculates the destination point using a fixed reference latitude.
+    ///
+    /// Same as [`destination`](Self::destination), but the latitude-dependent scaling is
+    /// computed at `ref_lat` instead of at the origin.
+    ///
+    /// # Parameters
+    ///
+    /// - `ref_lat`: Reference latitude in degrees used for the scaling factors
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `bearing`: Direction of travel in degrees (0° = North, 90° = East)
+    /// - `distance`: Distance to travel in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// Destination point as `[longitude, latitude]` in degrees
+    pub fn destination_at_ref_lat(
+        &self,
+        ref_lat: &T,
+        origin: &[T; 2],
+        bearing: &T,
+        distance: &T,
+    ) -> [T; 2] {
+        Self::destination_with(&self.coefs_at(ref_lat), origin, bearing, distance)
+    }
+
+    /// Projects a point onto a segment in the locally flat space around the point.
+    ///
+    /// This is an internal method shared by the point-to-segment and point-to-line
+    /// calculations, where `coefs` are the scaling factors at `point`. Longitude differences
+    /// follow the antimeridian mode, so segments crossing it are projected the short way.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with:
+    /// - `t`: The ratio along the segment of the closest point, clamped to `[0, 1]`
+    /// - `[x, y]`: The east and north offsets in the ruler units from `point` to the closest point
+    fn project(&self, coefs: &[T; 2], point: &[T; 2], start: &[T; 2], end: &[T; 2]) -> (T, [T; 2]) {
+        let [kx, ky] = *coefs;
+
+        let x = self.delta_lon(&point[0], &start[0]) * kx;
+        let y = (start[1] - point[1]) * ky;
+
+        let dx = self.delta_lon(&start[0], &end[0]) * kx;
+        let dy = (end[1] - start[1]) * ky;
+
+        let len = dx.powi(2) + dy.powi(2);
+
+        let t = if len > T::zero() {
+            (-(x * dx + y * dy) / len).max(T::zero()).min(T::one())
+        } else {
+            T::zero()
+        };
+
+        (t, [x + dx * t, y + dy * t])
+    }
+
+    /// Calculates the shortest distance from a point to a line segment.
+    ///
+    /// The point is projected onto the segment using the latitude-dependent scaling
+    /// computed at the point, with the projection clamped to the segment endpoints.
+    ///
+    /// # Parameters
+    ///
+    /// - `point`: The query point as `[longitude, latitude]` in degrees
+    /// - `start`: First endpoint of the segment as `[longitude, latitude]` in degrees
+    /// - `end`: Second endpoint of the segment as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance from the point to the closest point of the segment in the ruler units
+    pub fn cross_track_distance(&self, point: &[T; 2], start: &[T; 2], end: &[T; 2]) -> T {
+        let (_, [x, y]) = self.project(&self.coefs(point), point, start, end);
+
+        (x.powi(2) + y.powi(2)).sqrt()
+    }
+
+    /// Calculates the shortest distance from a point to a polyline.
+    ///
+    /// Computes the scaling factors once at the query point and takes the minimum
+    /// [`cross_track_distance`](Self::cross_track_distance) across all segments.
+    ///
+    /// # Parameters
+    ///
+    /// - `point`: The query point as `[longitude, latitude]` in degrees
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance from the point to the closest point of the polyline in the ruler units. A
+    /// single-vertex line yields the distance to that vertex, and an empty line
+    /// yields infinity.
+    pub fn distance_to_line(&self, point: &[T; 2], line: &[[T; 2]]) -> T {
+        if let [vertex] = line {
+            return self.distance(point, vertex);
+        }
+
+        let coefs = self.coefs(point);
+
+        line.windows(2)
+            .map(|segment| {
+                let (_, [x, y]) = self.project(&coefs, point, &segment[0], &segment[1]);
+                (x.powi(2) + y.powi(2)).sqrt()
+            })
+            .fold(T::infinity(), T::min)
+    }
+
+    /// Finds the closest point of a polyline to a query point.
+    ///
+    /// Same projection as [`distance_to_line`](Self::distance_to_line), also reporting
+    /// where on the polyline the closest point is, e.g. to snap GPS fixes to a road. If
+    /// several segments are equally close, the first one is reported.
+    ///
+    /// # Parameters
+    ///
+    /// - `point`: The query point as `[longitude, latitude]` in degrees
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The closest point as `[longitude, latitude]` in degrees, its distance to the query
+    /// point in the ruler units and the index of the segment it lies on, starting at 0 for
+    /// the segment between the first two vertices, or `None` if the line is empty
+    pub fn point_on_line(&self, point: &[T; 2], line: &[[T; 2]]) -> Option<([T; 2], T, usize)> {
+        if let [vertex] = line {
+            return Some((*vertex, self.distance(point, vertex), 0));
+        }
+
+        let coefs = self.coefs(point);
+
+        line.windows(2)
+            .enumerate()
+            .map(|(index, segment)| {
+                let [start, end] = [segment[0], segment[1]];
+                let (t, [x, y]) = self.project(&coefs, point, &start, &end);
+
+                (
+                    self.interpolate(&start, &end, &t),
+                    (x.powi(2) + y.powi(2)).sqrt(),
+                    index,
+                )
+            })
+            .fold(None, |nearest, candidate| match nearest {
+                Some((_, distance, _)) if distance <= candidate.1 => nearest,
+                _ => Some(candidate),
+            })
+    }
+
+    /// Calculates the minimum distance between two polylines.
+    ///
+    /// Zero if the polylines cross or touch, otherwise the closest gap between two of
+    /// their segments, which always involves a vertex of one of them projected onto the
+    /// other, so it is the minimum [`distance_to_line`](Self::distance_to_line) of the
+    /// vertices of each polyline to the other one.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: The first polyline vertices as `[longitude, latitude]` in degrees
+    /// - `b`: The second polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The minimum distance between the polylines in the ruler units, or infinity if any
+    /// of them is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let broadway = [[-73.9897, 40.7411], [-73.9855, 40.7580]];
+    /// let west_34th = [[-73.9970, 40.7530], [-73.9800, 40.7470]];
+    ///
+    /// // Broadway crosses 34th Street at Herald Square
+    /// assert_eq!(ruler.distance_between_lines(&broadway, &west_34th), 0.);
+    /// ```
+    ///
+    /// See also: [`distance_to_line`](Self::distance_to_line)
+    pub fn distance_between_lines(&self, a: &[[T; 2]], b: &[[T; 2]]) -> T {
+        let crossing = a.windows(2).any(|s| {
+            b.windows(2)
+                .any(|t| self.segments_cross(&s[0], &s[1], &t[0], &t[1]))
+        });
+
+        if crossing {
+            return T::zero();
+        }
+
+        let gap = |from: &[[T; 2]], to: &[[T; 2]]| {
+            from.iter()
+                .map(|vertex| self.distance_to_line(vertex, to))
+                .fold(T::infinity(), T::min)
+        };
+
+        gap(a, b).min(gap(b, a))
+    }
+
+    /// Tests whether two segments cross or touch.
+    ///
+    /// The orientation tests are invariant to the positive scaling of each axis, so they
+    /// are computed on the degree offsets directly. Collinear segments are reported as
+    /// not crossing, their overlap is measured from their endpoints instead.
+    fn segments_cross(&self, a: &[T; 2], b: &[T; 2], c: &[T; 2], d: &[T; 2]) -> bool {
+        let orientation = |p: &[T; 2], q: &[T; 2], r: &[T; 2]| {
+            self.delta_lon(&p[0], &q[0]) * (r[1] - p[1])
+                - (q[1] - p[1]) * self.delta_lon(&p[0], &r[0])
+        };
+
+        let [o1, o2] = [orientation(a, b, c), orientation(a, b, d)];
+        let [o3, o4] = [orientation(c, d, a), orientation(c, d, b)];
+
+        let zero = T::zero();
+
+        if o1 == zero && o2 == zero && o3 == zero && o4 == zero {
+            return false;
+        }
+
+        o1 * o2 <= zero && o3 * o4 <= zero
+    }
+
+    /// Calculates the total length of a polyline.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Sum of the distances between consecutive vertices in the ruler units, zero for
+    /// lines with less than two vertices
+    pub fn length(&self, line: &[[T; 2]]) -> T {
+        line.windows(2)
+            .map(|segment| self.distance(&segment[0], &segment[1]))
+            .fold(T::zero(), |total, distance| total + distance)
+    }
+
+    /// Calculates the total length of a polyline with compensated summation.
+    ///
+    /// Same as [`length`](Self::length), but accumulates the distances with the Neumaier
+    /// variant of Kahan summation, which keeps the rounding error of the running total
+    /// from growing with the number of segments, e.g. for long `f32` polylines made of
+    /// thousands of short segments.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Sum of the distances between consecutive vertices in the ruler units, zero for
+    /// lines with less than two vertices
+    ///
+    /// See also: [`length`](Self::length)
+    pub fn length_kahan(&self, line: &[[T; 2]]) -> T {
+        let (total, compensation) = line
+            .windows(2)
+            .map(|segment| self.distance(&segment[0], &segment[1]))
+            .fold((T::zero(), T::zero()), |(total, compensation), distance| {
+                let sum = total + distance;
+
+                let lost = if total.abs() >= distance.abs() {
+                    (total - sum) + distance
+                } else {
+                    (distance - sum) + total
+                };
+
+                (sum, compensation + lost)
+            });
+
+        total + compensation
+    }
+
+    /// Calculates the total length of a polyline, validating its vertices first.
+    ///
+    /// See [`length`](Self::length), this variant reports the first invalid vertex
+    /// instead of returning NaN or a meaningless length.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Sum of the distances between consecutive vertices in the ruler units
+    ///
+    /// # Errors
+    ///
+    /// - [`RulerError::InvalidCoordinate`] with the index of the first vertex that is not
+    ///   finite or whose latitude is beyond a pole
+    pub fn try_length(&self, line: &[[T; 2]]) -> Result<T, RulerError> {
+        let right = T::FRAC_PI_2().to_degrees();
+
+        if let Some(index) = line
+            .iter()
+            .position(|[lon, lat]| !lon.is_finite() || !lat.is_finite() || lat.abs() > right)
+        {
+            return Err(RulerError::InvalidCoordinate { index });
+        }
+
+        Ok(self.length(line))
+    }
+
+    /// Finds the point at a given distance along a polyline.
+    ///
+    /// Walks the segments until the distance is reached and interpolates linearly
+    /// within the segment where it falls, the short way across the antimeridian.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `distance`: Distance from the first vertex in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// The point as `[longitude, latitude]` in degrees, clamped to the first vertex for
+    /// non-positive distances and to the last vertex for distances beyond the length
+    /// of the line, or `None` if the line is empty
+    pub fn along(&self, line: &[[T; 2]], distance: &T) -> Option<[T; 2]> {
+        let (first, last) = (line.first()?, line.last()?);
+
+        if *distance <= T::zero() {
+            return Some(*first);
+        }
+
+        let mut walked = T::zero();
+
+        for segment in line.windows(2) {
+            let [start, end] = [segment[0], segment[1]];
+            let step = self.distance(&start, &end);
+
+            walked = walked + step;
+
+            if walked > *distance {
+                let ratio = (*distance - (walked - step)) / step;
+
+                return Some(self.interpolate(&start, &end, &ratio));
+            }
+        }
+
+        Some(*last)
+    }
+
+    /// Finds the points at many distances along a polyline, in a single pass.
+    ///
+    /// Same as calling [`along`](Self::along) for each distance, e.g. to place mile
+    /// markers or labels, but the polyline is walked only once for all of them, which
+    /// requires the distances to be sorted in ascending order.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `distances`: Distances from the first vertex in the ruler units, in ascending order
+    /// - `out`: Output buffer receiving the point at each distance as `[longitude, latitude]`
+    ///   in degrees
+    ///
+    /// Only the first `min(distances.len(), out.len())` points are written, and nothing
+    /// if the line is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::{CheapRuler, Units};
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84().with_units(Units::Miles);
+    ///
+    /// let route = [
+    ///     [-74.0134, 40.7033], // Battery Park
+    ///     [-73.9897, 40.7411], // Flatiron Building
+    ///     [-73.9819, 40.7681], // Columbus Circle
+    /// ];
+    ///
+    /// // a marker every mile
+    /// let mut markers = [[0.; 2]; 3];
+    /// ruler.sample_at_distances(&route, &[1., 2., 3.], &mut markers);
+    ///
+    /// assert_eq!(markers[1], ruler.along(&route, &2.).unwrap());
+    /// ```
+    ///
+    /// See also: [`along`](Self::along)
+    pub fn sample_at_distances(&self, line: &[[T; 2]], distances: &[T], out: &mut [[T; 2]]) {
+        debug_assert!(
+            distances.windows(2).all(|pair| pair[0] <= pair[1]),
+            "distances are not sorted in ascending order"
+        );
+
+        let (Some(first), Some(last)) = (line.first(), line.last()) else {
+            return;
+        };
+
+        let mut segments = line.windows(2).map(|segment| {
+            let [start, end] = [segment[0], segment[1]];
+            (start, end, self.distance(&start, &end))
+        });
+
+        let mut current = segments.next();
+
+        // distance from the first vertex to the end of the current segment
+        let mut walked = current.map_or(T::zero(), |(_, _, step)| step);
+
+        for (distance, point) in distances.iter().zip(out.iter_mut()) {
+            if *distance <= T::zero() {
+                *point = *first;
+                continue;
+            }
+
+            while current.is_some() && walked <= *distance {
+                current = segments.next();
+
+                if let Some((_, _, step)) = current {
+                    walked = walked + step;
+                }
+            }
+
+            *point = match current {
+                Some((start, end, step)) => {
+                    let ratio = (*distance - (walked - step)) / step;
+
+                    self.interpolate(&start, &end, &ratio)
+                }
+                None => *last,
+            };
+        }
+    }
+
+    /// Computes the bounding box of a polyline.
+    ///
+    /// In [`AntimeridianMode::Wrap`], consecutive vertices are joined the short way
+    /// around, so a polyline crossing the antimeridian gets a tight box that wraps
+    /// across it, with a minimum longitude greater than its maximum one, instead of a
+    /// box spanning the whole globe. Polylines going all the way around get a box
+    /// spanning all longitudes.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees,
+    /// or `None` if the polyline is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// // from Fiji to Samoa, across the antimeridian
+    /// let route = [[178.44, -18.14], [-171.76, -13.83]];
+    ///
+    /// let bbox = ruler.line_bbox(&route).unwrap();
+    /// assert_eq!(bbox, [178.44, -18.14, -171.76, -13.83]);
+    /// ```
+    ///
+    /// See also: [`bbox_intersects`](Self::bbox_intersects)
+    pub fn line_bbox(&self, line: &[[T; 2]]) -> Option<[T; 4]> {
+        let (first, rest) = line.split_first()?;
+
+        // longitudes unwrapped along the polyline so the box is continuous, along with the
+        // original longitudes of the westernmost and easternmost vertices
+        let mut lon = first[0];
+        let [mut west, mut east] = [(lon, first[0]); 2];
+        let [mut min_y, mut max_y] = [first[1]; 2];
+
+        for p in rest {
+            lon = lon + self.delta_lon(&lon, &p[0]);
+
+            if lon < west.0 {
+                west = (lon, p[0]);
+            }
+            if lon > east.0 {
+                east = (lon, p[0]);
+            }
+
+            min_y = min_y.min(p[1]);
+            max_y = max_y.max(p[1]);
+        }
+
+        let straight = T::PI().to_degrees();
+
+        if east.0 - west.0 >= straight + straight {
+            return Some([-straight, min_y, straight, max_y]);
+        }
+
+        Some([west.1, min_y, east.1, max_y])
+    }
+
+    /// Expands a bounding box by a distance in every direction.
+    ///
+    /// The expansion is conservative: the scaling factors are taken at the latitudes
+    /// where a degree is shortest, so every point within `buffer` of the original box,
+    /// as measured by [`distance`](Self::distance), lies inside the expanded one. If the
+    /// expanded box reaches a pole, it spans all longitudes.
+    ///
+    /// # Parameters
+    ///
+    /// - `bbox`: The box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees
+    /// - `buffer`: Distance to expand by in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// The expanded box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees
+    ///
+    /// See also: [`bbox_intersects`](Self::bbox_intersects)
+    pub fn buffer_bbox(&self, bbox: &[T; 4], buffer: &T) -> [T; 4] {
+        let [min_x, min_y, max_x, max_y] = *bbox;
+
+        let right = T::FRAC_PI_2().to_degrees();
+        let straight = T::PI().to_degrees();
+
+        // the meridian scaling is monotonic in latitude, its minimum is at the equator or a pole
+        let ky = self.coefs_at(&T::zero())[1].min(self.coefs_at(&right)[1]);
+        let dy = *buffer / ky;
+
+        let (min_y, max_y) = (min_y - dy, max_y + dy);
+
+        let farthest = min_y.abs().max(max_y.abs());
+
+        if farthest >= right {
+            return [min_x.min(-straight), min_y, max_x.max(straight), max_y];
+        }
+
+        let [kx, _] = self.coefs_at(&farthest);
+        let dx = *buffer / kx;
+
+        [min_x - dx, min_y, max_x + dx, max_y]
+    }
+
+    /// Checks whether two bounding boxes overlap, including touching edges.
+    ///
+    /// Boxes are compared in degree space. A box with a minimum longitude greater than
+    /// its maximum one wraps across the antimeridian, as returned by
+    /// [`line_bbox`](Self::line_bbox).
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: First box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees
+    /// - `b`: Second box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// `true` if the boxes share at least one point
+    ///
+    /// See also: [`buffer_bbox`](Self::buffer_bbox)
+    pub fn bbox_intersects(&self, a: &[T; 4], b: &[T; 4]) -> bool {
+        let straight = T::PI().to_degrees();
+
+        // longitude ranges, split in two at the antimeridian for wrapping boxes
+        let ranges = |bbox: &[T; 4]| {
+            if bbox[0] > bbox[2] {
+                [[bbox[0], straight], [-straight, bbox[2]]]
+            } else {
+                [[bbox[0], bbox[2]]; 2]
+            }
+        };
+
+        let overlap = ranges(a)
+            .iter()
+            .any(|a| ranges(b).iter().any(|b| a[0] <= b[1] && b[0] <= a[1]));
+
+        overlap && a[1] <= b[3] && b[1] <= a[3]
+    }
+
+    /// Checks whether a point lies inside a polygon ring, including its boundary.
+    ///
+    /// The ring is treated as planar around the point, with longitudes wrapped according
+    /// to the [`AntimeridianMode`], so rings crossing the antimeridian are supported as long
+    /// as they span less than 180° of longitude.
+    ///
+    /// # Parameters
+    ///
+    /// - `point`: The point as `[longitude, latitude]` in degrees
+    /// - `ring`: The ring vertices as `[longitude, latitude]` in degrees, closed or not
+    ///
+    /// # Returns
+    ///
+    /// `true` if the point is inside the ring or on one of its edges
+    pub fn inside_ring(&self, point: &[T; 2], ring: &[[T; 2]]) -> bool {
+        self.locate_in_ring(point, ring.iter().copied())
+            .unwrap_or(true)
+    }
+
+    /// Calculates the area of a polygon.
+    ///
+    /// Uses the shoelace formula in the planar approximation, with the latitude-dependent
+    /// scaling computed at the middle latitude of the exterior ring and longitudes wrapped
+    /// according to the [`AntimeridianMode`]. The rings may be wound either way.
+    ///
+    /// # Parameters
+    ///
+    /// - `polygon`: The rings as lists of `[longitude, latitude]` vertices in degrees,
+    ///   closed or not, the exterior ring first and then its holes
+    ///
+    /// # Returns
+    ///
+    /// The area of the exterior ring minus the area of the holes, in square ruler units,
+    /// or zero if there are no rings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// // Bryant Park, New York
+    /// let park = [
+    ///     [-73.9853, 40.7545],
+    ///     [-73.9818, 40.7531],
+    ///     [-73.9829, 40.7516],
+    ///     [-73.9865, 40.7530],
+    /// ];
+    ///
+    /// let area = ruler.area(&[&park]); // square meters
+    /// ```
+    pub fn area(&self, polygon: &[&[[T; 2]]]) -> T {
+        let Some((exterior, holes)) = polygon.split_first() else {
+            return T::zero();
+        };
+
+        let Some(origin) = exterior.first() else {
+            return T::zero();
+        };
+
+        let (south, north) = exterior
+            .iter()
+            .fold((origin[1], origin[1]), |(south, north), p| {
+                (south.min(p[1]), north.max(p[1]))
+            });
+
+        let coefs = self.coefs_at(&((south + north) / (T::one() + T::one())));
+
+        holes.iter().fold(
+            self.ring_area(&coefs, origin, exterior).abs(),
+            |area, hole| area - self.ring_area(&coefs, origin, hole).abs(),
+        )
+    }
+
+    /// Calculates the signed area of a ring.
+    ///
+    /// Same approximation as [`area`](Self::area), but keeps the sign given by the winding
+    /// order of the ring.
+    ///
+    /// # Parameters
+    ///
+    /// - `ring`: The ring as a list of `[longitude, latitude]` vertices in degrees, closed
+    ///   or not
+    ///
+    /// # Returns
+    ///
+    /// The area of the ring in square ruler units, positive if it is wound
+    /// counter-clockwise and negative if clockwise, or zero if the ring is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// // Bryant Park, New York, wound clockwise
+    /// let park = [
+    ///     [-73.9853, 40.7545],
+    ///     [-73.9818, 40.7531],
+    ///     [-73.9829, 40.7516],
+    ///     [-73.9865, 40.7530],
+    /// ];
+    ///
+    /// assert!(ruler.oriented_area(&park) < 0.);
+    /// assert_eq!(-ruler.oriented_area(&park), ruler.area(&[&park]));
+    /// ```
+    ///
+    /// See also: [`area`](Self::area), [`is_ccw`](Self::is_ccw)
+    pub fn oriented_area(&self, ring: &[[T; 2]]) -> T {
+        let Some(origin) = ring.first() else {
+            return T::zero();
+        };
+
+        let (south, north) = ring
+            .iter()
+            .fold((origin[1], origin[1]), |(south, north), p| {
+                (south.min(p[1]), north.max(p[1]))
+            });
+
+        let coefs = self.coefs_at(&((south + north) / (T::one() + T::one())));
+
+        self.ring_area(&coefs, origin, ring)
+    }
+
+    /// Checks whether a ring is wound counter-clockwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `ring`: The ring as a list of `[longitude, latitude]` vertices in degrees, closed
+    ///   or not
+    ///
+    /// # Returns
+    ///
+    /// `true` if the [`oriented_area`](Self::oriented_area) of the ring is positive
+    pub fn is_ccw(&self, ring: &[[T; 2]]) -> bool {
+        self.oriented_area(ring) > T::zero()
+    }
+
+    /// Calculates the signed area of a ring, positive when counter-clockwise, with the
+    /// vertices projected around an origin.
+    fn ring_area(&self, coefs: &[T; 2], origin: &[T; 2], ring: &[[T; 2]]) -> T {
+        let [kx, ky] = *coefs;
+
+        let project = |p: &[T; 2]| {
+            [
+                self.delta_lon(&origin[0], &p[0]) * kx,
+                (p[1] - origin[1]) * ky,
+            ]
+        };
+
+        let twice = ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            .fold(T::zero(), |sum, (a, b)| {
+                let ([ax, ay], [bx, by]) = (project(a), project(b));
+                sum + ax * by - bx * ay
+            });
+
+        twice / (T::one() + T::one())
+    }
+
+    /// Locates a point relative to a polygon ring.
+    ///
+    /// Returns `None` if the point lies on an edge, otherwise whether it is inside,
+    /// following the even-odd rule. The ring vertices are taken from an iterator, so
+    /// rings stored in other coordinate types are not copied into a slice first.
+    pub(crate) fn locate_in_ring(
+        &self,
+        point: &[T; 2],
+        ring: impl IntoIterator<Item = [T; 2]>,
+    ) -> Option<bool> {
+        let relative =
+            |vertex: [T; 2]| [self.delta_lon(&point[0], &vertex[0]), vertex[1] - point[1]];
+
+        let mut vertices = ring.into_iter().map(relative);
+
+        let Some(first) = vertices.next() else {
+            return Some(false);
+        };
+
+        let mut inside = false;
+        let [mut ax, mut ay] = first;
+
+        for [bx, by] in vertices.chain(core::iter::once(first)) {
+            let cross = ax * by - ay * bx;
+
+            if cross == T::zero() && ax * bx + ay * by <= T::zero() {
+                return None;
+            }
+
+            // the edge crosses the horizontal ray going east from the point
+            if (ay > T::zero()) != (by > T::zero()) && (cross > T::zero()) == (by > ay) {
+                inside = !inside;
+            }
+
+            [ax, ay] = [bx, by];
+        }
+
+        Some(inside)
+    }
+}
+
+/// Trait for types that may implement `From<f32>` depending on feature flags.
+///
+/// This trait is used to conditionally require `From<f32>` when using alternative
+/// `atan2` implementations that need to work with f32 constants.
+#[cfg(any(feature = "atan2_deg5", feature = "atan2_deg3"))]
+pub trait MaybeFromf32: From<f32> {}
+
+/// Trait for types that may implement `From<f32>` depending on feature flags.
+#[cfg(not(any(feature = "atan2_deg5", feature = "atan2_deg3")))]
+pub trait MaybeFromf32 {}
+
+#[cfg(any(feature = "atan2_deg5", feature = "atan2_deg3"))]
+impl<T: From<f32>> MaybeFromf32 for T {}
+#[cfg(not(any(feature = "atan2_deg5", feature = "atan2_deg3")))]
+impl<T> MaybeFromf32 for T {}
+
+impl<T: Float + FloatConst + Debug + MaybeFromf32> CheapRuler<T> {
+    /// Calculates the bearing from one point to another.
+    ///
+    /// Uses the flat Earth approximation with latitude-dependent scaling to compute
+    /// the initial bearing (forward azimuth) from the origin to the destination.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `destination`: Target point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Initial bearing in degrees (0° = North, 90° = East)
+    pub fn bearing(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.bearing_with(&self.coefs(origin), origin, destination)
+    }
+
+    /// Calculates the bearing from one point to another given as separate coordinates.
+    ///
+    /// Same as [`bearing`](Self::bearing), for coordinates stored in separate
+    /// longitude and latitude columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `lon1`, `lat1`: Starting point longitude and latitude in degrees
+    /// - `lon2`, `lat2`: Target point longitude and latitude in degrees
+    ///
+    /// # Returns
+    ///
+    /// Initial bearing in degrees (0° = North, 90° = East)
+    pub fn bearing_ll(&self, lon1: &T, lat1: &T, lon2: &T, lat2: &T) -> T {
+        self.bearing(&[*lon1, *lat1], &[*lon2, *lat2])
+    }
+
+    /// Calculates the bearing from one point to another using a fixed reference latitude.
+    ///
+    /// Same as [`bearing`](Self::bearing), but the latitude-dependent scaling is computed
+    /// at `ref_lat` instead of at the origin.
+    ///
+    /// # Parameters
+    ///
+    /// - `ref_lat`: Reference latitude in degrees used for the scaling factors
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `destination`: Target point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Initial bearing in degrees (0° = North, 90° = East)
+    pub fn bearing_at_ref_lat(&self, ref_lat: &T, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.bearing_with(&self.coefs_at(ref_lat), origin, destination)
+    }
+
+    /// Calculates the bearings from one origin to many targets.
+    ///
+    /// The latitude-dependent scaling is computed once at the origin and reused for
+    /// every target, avoiding the trigonometric setup of repeated
+    /// [`bearing`](Self::bearing) calls.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Common starting point as `[longitude, latitude]` in degrees
+    /// - `targets`: Target points as `[longitude, latitude]` in degrees
+    /// - `out`: Output buffer receiving the initial bearing to each target in degrees
+    ///
+    /// Only the first `min(targets.len(), out.len())` bearings are written.
+    ///
+    /// See also: [`distance_to_many`](Self::distance_to_many)
+    pub fn bearing_to_many(&self, origin: &[T; 2], targets: &[[T; 2]], out: &mut [T]) {
+        let coefs = self.coefs(origin);
+
+        for (target, bearing) in targets.iter().zip(out.iter_mut()) {
+            *bearing = self.bearing_with(&coefs, origin, target);
+        }
+    }
+
+    /// Calculates the bearing between two points using precomputed scaling factors.
+    fn bearing_with(&self, coefs: &[T; 2], origin: &[T; 2], destination: &[T; 2]) -> T {
+        let [kx, ky] = *coefs;
+
+        let dx = self.delta_lon(&origin[0], &destination[0]) * kx;
+        let dy = (destination[1] - origin[1]) * ky;
+
+        self.heading(dx, dy)
+    }
+
+    /// Finds the heading at a fraction of the length of a polyline.
+    ///
+    /// Returns the bearing of the segment that contains the point at the given fraction
+    /// of the total length, e.g. to orient a marker moving along a route. Zero-length
+    /// segments are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `fraction`: Fraction of the length from the first vertex, clamped to [0, 1]
+    ///
+    /// # Returns
+    ///
+    /// The bearing of the segment in degrees (0° = North, 90° = East), or `None` if the
+    /// line has no segment of positive length
+    ///
+    /// See also: [`along`](Self::along)
+    pub fn bearing_at_fraction(&self, line: &[[T; 2]], fraction: &T) -> Option<T> {
+        let target = self.length(line) * fraction.max(T::zero()).min(T::one());
+
+        let mut walked = T::zero();
+        let mut current = None;
+
+        for segment in line.windows(2) {
+            let step = self.distance(&segment[0], &segment[1]);
+
+            if step <= T::zero() {
+                continue;
+            }
+
+            current = Some(segment);
+            walked = walked + step;
+
+            if walked >= target {
+                break;
+            }
+        }
+
+        current.map(|segment| self.bearing(&segment[0], &segment[1]))
+    }
+
+    /// Calculates both the bearing and the distance from one point to another.
+    ///
+    /// The latitude-dependent scaling and the coordinate deltas are shared by both
+    /// results, which is cheaper than calling [`bearing`](Self::bearing) and
+    /// [`distance`](Self::distance) separately.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `destination`: Target point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the initial bearing in degrees (0° = North, 90° = East) and the
+    /// distance between the points in the ruler units
+    pub fn bearing_and_distance(&self, origin: &[T; 2], destination: &[T; 2]) -> (T, T) {
+        let [kx, ky] = self.coefs(origin);
+
+        let dx = self.delta_lon(&origin[0], &destination[0]) * kx;
+        let dy = (destination[1] - origin[1]) * ky;
+
+        (self.heading(dx, dy), (dx.powi(2) + dy.powi(2)).sqrt())
+    }
+
+    /// Calculates the signed turn at a vertex between two consecutive segments.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: Start of the incoming segment as `[longitude, latitude]` in degrees
+    /// - `b`: The vertex as `[longitude, latitude]` in degrees
+    /// - `c`: End of the outgoing segment as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The change of bearing in degrees within (-180°, 180°], positive for right
+    /// (clockwise) turns and negative for left ones
+    ///
+    /// See also: [`total_turning`](Self::total_turning)
+    pub fn turn_angle(&self, a: &[T; 2], b: &[T; 2], c: &[T; 2]) -> T {
+        turn(&self.bearing(a, b), &self.bearing(b, c))
+    }
+
+    /// Sums the signed turns along a track.
+    ///
+    /// Adds up the [`turn_angle`](Self::turn_angle) at every vertex, so a track that
+    /// goes around a loop once turns about ±360° while a straight or zigzagging one
+    /// stays near 0°. Zero-length segments, e.g. repeated GPS fixes, have no heading and
+    /// are skipped. If the track is closed, its first and last points being equal, the
+    /// turn where it closes is included as well.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The track as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The total turning in degrees, positive for clockwise loops and negative for
+    /// counter-clockwise ones, or zero if there are less than two segments
+    pub fn total_turning(&self, points: &[[T; 2]]) -> T {
+        let mut bearings = points
+            .windows(2)
+            .filter(|segment| segment[0] != segment[1])
+            .map(|segment| self.bearing(&segment[0], &segment[1]));
+
+        let Some(first) = bearings.next() else {
+            return T::zero();
+        };
+
+        let (last, total) = bearings.fold((first, T::zero()), |(previous, total), bearing| {
+            (bearing, total + turn(&previous, &bearing))
+        });
+
+        if points.len() > 2 && points.first() == points.last() {
+            total + turn(&last, &first)
+        } else {
+            total
+        }
+    }
+
+    /// Converts east and north displacements into a bearing in degrees.
+    fn heading(&self, dx: T, dy: T) -> T {
+        if let Some(atan2) = self.atan2 {
+            return atan2(dx, dy).to_degrees();
+        }
+
+        #[cfg(not(any(feature = "atan2_deg3", feature = "atan2_deg5")))]
+        return dx.atan2(dy).to_degrees();
+
+        #[cfg(any(feature = "atan2_deg5", feature = "atan2_deg3"))]
+        return math::atan2(dx, dy).to_degrees();
+    }
+}
+
+/// Computes the signed change from one bearing to another in degrees within (-180°, 180°].
+fn turn<T: Float>(from: &T, to: &T) -> T {
+    let full = T::from(360).unwrap();
+    let half = T::from(180).unwrap();
+
+    let delta = (*to - *from) % full;
+
+    if delta > half {
+        delta - full
+    } else if delta <= -half {
+        delta + full
+    } else {
+        delta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::vec::Vec;
+
+    const RELATIVE_ERROR: f64 = 0.001;
+
+    /// Checks the core measurements against geodesic references, for any float type.
+    fn assert_reference_values<T: Float + FloatConst + Debug + From<f32> + MaybeFromf32>() {
+        let ruler = CheapRuler::<T>::WGS84().with_atan2(Atan2Mode::Std);
+        let at = |lon: f32, lat: f32| [lon.into(), lat.into()];
+        let f64 = |value: T| value.to_f64().unwrap();
+
+        let empire_state = at(-73.9857, 40.7484);
+        let flatiron = at(-73.9897, 40.7411);
+        let times_square = at(-73.9855, 40.7580);
+
+        assert_relative_eq!(
+            f64(ruler.distance(&empire_state, &flatiron)),
+            878.236,
+            max_relative = RELATIVE_ERROR
+        );
+
+        let bearing = f64(ruler.bearing(&empire_state, &flatiron)).rem_euclid(360.);
+        assert_relative_eq!(bearing, 202.625, epsilon = 0.05);
+
+        let destination = ruler.destination(&empire_state, &200.0.into(), &1_000.0.into());
+        let reference = [-73.989_749, 40.739_938];
+        assert!(CheapRuler::WGS84().distance(&destination.map(f64), &reference) < 0.5);
+
+        assert_relative_eq!(
+            f64(ruler.length(&[flatiron, empire_state, times_square])),
+            1_944.442,
+            max_relative = RELATIVE_ERROR
+        );
+
+        let block = [
+            at(-73.990, 40.750),
+            at(-73.980, 40.750),
+            at(-73.980, 40.755),
+            at(-73.990, 40.755),
+        ];
+        assert_relative_eq!(
+            f64(ruler.area(&[&block])),
+            468_900.047,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn reference_values() {
+        assert_reference_values::<f32>();
+        assert_reference_values::<f64>();
+    }
+
+    #[test]
+    fn try_destination() {
+        let ruler = CheapRuler::<f64>::WGS84();
+        let origin = [-73.9857, 40.7484];
+
+        assert_eq!(
+            ruler.try_destination(&origin, &45., &1_000.),
+            Ok(ruler.destination(&origin, &45., &1_000.))
+        );
+
+        assert_eq!(
+            ruler.try_destination(&origin, &f64::NAN, &1_000.),
+            Err(RulerError::InvalidBearing)
+        );
+        assert_eq!(
+            ruler.try_destination(&origin, &45., &-1.),
+            Err(RulerError::InvalidDistance)
+        );
+        assert_eq!(
+            ruler.try_destination(&origin, &45., &f64::INFINITY),
+            Err(RulerError::InvalidDistance)
+        );
+        assert_eq!(
+            ruler.try_destination(&[0., 90.], &45., &1_000.),
+            Err(RulerError::InvalidOrigin)
+        );
+        assert_eq!(
+            ruler.try_destination(&[0., f64::NAN], &45., &1_000.),
+            Err(RulerError::InvalidOrigin)
+        );
+    }
+
+    #[test]
+    fn units() {
+        let meters = CheapRuler::<f64>::WGS84();
+        let miles = CheapRuler::<f64>::WGS84().with_units(Units::Miles);
+
+        let empire_state = [-73.9857, 40.7484];
+        let flatiron = [-73.9897, 40.7411];
+
+        let distance = meters.distance(&empire_state, &flatiron);
+        assert_relative_eq!(
+            miles.distance(&empire_state, &flatiron),
+            distance / 1609.344,
+            max_relative = RELATIVE_ERROR
+        );
+
+        let destination = meters.destination(&empire_state, &30., &1609.344);
+        assert_relative_eq!(
+            miles.destination(&empire_state, &30., &1.)[..],
+            destination[..],
+            max_relative = 1e-12
+        );
+
+        assert_relative_eq!(
+            miles.offset(&empire_state, &1., &-1.)[..],
+            meters.offset(&empire_state, &1609.344, &-1609.344)[..],
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn inside_ring() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let square = [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]];
+
+        assert!(ruler.inside_ring(&[0., 0.], &square));
+        assert!(ruler.inside_ring(&[1., 0.], &square));
+        assert!(ruler.inside_ring(&[1., 1.], &square));
+        assert!(!ruler.inside_ring(&[2., 0.], &square));
+        assert!(!ruler.inside_ring(&[0., 1.5], &square));
+
+        let across = [[179., -1.], [-179., -1.], [-179., 1.], [179., 1.]];
+
+        assert!(ruler.inside_ring(&[180., 0.], &across));
+        assert!(ruler.inside_ring(&[-179.5, 0.], &across));
+        assert!(!ruler.inside_ring(&[177., 0.], &across));
+
+        let raw = ruler.with_antimeridian(AntimeridianMode::Raw);
+        assert!(!raw.inside_ring(&[180., 0.], &across));
+    }
+
+    #[test]
+    fn point_on_line() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // up 5th Avenue and then across 42nd Street
+        let line = [
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9817, 40.7540], // 5th Ave & 42nd St
+            [-73.9772, 40.7527], // Grand Central
+        ];
+
+        let bryant_park = [-73.9840, 40.7536];
+        let (closest, distance, index) = ruler.point_on_line(&bryant_park, &line).unwrap();
+
+        assert_eq!(index, 1);
+        assert_eq!(distance, ruler.distance_to_line(&bryant_park, &line));
+        assert_relative_eq!(
+            ruler.distance(&bryant_park, &closest),
+            distance,
+            max_relative = 1e-9
+        );
+
+        // past the end of the line the closest point is its last vertex
+        let (closest, _, index) = ruler.point_on_line(&[-73.9700, 40.7500], &line).unwrap();
+        assert_eq!((closest, index), (line[3], 2));
+
+        assert_eq!(
+            ruler.point_on_line(&bryant_park, &line[..1]),
+            Some((line[0], ruler.distance(&bryant_park, &line[0]), 0))
+        );
+        assert_eq!(ruler.point_on_line(&bryant_park, &[]), None);
+    }
+
+    #[test]
+    fn point_on_line_across_antimeridian() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let line = [[179.9, 10.], [-179.9, 10.]];
+        let point = [179.95, 10.01];
+
+        let (closest, distance, index) = ruler.point_on_line(&point, &line).unwrap();
+
+        assert_eq!(index, 0);
+        assert_relative_eq!(closest[0], 179.95, epsilon = 1e-9);
+        assert_relative_eq!(closest[1], 10., epsilon = 1e-9);
+        assert_relative_eq!(
+            distance,
+            ruler.distance(&point, &[179.95, 10.]),
+            max_relative = 1e-9
+        );
+        assert_eq!(distance, ruler.distance_to_line(&point, &line));
+    }
+
+    #[test]
+    fn azimuthal_equidistant() {
+        let ruler = CheapRuler::<f64>::WGS84().with_bearing_tolerance(&0.);
+        let radar = [-73.8740, 40.7769]; // LaGuardia Airport
+
+        let contacts = [
+            [-73.9857, 40.7484],
+            [-73.7781, 40.6413],
+            [-73.6000, 41.0000],
+            [-74.1745, 40.6895],
+        ];
+
+        for contact in contacts {
+            let [east, north] = ruler.to_azimuthal_equidistant(&radar, &contact);
+
+            assert_relative_eq!(
+                (east.powi(2) + north.powi(2)).sqrt(),
+                ruler.distance(&radar, &contact),
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                east.atan2(north).to_degrees(),
+                ruler.bearing(&radar, &contact),
+                epsilon = 1e-9
+            );
+
+            let back = ruler.from_azimuthal_equidistant(&radar, &[east, north]);
+            assert_relative_eq!(back[0], contact[0], epsilon = 1e-9);
+            assert_relative_eq!(back[1], contact[1], epsilon = 1e-9);
+        }
+
+        // across the antimeridian
+        let fiji = [179.9, -17.];
+        let projected = ruler.to_azimuthal_equidistant(&fiji, &[-179.9, -17.]);
+
+        assert!(projected[0] > 0.);
+        assert_relative_eq!(
+            ruler.from_azimuthal_equidistant(&fiji, &projected)[0],
+            -179.9,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn line_bbox() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // from Fiji to Samoa, across the antimeridian
+        let route = [
+            [178.44, -18.14],
+            [179.9, -16.],
+            [-179.9, -15.],
+            [-171.76, -13.83],
+        ];
+
+        let bbox = ruler.line_bbox(&route).unwrap();
+        assert_eq!(bbox, [178.44, -18.14, -171.76, -13.83]);
+
+        // about 10 degrees wide, not the whole globe
+        assert!(bbox[0] > bbox[2]);
+        assert_relative_eq!(bbox[2] + 360. - bbox[0], 9.8, max_relative = 1e-9);
+
+        let raw = CheapRuler::<f64>::WGS84().with_antimeridian(AntimeridianMode::Raw);
+        assert_eq!(
+            raw.line_bbox(&route).unwrap(),
+            [-179.9, -18.14, 179.9, -13.83]
+        );
+
+        // wrapping boxes intersect on either side of the antimeridian only
+        let tonga = [-175.5, -21.5, -175., -21.];
+        let tuvalu = [179., -8.6, 179.3, -8.4];
+        let tahiti = [-149.6, -17.9, -149.1, -17.5];
+
+        assert!(ruler.bbox_intersects(&bbox, &[-176., -19., -173., -14.]));
+        assert!(ruler.bbox_intersects(&[178., -17., 179., -16.], &bbox));
+        assert!(!ruler.bbox_intersects(&bbox, &tonga));
+        assert!(!ruler.bbox_intersects(&bbox, &tuvalu));
+        assert!(!ruler.bbox_intersects(&bbox, &tahiti));
+        assert!(ruler.bbox_intersects(&bbox, &bbox));
+
+        // all the way around, and degenerate lines
+        let around = [[0., 60.], [120., 60.], [-120., 60.], [0., 60.]];
+        assert_eq!(ruler.line_bbox(&around), Some([-180., 60., 180., 60.]));
+
+        assert_eq!(ruler.line_bbox(&[]), None);
+        assert_eq!(
+            ruler.line_bbox(&route[..1]),
+            Some([178.44, -18.14, 178.44, -18.14])
+        );
+    }
+
+    #[test]
+    fn sample_at_distances() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let route = [
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9855, 40.7580], // Times Square
+            [-73.9819, 40.7681], // Columbus Circle
+        ];
+
+        let length = ruler.length(&route);
+        let distances = [
+            -10.,
+            0.,
+            100.,
+            100.,
+            877.,
+            1_500.,
+            2_000.,
+            length,
+            length + 1.,
+        ];
+
+        let mut out = [[0.; 2]; 9];
+        ruler.sample_at_distances(&route, &distances, &mut out);
+
+        for (distance, point) in distances.iter().zip(out) {
+            assert_eq!(Some(point), ruler.along(&route, distance));
+        }
+
+        // only as many points as both the distances and the buffer allow
+        let mut short = [[0.; 2]; 2];
+        ruler.sample_at_distances(&route, &distances[2..], &mut short);
+        assert_eq!(short, out[2..4]);
+
+        let mut untouched = [[1.; 2]; 2];
+        ruler.sample_at_distances(&[], &[0., 10.], &mut untouched);
+        assert_eq!(untouched, [[1.; 2]; 2]);
+
+        ruler.sample_at_distances(&route[..1], &[0., 10.], &mut untouched);
+        assert_eq!(untouched, [route[0]; 2]);
+
+        // across the antimeridian the points stay on the short way
+        let across = [[179.9, 0.], [-179.9, 0.]];
+        let mut points = [[0.; 2]; 2];
+        ruler.sample_at_distances(&across, &[5_000., 10_000.], &mut points);
+
+        for (distance, point) in [5_000., 10_000.].iter().zip(points) {
+            assert!(point[0].abs() > 179.9);
+            assert_relative_eq!(
+                ruler.distance(&across[0], &point),
+                distance,
+                max_relative = 1e-6
+            );
+            assert_eq!(Some(point), ruler.along(&across, distance));
+        }
+    }
+
+    #[test]
+    fn area() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // a 200 by 100 meters block, wound either way and closed or not
+        let center = [-73.9857, 40.7484];
+        let corner = |dx: f64, dy: f64| ruler.offset(&center, &dx, &dy);
+
+        let block = [
+            corner(-100., -50.),
+            corner(100., -50.),
+            corner(100., 50.),
+            corner(-100., 50.),
+        ];
+
+        assert_relative_eq!(ruler.area(&[&block]), 20_000., max_relative = 1e-6);
+
+        let mut closed = block.to_vec();
+        closed.push(block[0]);
+        closed.reverse();
+        assert_relative_eq!(ruler.area(&[&closed]), 20_000., max_relative = 1e-6);
+
+        // with a 20 by 20 meters courtyard
+        let courtyard = [
+            corner(-10., -10.),
+            corner(10., -10.),
+            corner(10., 10.),
+            corner(-10., 10.),
+        ];
+        assert_relative_eq!(
+            ruler.area(&[&block, &courtyard]),
+            19_600.,
+            max_relative = 1e-6
+        );
+
+        // across the antimeridian
+        let across = [[179.9, -0.1], [-179.9, -0.1], [-179.9, 0.1], [179.9, 0.1]];
+        let width = ruler.distance(&across[0], &across[1]);
+        let height = ruler.distance(&across[1], &across[2]);
+        assert_relative_eq!(ruler.area(&[&across]), width * height, max_relative = 1e-5);
+
+        assert_eq!(ruler.area(&[]), 0.);
+        assert_eq!(ruler.area(&[&[]]), 0.);
+
+        let acres = ruler.with_units(Units::Feet).area(&[&block]) / 43_560.;
+        assert_relative_eq!(acres, 4.942, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn from_config() {
+        let config = RulerConfig {
+            major: 3_396_200.,
+            minor: 3_376_200.,
+            units: Units::Kilometers,
+            atan2: Atan2Mode::Deg5,
+            antimeridian: AntimeridianMode::Raw,
+        };
+
+        let ruler = CheapRuler::<f64>::from_config(&config);
+
+        assert_eq!(ruler.units(), Units::Kilometers);
+        assert_eq!(ruler.antimeridian(), AntimeridianMode::Raw);
+
+        let mars = || CheapRuler::new(&3_396_200., &3_376_200.);
+        let (a, b) = ([10., 20.], [10.5, 20.3]);
+        assert_relative_eq!(ruler.distance(&a, &b) * 1000., mars().distance(&a, &b));
+
+        // the 5th degree approximation, whatever the enabled features
+        let bearing = ruler.bearing(&a, &b);
+        assert_eq!(bearing, mars().with_bearing_tolerance(&0.1).bearing(&a, &b));
+        assert_ne!(bearing, mars().with_atan2(Atan2Mode::Std).bearing(&a, &b));
+
+        // the long way around
+        let (west_of, east_of) = ([179.99, 0.], [-179.99, 0.]);
+        assert!(ruler.distance(&west_of, &east_of) > 10_000.);
+
+        // the default configuration matches WGS84
+        let default = CheapRuler::<f64>::from_config(&RulerConfig::default());
+        assert_relative_eq!(
+            default.distance(&a, &b),
+            CheapRuler::WGS84().distance(&a, &b),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn oriented_area() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let ccw = [[-0.01, -0.01], [0.01, -0.01], [0.01, 0.01], [-0.01, 0.01]];
+        let mut cw = ccw;
+        cw.reverse();
+
+        assert!(ruler.area(&[&ccw]) > 0.);
+        assert_relative_eq!(
+            ruler.area(&[&ccw]),
+            ruler.area(&[&cw]),
+            max_relative = 1e-12
+        );
+
+        assert_relative_eq!(ruler.oriented_area(&ccw), ruler.area(&[&ccw]));
+        assert_relative_eq!(ruler.oriented_area(&cw), -ruler.area(&[&cw]));
+
+        assert!(ruler.is_ccw(&ccw));
+        assert!(!ruler.is_ccw(&cw));
+
+        assert_eq!(ruler.oriented_area(&[]), 0.);
+        assert!(!ruler.is_ccw(&[]));
+    }
+
+    #[test]
+    fn distances_squared_to_many() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let depot = [-73.9857, 40.7484];
+        let stops = [
+            [-73.9897, 40.7411],
+            [-73.9855, 40.7580],
+            [-73.9680, 40.7489],
+            [-73.9934, 40.7505],
+        ];
+
+        let mut distances = [0.; 4];
+        let mut squared = [0.; 4];
+        ruler.distance_to_many(&depot, &stops, &mut distances);
+        ruler.distances_squared_to_many(&depot, &stops, &mut squared);
+
+        for (distance, squared) in distances.iter().zip(squared) {
+            assert_relative_eq!(distance.powi(2), squared, max_relative = 1e-12);
+        }
+
+        // a radius filter without square roots keeps the same stops
+        let within = |distances: &[f64], radius: f64| -> std::vec::Vec<usize> {
+            (0..4).filter(|&i| distances[i] <= radius).collect()
+        };
+        assert_eq!(
+            within(&squared, 1_000f64.powi(2)),
+            within(&distances, 1_000.)
+        );
+        assert_eq!(within(&squared, 1_100f64.powi(2)), [0, 1, 3]);
+    }
+
+    #[test]
+    fn bearing_to_many() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let radar = [-73.9857, 40.7484];
+        let targets = [
+            [-73.9897, 40.7411],
+            [-73.9855, 40.7580],
+            [-73.9680, 40.7489],
+            [-73.9934, 40.7505],
+        ];
+
+        let mut bearings = [0.; 4];
+        ruler.bearing_to_many(&radar, &targets, &mut bearings);
+
+        for (target, bearing) in targets.iter().zip(bearings) {
+            assert_eq!(bearing, ruler.bearing(&radar, target));
+        }
+
+        // shorter output buffers only receive the first bearings
+        let mut bearings = [0.; 2];
+        ruler.bearing_to_many(&radar, &targets, &mut bearings);
+        assert_eq!(bearings[1], ruler.bearing(&radar, &targets[1]));
+    }
+
+    #[test]
+    fn meters_per_pixel() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // the usual Web Mercator resolutions at the equator
+        for (zoom, resolution) in [
+            (0, 156_543.033_928_041),
+            (1, 78_271.516_964_020),
+            (10, 152.874_056_570),
+        ] {
+            assert_relative_eq!(
+                ruler.meters_per_pixel(&0., zoom),
+                resolution,
+                max_relative = 1e-9
+            );
+        }
+
+        assert_relative_eq!(
+            ruler.meters_per_pixel(&60., 10),
+            152.874_056_570 / 2.,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            ruler.with_units(Units::Kilometers).meters_per_pixel(&0., 0),
+            156.543_033_928,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn advance() {
+        let ruler = CheapRuler::<f64>::WGS84();
+        let origin = [-73.9857, 40.7484];
+
+        for heading in [0., 45., 90., 200., -30.] {
+            assert_eq!(
+                ruler.advance(&origin, &heading, &10., &5.),
+                ruler.destination(&origin, &heading, &50.)
+            );
+        }
+    }
+
+    #[test]
+    fn interpolate() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        assert_eq!(
+            ruler.interpolate(&[-73.99, 40.74], &[-73.98, 40.76], &0.5),
+            [-73.985, 40.75]
+        );
+
+        assert_eq!(
+            ruler.interpolate(&[179., 0.], &[-179., 2.], &0.5),
+            [180., 1.]
+        );
+        assert_eq!(
+            ruler.interpolate(&[179., 0.], &[-179., 2.], &0.75),
+            [-179.5, 1.5]
+        );
+
+        let raw = ruler.with_antimeridian(AntimeridianMode::Raw);
+        assert_eq!(raw.interpolate(&[179., 0.], &[-179., 2.], &0.5), [0., 1.]);
+    }
+
+    #[test]
+    fn separate_coordinates() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let (empire_state, flatiron) = ([-73.9857, 40.7484], [-73.9897, 40.7411]);
+
+        assert_eq!(
+            ruler.distance_ll(&-73.9857, &40.7484, &-73.9897, &40.7411),
+            ruler.distance(&empire_state, &flatiron)
+        );
+        assert_eq!(
+            ruler.bearing_ll(&-73.9857, &40.7484, &-73.9897, &40.7411),
+            ruler.bearing(&empire_state, &flatiron)
+        );
+        assert_eq!(
+            ruler.destination_ll(&-73.9857, &40.7484, &45., &100.),
+            ruler.destination(&empire_state, &45., &100.)
+        );
+    }
+
+    #[test]
+    fn bearing_tolerance() {
+        let origin = [-73.9857, 40.7484];
+        let exact = CheapRuler::<f64>::WGS84().with_bearing_tolerance(&0.);
+
+        let targets: Vec<[f64; 2]> = (0..360)
+            .map(|bearing| exact.destination(&origin, &(bearing as f64 + 0.5), &1_000.))
+            .collect();
+
+        let max_error = |tolerance: f64| {
+            let ruler = CheapRuler::<f64>::WGS84().with_bearing_tolerance(&tolerance);
+
+            targets
+                .iter()
+                .map(|target| {
+                    let error =
+                        (ruler.bearing(&origin, target) - exact.bearing(&origin, target)).abs();
+                    error.min(360. - error)
+                })
+                .fold(0., f64::max)
+        };
+
+        let errors: Vec<f64> = [1., 0.1, 0.01, 0.00001].map(max_error).to_vec();
+
+        for (error, tolerance) in errors.iter().zip([1., 0.1, 0.01, 0.00001]) {
+            assert!(*error <= tolerance);
+        }
+
+        for pair in errors.windows(2) {
+            assert!(pair[1] < pair[0]);
+        }
+    }
+
+    #[test]
+    fn total_turning() {
+        let ruler = CheapRuler::<f64>::WGS84();
+        let center = [-73.9654, 40.7829]; // Central Park
+
+        // a lap around a 500 meters radius track, clockwise
+        let mut lap: [[f64; 2]; 37] =
+            core::array::from_fn(|i| ruler.destination(&center, &((i % 36) as f64 * 10.), &500.));
+
+        assert_relative_eq!(ruler.total_turning(&lap), 360., epsilon = 1e-9);
+        // without closing, the turns at the first and last fixes are missing
+        assert_relative_eq!(ruler.total_turning(&lap[..36]), 340., epsilon = 1.);
+
+        lap.reverse();
+        assert_relative_eq!(ruler.total_turning(&lap), -360., epsilon = 1e-9);
+
+        // straight along a parallel, with a repeated fix
+        let straight = [
+            center,
+            ruler.offset(&center, &100., &0.),
+            ruler.offset(&center, &100., &0.),
+            ruler.offset(&center, &200., &0.),
+            ruler.offset(&center, &300., &0.),
+        ];
+
+        assert_relative_eq!(ruler.total_turning(&straight), 0., epsilon = 1e-9);
+
+        // a right turn followed by a left one
+        let zigzag = [
+            center,
+            ruler.offset(&center, &0., &100.),
+            ruler.offset(&center, &100., &100.),
+            ruler.offset(&center, &100., &200.),
+        ];
+
+        assert_relative_eq!(
+            ruler.turn_angle(&zigzag[0], &zigzag[1], &zigzag[2]),
+            90.,
+            epsilon = 1.
+        );
+        assert_relative_eq!(ruler.total_turning(&zigzag), 0., epsilon = 1e-9);
+
+        assert_eq!(ruler.total_turning(&straight[..2]), 0.);
+        assert_eq!(ruler.total_turning(&[]), 0.);
+    }
+
+    #[test]
+    fn length_kahan() {
+        let ruler = CheapRuler::<f32>::WGS84();
+
+        // a hundred thousand segments of about 1.1 meters along the equator
+        let line: Vec<[f32; 2]> = (0..100_000).map(|i| [i as f32 * 1e-5, 0.]).collect();
+
+        let reference: f64 = line
+            .windows(2)
+            .map(|segment| ruler.distance(&segment[0], &segment[1]) as f64)
+            .sum();
+
+        let naive = ruler.length(&line) as f64;
+        let kahan = ruler.length_kahan(&line) as f64;
+
+        assert!((naive - reference).abs() / reference > 1e-4);
+        assert_relative_eq!(kahan, reference, max_relative = 1e-6);
+
+        assert_eq!(ruler.length_kahan(&[]), 0.);
+        assert_eq!(ruler.length_kahan(&line[..1]), 0.);
+    }
+
+    #[test]
+    fn try_length() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let mut line = [
+            [-73.9857, 40.7484],
+            [-73.9897, 40.7411],
+            [-73.9927, 40.7394],
+            [-73.9819, 40.7681],
+        ];
+
+        assert_eq!(ruler.try_length(&line), Ok(ruler.length(&line)));
+        assert_eq!(ruler.try_length(&[]), Ok(0.));
+
+        line[2][0] = f64::NAN;
+        assert_eq!(
+            ruler.try_length(&line),
+            Err(RulerError::InvalidCoordinate { index: 2 })
+        );
+
+        line[1][1] = 90.5;
+        assert_eq!(
+            ruler.try_length(&line),
+            Err(RulerError::InvalidCoordinate { index: 1 })
+        );
+    }
+
+    #[test]
+    fn length_and_along() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let route = [
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9855, 40.7580], // Times Square
+        ];
+
+        let first = ruler.distance(&route[0], &route[1]);
+        let second = ruler.distance(&route[1], &route[2]);
+
+        assert_eq!(ruler.length(&route), first + second);
+        assert_eq!(ruler.length(&route[..1]), 0.);
+
+        assert_eq!(ruler.along(&route, &-1.), Some(route[0]));
+        assert_eq!(ruler.along(&route, &(first + second + 1.)), Some(route[2]));
+        assert_eq!(ruler.along(&[], &1.), None);
+
+        let point = ruler.along(&route, &(first + second / 2.)).unwrap();
+        assert_relative_eq!(
+            ruler.distance(&route[1], &point),
+            second / 2.,
+            max_relative = RELATIVE_ERROR
+        );
+        assert_relative_eq!(
+            ruler.distance(&point, &route[2]),
+            second / 2.,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[cfg(feature = "memoize")]
+    #[test]
+    fn memoized_coefs() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let empire_state = [-73.9857, 40.7484];
+        let flatiron = [-73.9897, 40.7411];
+
+        let first = ruler.distance(&empire_state, &flatiron);
+        assert_eq!(
+            ruler.cache.get(),
+            Some((40.7484, ruler.coefs(&empire_state)))
+        );
+        assert_eq!(ruler.distance(&empire_state, &flatiron), first);
+
+        // changing the units must not reuse factors cached for meters
+        let miles = ruler.with_units(Units::Miles);
+        assert_eq!(miles.cache.get(), None);
+        assert_relative_eq!(
+            miles.distance(&empire_state, &flatiron),
+            first / 1609.344,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn antimeridian() {
+        let west_of = [179.99, 10.];
+        let east_of = [-179.99, 10.];
+
+        let wrap = CheapRuler::<f64>::WGS84();
+        assert_eq!(wrap.antimeridian(), AntimeridianMode::Wrap);
+
+        let short = wrap.distance(&west_of, &[180.01, 10.]);
+        assert_relative_eq!(
+            wrap.distance(&west_of, &east_of),
+            short,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(wrap.bearing(&west_of, &east_of), 90., epsilon = 0.1);
+        assert_relative_eq!(wrap.bearing(&east_of, &west_of), -90., epsilon = 0.1);
+
+        let raw = CheapRuler::<f64>::WGS84().with_antimeridian(AntimeridianMode::Raw);
+
+        // along a parallel the distance is proportional to the longitude difference
+        assert_relative_eq!(
+            raw.distance(&west_of, &east_of),
+            short * 359.98 / 0.02,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(raw.bearing(&west_of, &east_of), -90., epsilon = 0.1);
+
+        // far from the antimeridian both modes agree
+        let empire_state = [-73.9857, 40.7484];
+        let flatiron = [-73.9897, 40.7411];
+        assert_eq!(
+            wrap.distance(&empire_state, &flatiron),
+            raw.distance(&empire_state, &flatiron)
+        );
+    }
+
+    #[test]
+    fn bearing_at_fraction() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // about 1 km east, then 1 km north
+        let origin = [-73.9857, 40.7484];
+        let corner = ruler.offset(&origin, &1_000., &0.);
+        let end = ruler.offset(&corner, &0., &1_000.);
+
+        let route = [origin, corner, corner, end];
+
+        for fraction in [-1., 0., 0.25, 0.49] {
+            assert_relative_eq!(
+                ruler.bearing_at_fraction(&route, &fraction).unwrap(),
+                90.,
+                epsilon = 0.1
+            );
+        }
+
+        for fraction in [0.51, 0.75, 1., 2.] {
+            assert_relative_eq!(
+                ruler.bearing_at_fraction(&route, &fraction).unwrap(),
+                0.,
+                epsilon = 0.1
+            );
+        }
+
+        assert_eq!(ruler.bearing_at_fraction(&route[..1], &0.5), None);
+        assert_eq!(ruler.bearing_at_fraction(&[origin, origin], &0.5), None);
+    }
+
+    #[test]
+    fn prolate_ellipsoid() {
+        let (major, minor) = (1_000_000., 1_200_000.);
+
+        let ruler = CheapRuler::<f64>::try_new(&major, &minor).unwrap();
+
+        let origin = [0., 0.];
+        let step = 0.01;
+
+        // along the equator the scale is the equatorial radius
+        let east = ruler.distance(&origin, &[step, 0.]);
+        assert_relative_eq!(
+            east,
+            major * step.to_radians(),
+            max_relative = RELATIVE_ERROR
+        );
+
+        // along the meridian at the equator the radius of curvature is minor² / major
+        let north = ruler.distance(&origin, &[0., step]);
+        assert_relative_eq!(
+            north,
+            minor.powi(2) / major * step.to_radians(),
+            max_relative = RELATIVE_ERROR
+        );
+
+        for lat in [-89., -45., 0., 45., 89.] {
+            let distance = ruler.distance(&[0., lat], &[step, lat + step]);
+            assert!(distance.is_finite() && distance > 0.);
+        }
+    }
+
+    #[test]
+    fn invalid_ellipsoid() {
+        for (major, minor) in [(0., 1.), (1., -1.), (f64::NAN, 1.), (1., f64::INFINITY)] {
+            assert!(matches!(
+                CheapRuler::try_new(&major, &minor),
+                Err(RulerError::InvalidEllipsoid)
+            ));
+        }
+    }
+
+    #[test]
+    fn ref_lat_scaling_is_consistent() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // a tile spanning roughly 1 km around midtown Manhattan
+        let ref_lat = 40.75;
+        let (dx, dy) = (0.001, 0.001);
+
+        let tile = [[-73.990, 40.745], [-73.985, 40.750], [-73.980, 40.755]];
+
+        let east = ruler.distance_at_ref_lat(&ref_lat, &tile[0], &[tile[0][0] + dx, tile[0][1]]);
+        let north = ruler.distance_at_ref_lat(&ref_lat, &tile[0], &[tile[0][0], tile[0][1] + dy]);
+
+        for point in &tile {
+            let east_of = [point[0] + dx, point[1]];
+            let north_of = [point[0], point[1] + dy];
+
+            assert_relative_eq!(ruler.distance_at_ref_lat(&ref_lat, point, &east_of), east);
+            assert_relative_eq!(ruler.distance_at_ref_lat(&ref_lat, point, &north_of), north);
+            assert_relative_eq!(
+                ruler.bearing_at_ref_lat(&ref_lat, point, &east_of),
+                90.,
+                max_relative = RELATIVE_ERROR
+            );
+
+            let destination = ruler.destination_at_ref_lat(&ref_lat, point, &90., &east);
+            assert_relative_eq!(destination[0], east_of[0]);
+            assert_relative_eq!(destination[1], east_of[1]);
+        }
+
+        assert_relative_eq!(
+            ruler.distance_at_ref_lat(&ref_lat, &tile[1], &tile[2]),
+            ruler.distance(&tile[1], &tile[2])
+        );
+    }
+
+    #[test]
+    fn distance_to_line() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let point = [-73.9857, 40.7484];
+        let line = [
+            [-73.9897, 40.7411],
+            [-73.9810, 40.7440],
+            [-73.9790, 40.7520],
+            [-73.9900, 40.7530],
+        ];
+
+        let steps = 10_000;
+        let brute_force = line
+            .windows(2)
+            .flat_map(|segment| {
+                (0..=steps).map(move |i| {
+                    let t = i as f64 / steps as f64;
+                    [
+                        segment[0][0] + (segment[1][0] - segment[0][0]) * t,
+                        segment[0][1] + (segment[1][1] - segment[0][1]) * t,
+                    ]
+                })
+            })
+            .map(|vertex| ruler.distance(&point, &vertex))
+            .fold(f64::INFINITY, f64::min);
+
+        assert_relative_eq!(
+            ruler.distance_to_line(&point, &line),
+            brute_force,
+            max_relative = RELATIVE_ERROR
+        );
+    }
+
+    #[test]
+    fn distance_between_lines() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // two parallel streets a block apart, offset along their length
+        let avenue = [[-73.9900, 40.7500], [-73.9800, 40.7500]];
+        let block = ruler.offset(&avenue[0], &0., &80.);
+        let parallel = [[block[0] + 0.003, block[1]], [block[0] + 0.02, block[1]]];
+
+        assert_relative_eq!(
+            ruler.distance_between_lines(&avenue, &parallel),
+            80.,
+            max_relative = RELATIVE_ERROR
+        );
+        assert_relative_eq!(
+            ruler.distance_between_lines(&parallel, &avenue),
+            80.,
+            max_relative = RELATIVE_ERROR
+        );
+
+        // a crossing without a shared vertex
+        let cross_street = [[-73.9850, 40.7450], [-73.9860, 40.7550]];
+
+        assert_eq!(ruler.distance_between_lines(&avenue, &cross_street), 0.);
+        assert_eq!(ruler.distance_between_lines(&cross_street, &avenue), 0.);
+
+        // a gap closest at the end of one line, projected onto the middle of the other
+        let dead_end = [[-73.9850, 40.7400], [-73.9850, 40.7490]];
+
+        assert_relative_eq!(
+            ruler.distance_between_lines(&avenue, &dead_end),
+            ruler.distance(&dead_end[1], &[-73.9850, 40.7500]),
+            max_relative = RELATIVE_ERROR
+        );
+
+        assert!(ruler.distance_between_lines(&avenue, &[]).is_infinite());
+    }
+
+    #[test]
+    fn distance_to_line_clamps_to_endpoints() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let point = [-73.9857, 40.7484];
+        let line = [[-73.9800, 40.7400], [-73.9700, 40.7400]];
+
+        assert_relative_eq!(
+            ruler.distance_to_line(&point, &line),
+            ruler.distance(&point, &line[0]),
+            max_relative = RELATIVE_ERROR
+        );
     }
 }
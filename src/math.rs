@@ -18,6 +18,10 @@
 //!
 //! These optimized implementations are automatically used by the `bearing` method when
 //! the corresponding feature flag is enabled.
+//!
+//! Regardless of the feature flags, a ruler can also pick an approximation at runtime from
+//! a bearing error tolerance, see
+//! [`CheapRuler::with_bearing_tolerance`](crate::CheapRuler::with_bearing_tolerance).
 
 use num_traits::{Float, FloatConst};
 
@@ -37,34 +41,16 @@ use num_traits::{Float, FloatConst};
 ///
 /// # Feature Flag
 ///
-/// Available when compiled with the `atan2_deg3` feature flag.
+/// Available when compiled with the `atan2_deg3` feature flag, unless `atan2_deg5` is
+/// enabled as well, which takes precedence.
 ///
 /// # See Also
 ///
 /// - [`atan2` with `atan2_deg5` flag](#method.atan2-1): Higher precision approximation
 /// - Rust's default `atan2`: Maximum precision, used when no feature flags are enabled
-#[cfg(feature = "atan2_deg3")]
+#[cfg(all(feature = "atan2_deg3", not(feature = "atan2_deg5")))]
 pub fn atan2<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
-    let a1: F = 0.9817f32.into();
-    let a3: F = 0.1963f32.into();
-
-    let pi_4 = F::FRAC_PI_4();
-
-    let abs_y = y.abs();
-
-    let (r, a) = if x < F::zero() {
-        ((x + abs_y) / (abs_y - x), pi_4 + pi_4 + pi_4)
-    } else {
-        ((x - abs_y) / (x + abs_y), pi_4)
-    };
-
-    let mut res = a + (a3 * r * r - a1) * r;
-
-    if y < F::zero() {
-        res = -res;
-    }
-
-    res
+    atan2_deg3(y, x)
 }
 
 /// Accurate 5th degree polynomial approximation of the atan2 function.
@@ -88,7 +74,8 @@ pub fn atan2<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
 ///
 /// # Feature Flag
 ///
-/// Available when compiled with the `atan2_deg5` feature flag.
+/// Available when compiled with the `atan2_deg5` feature flag, even alongside the default
+/// `atan2_deg3` one.
 ///
 /// # See Also
 ///
@@ -96,9 +83,40 @@ pub fn atan2<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
 /// - Rust's Default `atan2`: Maximum precision, used when no feature flags are enabled
 #[cfg(feature = "atan2_deg5")]
 pub fn atan2<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
+    atan2_deg5(y, x)
+}
+
+/// 3rd degree polynomial approximation of atan2, with a maximum error of about 0.6°.
+///
+/// Always compiled, backing both the `atan2_deg3` feature and the runtime selection of
+/// [`CheapRuler::with_bearing_tolerance`](crate::CheapRuler::with_bearing_tolerance).
+pub(crate) fn atan2_deg3<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
+    let a1: F = 0.9817f32.into();
+    let a3: F = 0.1963f32.into();
+
+    let pi_4 = F::FRAC_PI_4();
+
     let abs_y = y.abs();
-    let abs_x = x.abs();
 
+    let (r, a) = if x < F::zero() {
+        ((x + abs_y) / (abs_y - x), pi_4 + pi_4 + pi_4)
+    } else {
+        ((x - abs_y) / (x + abs_y), pi_4)
+    };
+
+    let mut res = a + (a3 * r * r - a1) * r;
+
+    if y < F::zero() {
+        res = -res;
+    }
+
+    res
+}
+
+/// 5th degree polynomial approximation of atan2, with a maximum error of about 0.035°.
+///
+/// See [`atan2_deg3`] for when it is compiled.
+pub(crate) fn atan2_deg5<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
     /// Internal helper function for the 5th degree atan approximation
     ///
     /// Calculates atan(x) for |x| ≤ 1 using a 5th degree polynomial approximation.
@@ -116,10 +134,44 @@ pub fn atan2<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
         x * (a1 + x_sq * (a3 + x_sq * a5))
     }
 
+    reduce(y, x, raw_atan_5)
+}
+
+/// 11th degree polynomial approximation of atan2, with a maximum error of about 0.0001°.
+///
+/// See [`atan2_deg3`] for when it is compiled.
+pub(crate) fn atan2_deg11<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
+    /// Higher precision 11th degree polynomial approximation of atan function.
+    ///
+    /// It offers even higher precision than the 5th degree polynomial but at a higher
+    /// computational cost.
+    ///
+    /// Polynomial coefficients from "Approximations for digital computers" by Cecil Hastings
+    /// and Horner's method for optimal polynomial evaluation
+    fn raw_atan_11<G: Float + From<f32>>(x: G) -> G {
+        let a1: G = 0.99997726f32.into();
+        let a3: G = (-0.33262347f32).into();
+        let a5: G = 0.19354346f32.into();
+        let a7: G = (-0.11643287f32).into();
+        let a9: G = 0.05265332f32.into();
+        let a11: G = (-0.0117212_f32).into();
+
+        let x_sq = x * x;
+        x * (a1 + x_sq * (a3 + x_sq * (a5 + x_sq * (a7 + x_sq * (a9 + x_sq * a11)))))
+    }
+
+    reduce(y, x, raw_atan_11)
+}
+
+/// Extends an approximation of atan on [-1, 1] to atan2 over the whole circle.
+fn reduce<F: Float + FloatConst>(y: F, x: F, raw_atan: fn(F) -> F) -> F {
+    let abs_y = y.abs();
+    let abs_x = x.abs();
+
     let mut res = if abs_x < abs_y {
-        F::FRAC_PI_2() - raw_atan_5(abs_x / abs_y)
+        F::FRAC_PI_2() - raw_atan(abs_x / abs_y)
     } else {
-        raw_atan_5(abs_y / abs_x)
+        raw_atan(abs_y / abs_x)
     };
 
     if x < F::zero() {
@@ -133,37 +185,20 @@ pub fn atan2<F: Float + FloatConst + From<f32>>(y: F, x: F) -> F {
     res
 }
 
-/// Higher precision 11th degree polynomial approximation of atan function.
-///
-/// This function is included for reference and potential future use. It offers even higher
-/// precision than the 5th degree polynomial (error < 0.001 radians) but at a higher
-/// computational cost.
-///
-/// Polynomial coefficients from "Approximations for digital computers" by Cecil Hastings
-/// and Horner's method for optimal polynomial evaluation
-#[allow(dead_code)]
-fn _raw_atan_11<F: Float + From<f32>>(x: F) -> F {
-    let a1: F = 0.99997726f32.into();
-    let a3: F = (-0.33262347f32).into();
-    let a5: F = 0.19354346f32.into();
-    let a7: F = (-0.11643287f32).into();
-    let a9: F = 0.05265332f32.into();
-    let a11: F = (-0.0117212_f32).into();
-
-    let x_sq = x * x;
-    x * (a1 + x_sq * (a3 + x_sq * (a5 + x_sq * (a7 + x_sq * (a9 + x_sq * a11)))))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(any(feature = "atan2_deg3", feature = "atan2_deg5"))]
     use approx::assert_relative_eq;
     use proptest::prelude::*;
 
+    #[cfg(any(feature = "atan2_deg3", feature = "atan2_deg5"))]
     const RELATIVE_ERROR: f32 = 0.1;
+    #[cfg(any(feature = "atan2_deg3", feature = "atan2_deg5"))]
     const EPSILON: f32 = 0.1;
 
     proptest! {
+        #[cfg(any(feature = "atan2_deg3", feature = "atan2_deg5"))]
         #[test]
         fn fuzzy_test_atan2(x in -1000f32..=1000., y in -1000f32..=1000.) {
 
@@ -174,5 +209,19 @@ mod tests {
 
             assert_relative_eq!(atan2(x,y), x.atan2(y), epsilon = EPSILON, max_relative = RELATIVE_ERROR);
         }
+
+        #[test]
+        fn fuzzy_test_atan2_degrees(x in -1000f32..=1000., y in -1000f32..=1000.) {
+            if x == 0. && y == 0. {
+                return Ok(())
+            }
+
+            let exact = x.atan2(y);
+
+            for (approximation, max_error) in [(atan2_deg3 as fn(f32, f32) -> f32, 0.6), (atan2_deg5, 0.035), (atan2_deg11, 0.0002)] {
+                let error = (approximation(x, y) - exact).abs().to_degrees();
+                assert!(error <= max_error || (360. - error) <= max_error);
+            }
+        }
     }
 }
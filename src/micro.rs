@@ -0,0 +1,187 @@
+//! This module provides `MicroRuler`, a ruler measuring integer microdegrees with
+//! fixed-point arithmetic only.
+//!
+//! On microcontrollers without a floating point unit, such as many GPS trackers, every
+//! float operation is emulated in software. A `MicroRuler` computes the scaling factors
+//! once, for every degree of latitude, then measures with integer multiplications, a
+//! linear interpolation of the scaling and an integer square root.
+
+use crate::{AntimeridianMode, CheapRuler};
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+/// Microdegrees in a degree.
+const MICRO: i64 = 1_000_000;
+
+/// Fractional bits of the fixed-point scaling factors.
+const SHIFT: u32 = 16;
+
+/// A ruler measuring coordinates given as integer microdegrees, without floating point.
+///
+/// Obtained with [`CheapRuler::micro`], it holds the scaling factors of the ruler for
+/// every degree of latitude in fixed-point, and interpolates them linearly in between.
+/// Distances are returned in thousandths of the ruler units, millimeters by default.
+///
+/// Compared to the `f64` [`CheapRuler::distance`], the results are within 0.01%, plus
+/// the resolution of the inputs, about 0.11 meters for one microdegree.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::CheapRuler;
+///
+/// let ruler = CheapRuler::<f32>::WGS84().micro();
+///
+/// let empire_state = [-73_985_700, 40_748_400];
+/// let flatiron = [-73_989_700, 40_741_100];
+///
+/// let millimeters = ruler.distance(&empire_state, &flatiron);
+///
+/// assert!((878_000..880_000).contains(&millimeters));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MicroRuler {
+    /// Scaling factors `[kx, ky]` at each degree of latitude from 0 to 90, in thousandths
+    /// of the ruler units per microdegree, with `SHIFT` fractional bits
+    coefs: [[u32; 2]; 91],
+    /// Whether to wrap longitude differences across the antimeridian
+    wrap: bool,
+}
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Creates a fixed-point ruler for integer microdegree coordinates.
+    ///
+    /// Floating point is only used here, to tabulate the scaling factors, the returned
+    /// ruler measures with integer arithmetic only. It keeps the ellipsoid, the units and
+    /// the antimeridian mode of this one.
+    ///
+    /// # Returns
+    ///
+    /// A [`MicroRuler`] measuring in thousandths of the ruler units
+    pub fn micro(&self) -> MicroRuler {
+        let scale = T::from(1_000. * f64::from(1u32 << SHIFT) / MICRO as f64).unwrap();
+        let fixed = |k: T| (k * scale).round().to_u32().unwrap_or(u32::MAX);
+
+        let mut coefs = [[0; 2]; 91];
+
+        for (lat, entry) in coefs.iter_mut().enumerate() {
+            let [kx, ky] = self.coefs_at(&T::from(lat).unwrap());
+            *entry = [fixed(kx), fixed(ky)];
+        }
+
+        MicroRuler {
+            coefs,
+            wrap: self.antimeridian == AntimeridianMode::Wrap,
+        }
+    }
+}
+
+impl MicroRuler {
+    /// Calculates the distance between two points.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: The origin as `[longitude, latitude]` in microdegrees
+    /// - `b`: The destination as `[longitude, latitude]` in microdegrees
+    ///
+    /// # Returns
+    ///
+    /// The distance in thousandths of the ruler units, saturating at `u32::MAX`, e.g.
+    /// beyond about 4,000 km in millimeters
+    pub fn distance(&self, a: &[i32; 2], b: &[i32; 2]) -> u32 {
+        let [kx, ky] = self.coefs(a[1]);
+
+        let dx = (self.delta_lon(a[0], b[0]) * kx) >> SHIFT;
+        let dy = ((i64::from(b[1]) - i64::from(a[1])) * ky) >> SHIFT;
+
+        let squared = dx
+            .unsigned_abs()
+            .saturating_pow(2)
+            .saturating_add(dy.unsigned_abs().saturating_pow(2));
+
+        u32::try_from(squared.isqrt()).unwrap_or(u32::MAX)
+    }
+
+    /// Interpolates the fixed-point scaling factors at a latitude in microdegrees.
+    fn coefs(&self, lat: i32) -> [i64; 2] {
+        let lat = i64::from(lat).abs().min(90 * MICRO);
+
+        let index = (lat / MICRO) as usize;
+        let fraction = lat % MICRO;
+
+        let [low, high] = [self.coefs[index], self.coefs[(index + 1).min(90)]];
+
+        [0, 1].map(|axis| {
+            let [low, high] = [i64::from(low[axis]), i64::from(high[axis])];
+            low + (high - low) * fraction / MICRO
+        })
+    }
+
+    /// Computes the longitude difference in microdegrees, according to the antimeridian mode.
+    fn delta_lon(&self, from: i32, to: i32) -> i64 {
+        let delta = i64::from(to) - i64::from(from);
+
+        if self.wrap && delta.abs() > 180 * MICRO {
+            delta - delta.signum() * 360 * MICRO
+        } else {
+            delta
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Units;
+
+    const PAIRS: [[[f64; 2]; 2]; 5] = [
+        [[-73.9857, 40.7484], [-73.9897, 40.7411]],   // New York
+        [[2.2945, 48.8584], [2.3499, 48.8530]],       // Paris
+        [[151.2153, -33.8568], [151.2093, -33.8688]], // Sydney
+        [[-58.3816, -34.6037], [-58.3712, -34.6083]], // Buenos Aires
+        [[179.9950, -16.5], [-179.9950, -16.51]],     // across the antimeridian
+    ];
+
+    fn micro(point: &[f64; 2]) -> [i32; 2] {
+        point.map(|degrees| (degrees * 1e6).round() as i32)
+    }
+
+    #[test]
+    fn micro_distance() {
+        let single = CheapRuler::<f32>::WGS84();
+        let double = CheapRuler::<f64>::WGS84();
+        let ruler = single.micro();
+
+        for [a, b] in PAIRS {
+            let fixed = ruler.distance(&micro(&a), &micro(&b)) as f64 / 1_000.;
+
+            let reference = double.distance(&a, &b);
+            assert!((fixed - reference).abs() <= 1e-4 * reference + 0.11);
+
+            let [a, b] = [a, b].map(|p| p.map(|degrees| degrees as f32));
+            let float = single.distance(&a, &b) as f64;
+            assert!((fixed - float).abs() <= 1e-3 * float + 1.);
+        }
+
+        assert_eq!(ruler.distance(&[0, 0], &[0, 0]), 0);
+        assert_eq!(ruler.distance(&[0, 0], &[180_000_000, 0]), u32::MAX);
+    }
+
+    #[test]
+    fn micro_settings() {
+        let [a, b] = PAIRS[4].map(|p| micro(&p));
+
+        let raw = CheapRuler::<f64>::WGS84()
+            .with_antimeridian(AntimeridianMode::Raw)
+            .micro();
+        assert_eq!(raw.distance(&a, &b), u32::MAX);
+
+        let meters = CheapRuler::<f64>::WGS84().micro().distance(&a, &b);
+        let kilometers = CheapRuler::<f64>::WGS84()
+            .with_units(Units::Kilometers)
+            .micro()
+            .distance(&a, &b);
+
+        assert!((kilometers as f64 - meters as f64 / 1_000.).abs() <= 1e-3 * kilometers as f64);
+    }
+}
@@ -0,0 +1,859 @@
+//! This module provides an owned polyline type for users of the core `CheapRuler`,
+//! along with the ruler's polyline operations that build new vertex lists, such as
+//! densification and clipping.
+//!
+//! `Polyline` wraps a `Vec` of `[longitude, latitude]` vertices and exposes the ruler's
+//! polyline operations as methods, without depending on the geo crate.
+//!
+//! It is available when the `alloc` feature flag is enabled.
+
+use crate::CheapRuler;
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+/// An owned polyline, a sequence of `[longitude, latitude]` vertices in degrees.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::{CheapRuler, Polyline};
+///
+/// let ruler = CheapRuler::<f64>::WGS84();
+///
+/// let route = Polyline(vec![
+///     [-73.9897, 40.7411], // Flatiron Building
+///     [-73.9857, 40.7484], // Empire State Building
+///     [-73.9855, 40.7580], // Times Square
+/// ]);
+///
+/// let length = route.length(&ruler);
+/// let halfway = route.along(&ruler, &(length / 2.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polyline<T>(pub Vec<[T; 2]>);
+
+impl<T> From<Vec<[T; 2]>> for Polyline<T> {
+    fn from(vertices: Vec<[T; 2]>) -> Self {
+        Self(vertices)
+    }
+}
+
+impl<T> AsRef<[[T; 2]]> for Polyline<T> {
+    fn as_ref(&self) -> &[[T; 2]] {
+        &self.0
+    }
+}
+
+impl<T: Float + FloatConst + Debug> Polyline<T> {
+    /// Calculates the total length of the polyline.
+    ///
+    /// See [`CheapRuler::length`].
+    pub fn length(&self, ruler: &CheapRuler<T>) -> T {
+        ruler.length(&self.0)
+    }
+
+    /// Computes the bounding box of the polyline.
+    ///
+    /// # Returns
+    ///
+    /// The box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees,
+    /// or `None` if the polyline is empty
+    pub fn bbox(&self) -> Option<[T; 4]> {
+        let [x, y] = *self.0.first()?;
+
+        Some(
+            self.0
+                .iter()
+                .fold([x, y, x, y], |[min_x, min_y, max_x, max_y], [x, y]| {
+                    [min_x.min(*x), min_y.min(*y), max_x.max(*x), max_y.max(*y)]
+                }),
+        )
+    }
+
+    /// Simplifies the polyline using the Douglas–Peucker algorithm.
+    ///
+    /// See [`CheapRuler::simplify`].
+    pub fn simplify(&self, ruler: &CheapRuler<T>, tolerance: &T) -> Self {
+        Self(ruler.simplify(&self.0, tolerance))
+    }
+
+    /// Finds the point at a given distance along the polyline.
+    ///
+    /// See [`CheapRuler::along`].
+    pub fn along(&self, ruler: &CheapRuler<T>, distance: &T) -> Option<[T; 2]> {
+        ruler.along(&self.0, distance)
+    }
+
+    /// Adds vertices so that no segment of the polyline is longer than a given distance.
+    ///
+    /// See [`CheapRuler::densify`].
+    pub fn densify(&self, ruler: &CheapRuler<T>, max_distance: &T) -> Self {
+        Self(ruler.densify(&self.0, max_distance))
+    }
+}
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Adds vertices to a polyline so that no segment is longer than a given distance.
+    ///
+    /// Each segment is split into the fewest equal parts that are at most `max_distance`
+    /// long, interpolating linearly between its vertices, see [`interpolate`](Self::interpolate)
+    /// for the antimeridian handling. The original vertices are kept.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `max_distance`: Maximum length of a segment in the ruler units, the polyline is
+    ///   returned unchanged if it is not positive and finite
+    ///
+    /// # Returns
+    ///
+    /// The densified polyline vertices as `[longitude, latitude]` in degrees
+    pub fn densify(&self, line: &[[T; 2]], max_distance: &T) -> Vec<[T; 2]> {
+        let mut densified = Vec::with_capacity(line.len());
+        self.densify_into(line, max_distance, &mut densified);
+
+        densified
+    }
+
+    /// Adds vertices to a polyline so that no segment is longer than a given distance, into
+    /// a reused buffer.
+    ///
+    /// Same as [`densify`](Self::densify), but the output buffer is cleared and refilled
+    /// instead of allocated, so a buffer reused across calls only grows when more
+    /// vertices than ever are needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `max_distance`: Maximum length of a segment in the ruler units, the polyline is
+    ///   copied unchanged if it is not positive and finite
+    /// - `out`: Buffer receiving the densified polyline vertices, its previous content is dropped
+    pub fn densify_into(&self, line: &[[T; 2]], max_distance: &T, out: &mut Vec<[T; 2]>) {
+        out.clear();
+
+        if !(*max_distance > T::zero() && max_distance.is_finite()) {
+            out.extend_from_slice(line);
+            return;
+        }
+
+        for segment in line.windows(2) {
+            let [start, end] = [segment[0], segment[1]];
+
+            let parts = (self.distance(&start, &end) / *max_distance)
+                .ceil()
+                .to_usize()
+                .unwrap_or(1)
+                .max(1);
+
+            let count = T::from(parts).unwrap();
+
+            for part in 0..parts {
+                let ratio = T::from(part).unwrap() / count;
+
+                out.push(self.interpolate(&start, &end, &ratio));
+            }
+        }
+
+        out.extend(line.last());
+    }
+
+    /// Builds a polyline parallel to another one, at a given distance to its side.
+    ///
+    /// Each vertex is moved along the bisector of its two segments, so that both offset
+    /// segments stay at the given distance from the original ones. The miter at sharp
+    /// turns is limited to 4 times the distance, and repeated vertices are moved along
+    /// with their neighbors.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `distance`: Distance to the left of the direction of travel in the ruler units,
+    ///   negative values offset to the right
+    ///
+    /// # Returns
+    ///
+    /// The offset polyline vertices as `[longitude, latitude]` in degrees, one for each
+    /// input vertex
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let fifth_avenue = [
+    ///     [-73.9897, 40.7411], // Flatiron Building
+    ///     [-73.9857, 40.7484], // Empire State Building
+    /// ];
+    ///
+    /// // the east sidewalk, 15 meters to the right
+    /// let sidewalk = ruler.offset_line(&fifth_avenue, &-15.);
+    /// ```
+    pub fn offset_line(&self, line: &[[T; 2]], distance: &T) -> Vec<[T; 2]> {
+        // unit normals to the left of each segment, none for repeated vertices
+        let normals: Vec<Option<[T; 2]>> = line
+            .windows(2)
+            .map(|segment| {
+                let [kx, ky] = self.coefs(&segment[0]);
+
+                let dx = self.delta_lon(&segment[0][0], &segment[1][0]) * kx;
+                let dy = (segment[1][1] - segment[0][1]) * ky;
+                let norm = dx.hypot(dy);
+
+                (norm > T::zero()).then(|| [-dy / norm, dx / norm])
+            })
+            .collect();
+
+        let limit = T::from(0.125).unwrap();
+
+        line.iter()
+            .enumerate()
+            .map(|(i, point)| {
+                let before = normals[..i.min(normals.len())]
+                    .iter()
+                    .rev()
+                    .flatten()
+                    .next();
+                let after = normals.get(i..).unwrap_or_default().iter().flatten().next();
+
+                let [nx, ny] = match (before, after) {
+                    // the miter projects to a unit length on both normals
+                    (Some(a), Some(b)) => {
+                        let scale = (T::one() + a[0] * b[0] + a[1] * b[1]).max(limit);
+                        [(a[0] + b[0]) / scale, (a[1] + b[1]) / scale]
+                    }
+                    (Some(normal), None) | (None, Some(normal)) => *normal,
+                    (None, None) => [T::zero(); 2],
+                };
+
+                self.offset(point, &(nx * *distance), &(ny * *distance))
+            })
+            .collect()
+    }
+
+    /// Finds the points at many distances along a polyline, in a single pass.
+    ///
+    /// Same as [`sample_at_distances`](Self::sample_at_distances), returning a newly
+    /// allocated vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `distances`: Distances from the first vertex in the ruler units, in ascending order
+    ///
+    /// # Returns
+    ///
+    /// The point at each distance as `[longitude, latitude]` in degrees, or an empty
+    /// vector if the line is empty
+    pub fn collect_at_distances(&self, line: &[[T; 2]], distances: &[T]) -> Vec<[T; 2]> {
+        if line.is_empty() {
+            return Vec::new();
+        }
+
+        let mut points = vec![[T::zero(); 2]; distances.len()];
+        self.sample_at_distances(line, distances, &mut points);
+
+        points
+    }
+
+    /// Builds a circle around a center point as a closed ring of vertices.
+    ///
+    /// The vertices are placed at the given distance from the center at evenly spaced
+    /// bearings, starting north and going clockwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `center`: The center of the circle as `[longitude, latitude]` in degrees
+    /// - `radius`: Radius of the circle in the ruler units
+    /// - `steps`: Number of distinct vertices, at least 3 are used
+    ///
+    /// # Returns
+    ///
+    /// The `steps + 1` ring vertices as `[longitude, latitude]` in degrees, where the
+    /// last vertex repeats the first one
+    pub fn circle(&self, center: &[T; 2], radius: &T, steps: usize) -> Vec<[T; 2]> {
+        let steps = steps.max(3);
+        let full = T::PI().to_degrees() + T::PI().to_degrees();
+        let count = T::from(steps).unwrap();
+
+        let [kx, ky] = self.coefs(center);
+
+        let mut ring: Vec<[T; 2]> = (0..steps)
+            .map(|i| {
+                let bearing = full * T::from(i).unwrap() / count;
+                Self::destination_with(&[kx, ky], center, &bearing, radius)
+            })
+            .collect();
+
+        ring.push(ring[0]);
+
+        ring
+    }
+
+    /// Builds a regular grid of points around a center point.
+    ///
+    /// The points are spaced evenly east and north with [`offset`](Self::offset) from the
+    /// center, so they share the scaling at the center and form a regular lattice in
+    /// degrees, e.g. to sample a heatmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `center`: The center of the grid as `[longitude, latitude]` in degrees
+    /// - `cell`: Spacing between adjacent points in the ruler units
+    /// - `nx`: Number of columns, west to east
+    /// - `ny`: Number of rows, south to north
+    ///
+    /// # Returns
+    ///
+    /// The `nx * ny` points as `[longitude, latitude]` in degrees, row by row starting from
+    /// the south-west corner, so the point of column `i` and row `j` is at `j * nx + i`
+    pub fn grid(&self, center: &[T; 2], cell: &T, nx: usize, ny: usize) -> Vec<[T; 2]> {
+        let two = T::one() + T::one();
+        let first = |n: usize| -T::from(n.saturating_sub(1)).unwrap() / two * *cell;
+
+        let (west, south) = (first(nx), first(ny));
+
+        (0..ny)
+            .flat_map(|j| (0..nx).map(move |i| (i, j)))
+            .map(|(i, j)| {
+                let dx = west + T::from(i).unwrap() * *cell;
+                let dy = south + T::from(j).unwrap() * *cell;
+                self.offset(center, &dx, &dy)
+            })
+            .collect()
+    }
+
+    /// Clips a polyline to a bounding box.
+    ///
+    /// Uses the Cohen–Sutherland algorithm in coordinate space: segments are cut where
+    /// they cross the edges of the box, and a new part starts every time the line
+    /// leaves and re-enters it.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `bbox`: The box as `[min_longitude, min_latitude, max_longitude, max_latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The parts of the polyline inside the box, each as a list of `[longitude, latitude]`
+    /// vertices in degrees, or no parts if the line lies entirely outside
+    pub fn clip_to_bbox(&self, line: &[[T; 2]], bbox: &[T; 4]) -> Vec<Vec<[T; 2]>> {
+        let mut parts = Vec::new();
+        let mut part = Vec::new();
+
+        let Some(first) = line.first() else {
+            return parts;
+        };
+
+        let mut code_a = outcode(first, bbox);
+
+        for (i, segment) in line.windows(2).enumerate() {
+            let [mut a, mut b] = [segment[0], segment[1]];
+
+            let is_last = i + 2 == line.len();
+
+            let mut code_b = outcode(&b, bbox);
+            let end_code = code_b;
+
+            loop {
+                if code_a | code_b == 0 {
+                    // the (possibly trimmed) segment is inside the box
+                    part.push(a);
+
+                    if code_b != end_code {
+                        // the segment leaves the box
+                        part.push(b);
+
+                        if !is_last {
+                            parts.push(core::mem::take(&mut part));
+                        }
+                    } else if is_last {
+                        part.push(b);
+                    }
+
+                    break;
+                } else if code_a & code_b != 0 {
+                    // both ends are on the same outer side
+                    break;
+                } else if code_a != 0 {
+                    a = intersect(&a, &b, code_a, bbox);
+                    code_a = outcode(&a, bbox);
+                } else {
+                    b = intersect(&a, &b, code_b, bbox);
+                    code_b = outcode(&b, bbox);
+                }
+            }
+
+            code_a = end_code;
+        }
+
+        if !part.is_empty() {
+            parts.push(part);
+        }
+
+        parts
+    }
+
+    /// Splits a polyline into consecutive parts of a fixed length.
+    ///
+    /// The split points are interpolated on the segments where the accumulated length
+    /// reaches a multiple of `length`, and every part starts where the previous one ends,
+    /// e.g. to cut a trail into kilometer sections.
+    ///
+    /// # Parameters
+    ///
+    /// - `line`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `length`: The length of each part in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// The parts as lists of `[longitude, latitude]` vertices in degrees. All of them are
+    /// `length` long except possibly the last one, which is shorter. Lines with less than
+    /// two vertices have no parts, and a non-positive `length` leaves the line whole.
+    pub fn split_by_length(&self, line: &[[T; 2]], length: &T) -> Vec<Vec<[T; 2]>> {
+        let mut parts = Vec::new();
+
+        if line.len() < 2 {
+            return parts;
+        }
+
+        if *length <= T::zero() || length.is_nan() {
+            parts.push(line.to_vec());
+            return parts;
+        }
+
+        let mut part = vec![line[0]];
+        let mut left = *length;
+
+        for segment in line.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            let distance = self.distance(&a, &b);
+
+            // the splits are counted up front and placed from their index, since adding
+            // `length` over and over stops moving in f32 once it is below half an ulp
+            let first = left;
+            let splits = match distance > first {
+                true => ((distance - first) / *length)
+                    .ceil()
+                    .to_usize()
+                    .unwrap_or(0),
+                false => 0,
+            };
+
+            let mut start = T::zero();
+
+            for index in 0..splits {
+                start = first + T::from(index).unwrap() * *length;
+
+                let split = self.interpolate(&a, &b, &(start / distance));
+                part.push(split);
+                parts.push(core::mem::replace(&mut part, vec![split]));
+
+                left = *length;
+            }
+
+            left = left - (distance - start);
+            part.push(b);
+
+            // the part ends exactly on a vertex
+            if left <= T::zero() {
+                parts.push(core::mem::replace(&mut part, vec![b]));
+                left = *length;
+            }
+        }
+
+        if part.len() >= 2 {
+            parts.push(part);
+        }
+
+        parts
+    }
+}
+
+/// Computes the Cohen–Sutherland region code of a point relative to a bounding box.
+///
+/// Bits are set for left (1), right (2), below (4) and above (8) the box, zero means inside.
+fn outcode<T: Float>(point: &[T; 2], bbox: &[T; 4]) -> u8 {
+    let mut code = 0;
+
+    if point[0] < bbox[0] {
+        code |= 1;
+    } else if point[0] > bbox[2] {
+        code |= 2;
+    }
+
+    if point[1] < bbox[1] {
+        code |= 4;
+    } else if point[1] > bbox[3] {
+        code |= 8;
+    }
+
+    code
+}
+
+/// Intersects a segment with the box edge indicated by a region code.
+fn intersect<T: Float>(a: &[T; 2], b: &[T; 2], code: u8, bbox: &[T; 4]) -> [T; 2] {
+    let at_y = |y: T| [a[0] + (b[0] - a[0]) * (y - a[1]) / (b[1] - a[1]), y];
+    let at_x = |x: T| [x, a[1] + (b[1] - a[1]) * (x - a[0]) / (b[0] - a[0])];
+
+    if code & 8 != 0 {
+        at_y(bbox[3])
+    } else if code & 4 != 0 {
+        at_y(bbox[1])
+    } else if code & 2 != 0 {
+        at_x(bbox[2])
+    } else {
+        at_x(bbox[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    const RELATIVE_ERROR: f64 = 0.001;
+
+    fn route() -> Polyline<f64> {
+        Polyline(vec![
+            [-73.9897, 40.7411], // Flatiron Building
+            [-73.9857, 40.7484], // Empire State Building
+            [-73.9855, 40.7580], // Times Square
+        ])
+    }
+
+    #[test]
+    fn length() {
+        let ruler = CheapRuler::WGS84();
+        let route = route();
+
+        assert_eq!(
+            route.length(&ruler),
+            ruler.distance(&route.0[0], &route.0[1]) + ruler.distance(&route.0[1], &route.0[2])
+        );
+        assert_eq!(Polyline::default().length(&ruler), 0.);
+    }
+
+    #[test]
+    fn bbox() {
+        assert_eq!(route().bbox(), Some([-73.9897, 40.7411, -73.9855, 40.7580]));
+        assert_eq!(Polyline::<f64>::default().bbox(), None);
+    }
+
+    #[test]
+    fn simplify() {
+        let ruler = CheapRuler::WGS84();
+
+        let straight = Polyline(vec![[-73.99, 40.74], [-73.98, 40.74001], [-73.97, 40.74]]);
+
+        assert_eq!(
+            straight.simplify(&ruler, &5.),
+            Polyline(vec![[-73.99, 40.74], [-73.97, 40.74]])
+        );
+        assert_eq!(straight.simplify(&ruler, &0.1), straight);
+    }
+
+    #[test]
+    fn along() {
+        let ruler = CheapRuler::WGS84();
+        let route = route();
+
+        let length = route.length(&ruler);
+
+        assert_eq!(route.along(&ruler, &0.), Some(route.0[0]));
+        assert_eq!(route.along(&ruler, &length), Some(route.0[2]));
+
+        let halfway = route.along(&ruler, &(length / 2.)).unwrap();
+        let walked = ruler.length(&[route.0[0], route.0[1], halfway]);
+
+        assert_relative_eq!(walked, length / 2., max_relative = RELATIVE_ERROR);
+    }
+
+    #[test]
+    fn densify() {
+        let ruler = CheapRuler::WGS84();
+        let route = route();
+
+        let densified = route.densify(&ruler, &100.);
+
+        assert!(densified.0.len() > route.0.len());
+        assert_eq!(densified.0.first(), route.0.first());
+        assert_eq!(densified.0.last(), route.0.last());
+        assert!(route.0.iter().all(|vertex| densified.0.contains(vertex)));
+
+        for segment in densified.0.windows(2) {
+            assert!(ruler.distance(&segment[0], &segment[1]) <= 100. * (1. + RELATIVE_ERROR));
+        }
+
+        assert_relative_eq!(
+            densified.length(&ruler),
+            route.length(&ruler),
+            max_relative = RELATIVE_ERROR
+        );
+
+        assert_eq!(route.densify(&ruler, &10_000.), route);
+
+        for max_distance in [0., -100., f64::NAN, f64::INFINITY] {
+            assert_eq!(route.densify(&ruler, &max_distance), route);
+        }
+        assert_eq!(
+            Polyline::default().densify(&ruler, &100.),
+            Polyline::default()
+        );
+    }
+
+    #[test]
+    fn across_antimeridian() {
+        let ruler = CheapRuler::WGS84();
+        let line = [[179.9, 0.], [-179.9, 0.]];
+
+        let length = ruler.length(&line);
+        assert!(length < 25_000.);
+
+        let point = ruler.along(&line, &(length / 4.)).unwrap();
+        assert_relative_eq!(point[0], 179.95, epsilon = 1e-9);
+        assert_relative_eq!(point[1], 0.);
+
+        let densified = ruler.densify(&line, &5_000.);
+
+        assert_eq!(densified.len(), 6);
+        assert!(densified.iter().all(|vertex| vertex[0].abs() >= 179.9));
+
+        for segment in densified.windows(2) {
+            assert!(ruler.distance(&segment[0], &segment[1]) <= 5_000.);
+        }
+    }
+
+    #[test]
+    fn densify_into() {
+        let ruler = CheapRuler::WGS84();
+        let route = route();
+
+        // stale content from a previous, larger call is dropped
+        let mut out = vec![[0., 0.]; 64];
+
+        for max_distance in [100., 10_000., 0.] {
+            for line in [&route.0[..], &route.0[..1], &[]] {
+                ruler.densify_into(line, &max_distance, &mut out);
+
+                assert_eq!(out, ruler.densify(line, &max_distance));
+            }
+        }
+    }
+
+    #[test]
+    fn offset_line() {
+        let ruler = CheapRuler::WGS84();
+        let route = route();
+
+        for distance in [15., -15.] {
+            let offset = ruler.offset_line(&route.0, &distance);
+
+            assert_eq!(offset.len(), route.0.len());
+
+            // the ends are offset square to their segment, to the left for positive distances
+            for (vertex, [a, b]) in [(0, [0, 1]), (2, [1, 2])] {
+                assert_relative_eq!(
+                    ruler.distance(&route.0[vertex], &offset[vertex]),
+                    distance.abs(),
+                    max_relative = RELATIVE_ERROR
+                );
+
+                let side = ruler.bearing(&route.0[a], &route.0[b]) - distance.signum() * 90.;
+                assert_relative_eq!(
+                    ruler.bearing(&route.0[vertex], &offset[vertex]),
+                    side,
+                    epsilon = 0.5
+                );
+            }
+
+            // the corner is moved along the bisector, keeping both offset segments parallel
+            for [a, b] in [[0, 1], [1, 2]] {
+                assert_relative_eq!(
+                    ruler.bearing(&offset[a], &offset[b]),
+                    ruler.bearing(&route.0[a], &route.0[b]),
+                    epsilon = 0.5
+                );
+            }
+        }
+
+        // repeated vertices follow their neighbors, and a lone vertex stays in place
+        let repeated = [route.0[0], route.0[0], route.0[1]];
+        let offset = ruler.offset_line(&repeated, &15.);
+        assert_eq!(offset[0], offset[1]);
+
+        assert_eq!(ruler.offset_line(&route.0[..1], &15.), route.0[..1]);
+        assert!(ruler.offset_line(&[], &15.).is_empty());
+    }
+
+    #[test]
+    fn collect_at_distances() {
+        let ruler = CheapRuler::WGS84();
+        let route = route();
+
+        let points = ruler.collect_at_distances(&route.0, &[0., 250., 500., 5_000.]);
+
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[1], ruler.along(&route.0, &250.).unwrap());
+        assert_eq!(points[3], route.0[2]);
+
+        assert!(ruler.collect_at_distances(&[], &[0., 250.]).is_empty());
+    }
+
+    #[test]
+    fn circle() {
+        let ruler = CheapRuler::<f64>::WGS84();
+        let center = [-73.9857, 40.7484];
+
+        let ring = ruler.circle(&center, &500., 64);
+
+        assert_eq!(ring.len(), 65);
+        assert_eq!(ring.first(), ring.last());
+
+        for vertex in &ring {
+            assert_relative_eq!(
+                ruler.distance(&center, vertex),
+                500.,
+                max_relative = RELATIVE_ERROR
+            );
+        }
+
+        assert_eq!(ruler.circle(&center, &500., 0).len(), 4);
+    }
+
+    #[test]
+    fn grid() {
+        let ruler = CheapRuler::<f64>::WGS84();
+        let center = [-73.9857, 40.7484];
+
+        let grid = ruler.grid(&center, &250., 4, 3);
+
+        assert_eq!(grid.len(), 12);
+
+        // an odd number of rows puts the middle row on the center latitude
+        assert_relative_eq!(grid[4][1], center[1]);
+        assert!(grid[0][0] < center[0] && grid[3][0] > center[0]);
+
+        for j in 0..3 {
+            for i in 0..4 {
+                let point = grid[j * 4 + i];
+
+                if i + 1 < 4 {
+                    let east = grid[j * 4 + i + 1];
+                    assert_relative_eq!(
+                        ruler.distance(&point, &east),
+                        250.,
+                        max_relative = RELATIVE_ERROR
+                    );
+                    assert_relative_eq!(
+                        ruler.bearing(&point, &east),
+                        90.,
+                        max_relative = RELATIVE_ERROR
+                    );
+                }
+
+                if j + 1 < 3 {
+                    let north = grid[(j + 1) * 4 + i];
+                    assert_relative_eq!(
+                        ruler.distance(&point, &north),
+                        250.,
+                        max_relative = RELATIVE_ERROR
+                    );
+                }
+            }
+        }
+
+        assert!(ruler.grid(&center, &250., 0, 3).is_empty());
+        assert_eq!(ruler.grid(&center, &250., 1, 1), [center]);
+    }
+
+    #[test]
+    fn split_by_length() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // along Broadway from Union Square to Columbus Circle, about 3.7 km
+        let route = [
+            [-73.9903, 40.7359],
+            [-73.9897, 40.7411],
+            [-73.9877, 40.7480],
+            [-73.9855, 40.7580],
+            [-73.9819, 40.7681],
+        ];
+
+        let parts = ruler.split_by_length(&route, &1_000.);
+
+        assert_eq!(parts.len(), 4);
+
+        let (last, full) = parts.split_last().unwrap();
+
+        for part in full {
+            assert_relative_eq!(ruler.length(part), 1_000., max_relative = RELATIVE_ERROR);
+        }
+        assert!(ruler.length(last) < 1_000.);
+
+        // the parts are continuous and add up to the whole route, up to the scaling at
+        // the latitude of each split
+        for pair in parts.windows(2) {
+            assert_eq!(pair[0].last(), pair[1].first());
+        }
+        assert_eq!(parts[0][0], route[0]);
+        assert_eq!(last.last(), route.last());
+        assert_relative_eq!(
+            parts.iter().map(|part| ruler.length(part)).sum::<f64>(),
+            ruler.length(&route),
+            max_relative = 1e-5
+        );
+
+        // a part ending exactly on a vertex does not leave a degenerate one behind
+        let exact = ruler.split_by_length(&route[..2], &ruler.length(&route[..2]));
+        assert_eq!(exact, [route[..2].to_vec()]);
+
+        assert!(ruler.split_by_length(&route[..1], &1_000.).is_empty());
+        assert_eq!(ruler.split_by_length(&route, &0.), [route.to_vec()]);
+
+        // many short parts in f32 are placed from their index, not accumulated
+        let ruler = CheapRuler::<f32>::WGS84();
+        let segment = [[0., 0.], [0.01, 0.]];
+
+        let length = ruler.length(&segment) / 100_000.5;
+        let parts = ruler.split_by_length(&segment, &length);
+
+        assert_eq!(parts.len(), 100_001);
+        assert_eq!(parts.last().unwrap().last(), segment.last());
+    }
+
+    #[test]
+    fn clip_to_bbox() {
+        let ruler = CheapRuler::<f64>::WGS84();
+        let bbox = [0., 0., 10., 10.];
+
+        // passes through the box
+        assert_eq!(
+            ruler.clip_to_bbox(&[[-5., 5.], [15., 5.]], &bbox),
+            vec![vec![[0., 5.], [10., 5.]]]
+        );
+
+        // starts inside the box
+        assert_eq!(
+            ruler.clip_to_bbox(&[[5., 5.], [5., 8.], [5., 15.]], &bbox),
+            vec![vec![[5., 5.], [5., 8.], [5., 10.]]]
+        );
+
+        // leaves and re-enters the box
+        assert_eq!(
+            ruler.clip_to_bbox(&[[2., 5.], [2., 15.], [8., 15.], [8., 5.]], &bbox),
+            vec![vec![[2., 5.], [2., 10.]], vec![[8., 10.], [8., 5.]]]
+        );
+
+        // lies entirely outside the box
+        assert!(
+            ruler
+                .clip_to_bbox(&[[-5., -5.], [-5., 15.], [15., 15.]], &bbox)
+                .is_empty()
+        );
+        assert!(ruler.clip_to_bbox(&[], &bbox).is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+//! This module provides rhumb line measurements for `CheapRuler`.
+//!
+//! A rhumb line keeps a constant bearing, crossing every meridian at the same angle. Over
+//! the short distances the ruler is meant for, it is the straight line of the ruler's
+//! planar space when the latitude-dependent scaling is computed halfway in latitude,
+//! instead of at the origin. This makes the rhumb measurements symmetric: the distance
+//! is the same both ways and the bearing back is the opposite one.
+
+use crate::{CheapRuler, MaybeFromf32};
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Calculates the length of the rhumb line between two points.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: First point as `[longitude, latitude]` in degrees
+    /// - `destination`: Second point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// Distance along the rhumb line in the ruler units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let battery_park = [-74.0170, 40.7033];
+    /// let liberty_island = [-74.0445, 40.6892];
+    ///
+    /// assert_eq!(
+    ///     ruler.rhumb_distance(&battery_park, &liberty_island),
+    ///     ruler.rhumb_distance(&liberty_island, &battery_park)
+    /// );
+    /// ```
+    ///
+    /// See also: [`rhumb_destination`](Self::rhumb_destination)
+    pub fn rhumb_distance(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.distance_at_ref_lat(&mid_lat(origin, destination), origin, destination)
+    }
+
+    /// Calculates the destination point of a rhumb line, keeping a constant bearing.
+    ///
+    /// The latitude of the destination is first estimated with the scaling at the origin,
+    /// then the destination is computed with the scaling halfway to that latitude.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `bearing`: Constant direction of travel in degrees (0° = North, 90° = East)
+    /// - `distance`: Distance to travel in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// Destination point as `[longitude, latitude]` in degrees
+    ///
+    /// See also: [`rhumb_distance`](Self::rhumb_distance)
+    pub fn rhumb_destination(&self, origin: &[T; 2], bearing: &T, distance: &T) -> [T; 2] {
+        let estimate = self.destination(origin, bearing, distance);
+
+        self.destination_at_ref_lat(&mid_lat(origin, &estimate), origin, bearing, distance)
+    }
+}
+
+impl<T: Float + FloatConst + Debug + MaybeFromf32> CheapRuler<T> {
+    /// Calculates the constant bearing of the rhumb line from one point to another.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: Starting point as `[longitude, latitude]` in degrees
+    /// - `destination`: Target point as `[longitude, latitude]` in degrees
+    ///
+    /// # Returns
+    ///
+    /// The bearing in degrees (0° = North, 90° = East), the same all along the line
+    ///
+    /// See also: [`rhumb_distance`](Self::rhumb_distance)
+    pub fn rhumb_bearing(&self, origin: &[T; 2], destination: &[T; 2]) -> T {
+        self.bearing_at_ref_lat(&mid_lat(origin, destination), origin, destination)
+    }
+}
+
+/// The latitude halfway between two points.
+fn mid_lat<T: Float>(a: &[T; 2], b: &[T; 2]) -> T {
+    (a[1] + b[1]) / (T::one() + T::one())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rhumb() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        let battery_park = [-74.0170, 40.7033];
+        let bear_mountain = [-73.9885, 41.3126];
+
+        // symmetric both ways, unlike the plain measurements scaled at the origin
+        assert_eq!(
+            ruler.rhumb_distance(&battery_park, &bear_mountain),
+            ruler.rhumb_distance(&bear_mountain, &battery_park)
+        );
+        assert_relative_eq!(
+            ruler.rhumb_bearing(&battery_park, &bear_mountain),
+            ruler.rhumb_bearing(&bear_mountain, &battery_park) + 180.,
+            epsilon = 1e-9
+        );
+
+        // going back and forth along a rhumb line, with exact bearings
+        let ruler = ruler.with_bearing_tolerance(&0.);
+
+        let bearing = ruler.rhumb_bearing(&battery_park, &bear_mountain);
+        let distance = ruler.rhumb_distance(&battery_park, &bear_mountain);
+
+        let destination = ruler.rhumb_destination(&battery_park, &bearing, &distance);
+
+        assert_relative_eq!(destination[0], bear_mountain[0], epsilon = 1e-6);
+        assert_relative_eq!(destination[1], bear_mountain[1], epsilon = 1e-6);
+    }
+}
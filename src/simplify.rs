@@ -0,0 +1,499 @@
+//! This module provides polyline simplification algorithms for `CheapRuler`.
+//!
+//! Simplification removes vertices that do not contribute significantly to the shape
+//! of a polyline. Thresholds are expressed in the ruler units, using the ruler's
+//! latitude-dependent scaling, rather than in degrees.
+//!
+//! These methods return newly allocated vectors, or refill caller provided ones, and are
+//! available when the `alloc` feature flag is enabled.
+
+use crate::CheapRuler;
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use num_traits::{Float, FloatConst};
+
+/// A vertex candidate for removal, ordered so that the smallest area pops first.
+struct Candidate<T> {
+    area: T,
+    index: usize,
+}
+
+impl<T: Float> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for Candidate<T> {}
+
+impl<T: Float> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<T: Float + FloatConst + Debug> CheapRuler<T> {
+    /// Calculates the area of the triangle formed by three points.
+    ///
+    /// Uses the latitude-dependent scaling computed at the middle vertex, and longitude
+    /// differences following the antimeridian mode.
+    fn triangle_area(&self, a: &[T; 2], b: &[T; 2], c: &[T; 2]) -> T {
+        let [kx, ky] = self.coefs(b);
+
+        let (ax, ay) = (self.delta_lon(&b[0], &a[0]) * kx, (a[1] - b[1]) * ky);
+        let (cx, cy) = (self.delta_lon(&b[0], &c[0]) * kx, (c[1] - b[1]) * ky);
+
+        (ax * cy - ay * cx).abs() / (T::one() + T::one())
+    }
+
+    /// Simplifies a polyline using the Visvalingam–Whyatt algorithm.
+    ///
+    /// Repeatedly removes the vertex whose triangle with its two neighbors has the
+    /// smallest area (its effective area), until every remaining vertex has an effective
+    /// area of at least `min_area`. The first and last vertices are always kept.
+    ///
+    /// Compared to distance based simplification, this often gives visually better
+    /// results for cartographic generalization.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `min_area`: Minimum effective area of a vertex to be kept, in square ruler units
+    ///
+    /// # Returns
+    ///
+    /// The simplified polyline vertices as `[longitude, latitude]` in degrees
+    pub fn simplify_area(&self, points: &[[T; 2]], min_area: &T) -> Vec<[T; 2]> {
+        let n = points.len();
+
+        if n < 3 {
+            return points.to_vec();
+        }
+
+        let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+        let mut areas = Vec::with_capacity(n);
+        let mut heap = BinaryHeap::with_capacity(n);
+
+        areas.push(T::infinity());
+        for i in 1..(n - 1) {
+            let area = self.triangle_area(&points[i - 1], &points[i], &points[i + 1]);
+            areas.push(area);
+            heap.push(Candidate { area, index: i });
+        }
+        areas.push(T::infinity());
+
+        let mut removed = alloc::vec![false; n];
+
+        while let Some(Candidate { area, index }) = heap.pop() {
+            // skip stale entries whose area changed after a neighbor was removed
+            if removed[index] || area != areas[index] {
+                continue;
+            }
+
+            if area >= *min_area {
+                break;
+            }
+
+            removed[index] = true;
+
+            let (before, after) = (prev[index], next[index]);
+            next[before] = after;
+            prev[after] = before;
+
+            for neighbor in [before, after] {
+                if neighbor == 0 || neighbor == n - 1 {
+                    continue;
+                }
+
+                // effective areas never decrease, so removals happen in a consistent order
+                let updated = self
+                    .triangle_area(
+                        &points[prev[neighbor]],
+                        &points[neighbor],
+                        &points[next[neighbor]],
+                    )
+                    .max(area);
+
+                areas[neighbor] = updated;
+                heap.push(Candidate {
+                    area: updated,
+                    index: neighbor,
+                });
+            }
+        }
+
+        points
+            .iter()
+            .zip(removed)
+            .filter(|(_, removed)| !removed)
+            .map(|(point, _)| *point)
+            .collect()
+    }
+
+    /// Simplifies a polyline using the Douglas–Peucker algorithm.
+    ///
+    /// Recursively keeps the vertex farthest from the segment joining the ends of each
+    /// section, as measured by [`cross_track_distance`](Self::cross_track_distance),
+    /// while that distance exceeds the tolerance. The first and last vertices are
+    /// always kept.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `tolerance`: Maximum distance of a removed vertex to the simplified line, in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// The simplified polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// See also: [`simplify_area`](Self::simplify_area)
+    pub fn simplify(&self, points: &[[T; 2]], tolerance: &T) -> Vec<[T; 2]> {
+        let mut simplified = Vec::new();
+        self.simplify_into(points, tolerance, &mut simplified, &mut Vec::new());
+
+        simplified
+    }
+
+    /// Simplifies a polyline using the Douglas–Peucker algorithm into reused buffers.
+    ///
+    /// Same as [`simplify`](Self::simplify), but the output and the stack of sections
+    /// pending a split are caller provided, so buffers reused across calls, e.g. over
+    /// millions of small geometries, only grow when a larger polyline comes.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `tolerance`: Maximum distance of a removed vertex to the simplified line, in the ruler units
+    /// - `out`: Buffer receiving the simplified polyline vertices, its previous content is dropped
+    /// - `stack`: Scratch buffer for the sections pending a split, its content is irrelevant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_ruler::CheapRuler;
+    ///
+    /// let ruler = CheapRuler::<f64>::WGS84();
+    ///
+    /// let streets = [
+    ///     [[-73.9900, 40.7400], [-73.9880, 40.74001], [-73.9860, 40.7400]],
+    ///     [[-73.9860, 40.7400], [-73.9860, 40.7450], [-73.9810, 40.7450]],
+    /// ];
+    ///
+    /// let (mut simplified, mut stack) = (Vec::new(), Vec::new());
+    ///
+    /// for street in &streets {
+    ///     ruler.simplify_into(street, &5., &mut simplified, &mut stack);
+    ///
+    ///     assert_eq!(simplified, ruler.simplify(street, &5.));
+    /// }
+    /// ```
+    pub fn simplify_into(
+        &self,
+        points: &[[T; 2]],
+        tolerance: &T,
+        out: &mut Vec<[T; 2]>,
+        stack: &mut Vec<(usize, usize)>,
+    ) {
+        out.clear();
+
+        let n = points.len();
+
+        if n < 3 {
+            out.extend_from_slice(points);
+            return;
+        }
+
+        self.douglas_peucker(points, tolerance, stack, |index| out.push(points[index]));
+    }
+
+    /// Runs the Douglas–Peucker algorithm on a polyline of at least 2 vertices, calling
+    /// `keep` with the index of every kept vertex, in order.
+    fn douglas_peucker(
+        &self,
+        points: &[[T; 2]],
+        tolerance: &T,
+        stack: &mut Vec<(usize, usize)>,
+        mut keep: impl FnMut(usize),
+    ) {
+        let n = points.len();
+
+        stack.clear();
+        stack.push((0, n - 1));
+
+        // sections are split depth first, left before right, so the vertices kept at
+        // the start of each section that is not split any further come in order
+        while let Some((first, last)) = stack.pop() {
+            match self.farthest(points, first, last) {
+                Some((index, distance)) if distance > *tolerance => {
+                    stack.push((index, last));
+                    stack.push((first, index));
+                }
+                _ => keep(first),
+            }
+        }
+
+        keep(n - 1);
+    }
+
+    /// Finds the vertex between two others farthest from the segment joining them.
+    ///
+    /// # Returns
+    ///
+    /// The index of the farthest vertex and its distance in the ruler units, or `None`
+    /// if the two vertices are adjacent
+    fn farthest(&self, points: &[[T; 2]], first: usize, last: usize) -> Option<(usize, T)> {
+        (first + 1..last)
+            .map(|i| {
+                let distance = self.cross_track_distance(&points[i], &points[first], &points[last]);
+                (i, distance)
+            })
+            .fold(None, |farthest, (i, distance)| match farthest {
+                Some((_, max)) if max >= distance => farthest,
+                _ => Some((i, distance)),
+            })
+    }
+
+    /// Simplifies a polyline using the Douglas–Peucker algorithm, without introducing
+    /// self-intersections.
+    ///
+    /// Plain [`simplify`](Self::simplify) may cut a shortcut across a nearby part of the
+    /// polyline when removing a sharp feature. Here, after simplifying, every pair of
+    /// non-adjacent simplified segments crossing each other is split again at its
+    /// farthest vertex, whatever the tolerance, until no such crossing remains. Crossings
+    /// already present in the input polyline are kept.
+    ///
+    /// Every round of splits checks all the pairs of simplified segments, so the worst
+    /// case is cubic in the number of vertices, for inputs where most vertices have to be
+    /// restored one round at a time. It is meant for boundaries of up to a few thousand
+    /// vertices, rather than for whole datasets at once.
+    ///
+    /// # Parameters
+    ///
+    /// - `points`: The polyline vertices as `[longitude, latitude]` in degrees
+    /// - `tolerance`: Maximum distance of a removed vertex to the simplified line, in the ruler units
+    ///
+    /// # Returns
+    ///
+    /// The simplified polyline vertices as `[longitude, latitude]` in degrees
+    ///
+    /// See also: [`simplify`](Self::simplify)
+    pub fn simplify_preserve_topology(&self, points: &[[T; 2]], tolerance: &T) -> Vec<[T; 2]> {
+        let n = points.len();
+
+        if n < 3 {
+            return points.to_vec();
+        }
+
+        let mut kept = alloc::vec![false; n];
+        self.douglas_peucker(points, tolerance, &mut Vec::new(), |index| {
+            kept[index] = true
+        });
+
+        // split the sections crossing another one until none is left to split
+        loop {
+            let indices: Vec<usize> = (0..n).filter(|i| kept[*i]).collect();
+            let sections: Vec<(usize, usize)> = indices.windows(2).map(|w| (w[0], w[1])).collect();
+
+            let mut split = false;
+
+            for (i, &(a, b)) in sections.iter().enumerate() {
+                for &(c, d) in sections.iter().skip(i + 2) {
+                    // the ends of a closed ring meet without crossing
+                    if a == 0 && d == n - 1 && points[a] == points[d] {
+                        continue;
+                    }
+
+                    if !self.segments_cross(&points[a], &points[b], &points[c], &points[d]) {
+                        continue;
+                    }
+
+                    for (first, last) in [(a, b), (c, d)] {
+                        if let Some((index, _)) = self.farthest(points, first, last) {
+                            split |= !kept[index];
+                            kept[index] = true;
+                        }
+                    }
+                }
+            }
+
+            if !split {
+                break;
+            }
+        }
+
+        points
+            .iter()
+            .zip(kept)
+            .filter(|(_, kept)| *kept)
+            .map(|(point, _)| *point)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn simplify_area() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // a straight street with small GPS jitter, then a sharp corner
+        let points = [
+            [-73.9900, 40.7400],
+            [-73.9890, 40.74001],
+            [-73.9880, 40.73999],
+            [-73.9870, 40.74001],
+            [-73.9860, 40.7400],
+            [-73.9860, 40.7450],
+            [-73.9860, 40.74501],
+            [-73.9860, 40.7500],
+        ];
+
+        let simplified = ruler.simplify_area(&points, &1_000.);
+
+        assert_eq!(
+            simplified,
+            [
+                [-73.9900, 40.7400],
+                [-73.9860, 40.7400],
+                [-73.9860, 40.7500]
+            ]
+        );
+
+        assert_eq!(ruler.simplify_area(&points, &0.), points);
+        assert_eq!(ruler.simplify_area(&points[..2], &1_000.), points[..2]);
+
+        // a straight line across the antimeridian, its middle vertex has no area
+        let across = [[179.99, 10.], [-179.99, 10.], [-179.97, 10.]];
+
+        assert_eq!(
+            ruler.simplify_area(&across, &1.),
+            [[179.99, 10.], [-179.97, 10.]]
+        );
+    }
+
+    #[test]
+    fn simplify() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // a straight street with small GPS jitter, then a sharp corner
+        let points = [
+            [-73.9900, 40.7400],
+            [-73.9890, 40.74001],
+            [-73.9880, 40.73999],
+            [-73.9870, 40.74001],
+            [-73.9860, 40.7400],
+            [-73.9860, 40.7450],
+            [-73.9860, 40.74501],
+            [-73.9860, 40.7500],
+        ];
+
+        assert_eq!(
+            ruler.simplify(&points, &5.),
+            [
+                [-73.9900, 40.7400],
+                [-73.9860, 40.7400],
+                [-73.9860, 40.7500]
+            ]
+        );
+
+        // only the vertices on the meridian segment are within a tiny tolerance
+        let expected = [&points[..5], &points[7..]].concat();
+        assert_eq!(ruler.simplify(&points, &0.1), expected);
+
+        assert_eq!(ruler.simplify(&points[..2], &5.), points[..2]);
+    }
+
+    #[test]
+    fn simplify_preserve_topology() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // out and back along a street, the way out dips south around an obstacle and the
+        // way back passes north of the dip, closer to the street than the dip is deep
+        let at = |x: f64, y: f64| [-73.99 + x * 0.001, 40.74 + y * 0.001];
+        let points = [
+            at(0., 0.),
+            at(5., -0.3),
+            at(10., 0.),
+            at(10., 0.25),
+            at(10., 0.5),
+            at(5., -0.1),
+            at(0., 0.5),
+        ];
+
+        let self_crossing = |line: &[[f64; 2]]| {
+            let segments: Vec<_> = line.windows(2).collect();
+
+            (0..segments.len()).any(|i| {
+                segments.iter().skip(i + 2).any(|other| {
+                    ruler.segments_cross(&segments[i][0], &segments[i][1], &other[0], &other[1])
+                })
+            })
+        };
+
+        assert!(!self_crossing(&points));
+
+        // dropping the dip cuts across the way back
+        let plain = ruler.simplify(&points, &40.);
+        assert!(!plain.contains(&points[1]));
+        assert!(self_crossing(&plain));
+
+        let preserved = ruler.simplify_preserve_topology(&points, &40.);
+        assert!(!self_crossing(&preserved));
+        assert_eq!(preserved, [&points[..3], &points[4..]].concat());
+
+        // without a crossing to avoid, both simplify the same
+        assert_eq!(
+            ruler.simplify_preserve_topology(&points[..3], &40.),
+            ruler.simplify(&points[..3], &40.)
+        );
+        assert_eq!(
+            ruler.simplify_preserve_topology(&points[..2], &40.),
+            points[..2]
+        );
+    }
+
+    #[test]
+    fn simplify_into() {
+        let ruler = CheapRuler::<f64>::WGS84();
+
+        // a straight street with small GPS jitter, then a sharp corner
+        let points = [
+            [-73.9900, 40.7400],
+            [-73.9890, 40.74001],
+            [-73.9880, 40.73999],
+            [-73.9870, 40.74001],
+            [-73.9860, 40.7400],
+            [-73.9860, 40.7450],
+            [-73.9860, 40.74501],
+            [-73.9860, 40.7500],
+        ];
+
+        // stale content from a previous, larger call is dropped
+        let mut out = vec![[0., 0.]; 16];
+        let mut stack = vec![(3, 1); 16];
+
+        for tolerance in [5., 0.1, 0.] {
+            for line in [&points[..], &points[..5], &points[..2], &[]] {
+                ruler.simplify_into(line, &tolerance, &mut out, &mut stack);
+
+                assert_eq!(out, ruler.simplify(line, &tolerance));
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! # Units Module
+//!
+//! This module defines the distance units a `CheapRuler` can measure in.
+
+use num_traits::Float;
+
+/// Distance units used by a `CheapRuler` for both its inputs and outputs.
+///
+/// A ruler configured with a unit returns distances in that unit, and interprets every
+/// distance argument (e.g. for `destination` or `offset`) in that same unit.
+///
+/// # Examples
+///
+/// ```
+/// use geo_ruler::{CheapRuler, Units};
+///
+/// let ruler = CheapRuler::<f64>::WGS84().with_units(Units::Miles);
+/// let empire_state = [-73.9857, 40.7484];
+/// let flatiron = [-73.9897, 40.7411];
+///
+/// let distance = ruler.distance(&empire_state, &flatiron); // Result in miles
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Units {
+    Kilometers,
+    #[default]
+    Meters,
+    Miles,
+    NauticalMiles,
+    Yards,
+    Feet,
+    Inches,
+}
+
+impl Units {
+    /// Returns the length of one unit in meters.
+    pub fn meters<T: Float>(&self) -> T {
+        let meters = match self {
+            Units::Kilometers => 1000.,
+            Units::Meters => 1.,
+            Units::Miles => 1609.344,
+            Units::NauticalMiles => 1852.,
+            Units::Yards => 0.9144,
+            Units::Feet => 0.3048,
+            Units::Inches => 0.0254,
+        };
+
+        T::from(meters).unwrap()
+    }
+}
@@ -35,6 +35,14 @@
 //! // Find destination point 100m away at 45 degree bearing
 //! const destination = empireState.destination(45.0, 100.0);
 //! console.log(`Destination: ${destination.x}, ${destination.y}`);
+//!
+//! // Get both bearing and distance at once, e.g. to draw a labeled arrow
+//! const vector = empireState.vector_to(flatiron);
+//! console.log(`${vector.distance.toFixed(1)} meters at ${vector.bearing.toFixed(1)} degrees`);
+//!
+//! // Build GeoJSON geometries to hand to a map library
+//! const ruler = new Ruler();
+//! const area = JSON.parse(ruler.circle_geojson(-73.9857, 40.7484, 500.0, 64));
 //! ```
 //!
 //! # Feature Flag
@@ -46,9 +54,69 @@
 //! All coordinates use the WGS84 datum with longitude/latitude in decimal degrees.
 //! The `x` field represents longitude and the `y` field represents latitude.
 
-use crate::CheapRuler;
+use crate::constants::{WGS84_E2, WGS84_RE};
+use crate::{AntimeridianMode, CheapRuler, Units};
+use alloc::string::String;
+use core::fmt::Write;
 use wasm_bindgen::prelude::*;
 
+/// WGS84 ruler shared by all the bindings, so it is not rebuilt on every call.
+///
+/// With `memoize` the ruler is not `Sync`, so it is only shared on a WebAssembly instance
+/// without threads, see [`with_ruler`].
+#[cfg(any(
+    not(feature = "memoize"),
+    all(target_arch = "wasm32", not(target_feature = "atomics"))
+))]
+static RULER: SharedRuler = SharedRuler(wgs84());
+
+/// A ruler that can be shared across the bindings.
+#[cfg(any(
+    not(feature = "memoize"),
+    all(target_arch = "wasm32", not(target_feature = "atomics"))
+))]
+struct SharedRuler(CheapRuler<f32>);
+
+// SAFETY: the memoization cache is a `Cell`, a WebAssembly instance without the atomics
+// feature has a single thread, so the cache is never accessed concurrently
+#[cfg(all(
+    feature = "memoize",
+    target_arch = "wasm32",
+    not(target_feature = "atomics")
+))]
+unsafe impl Sync for SharedRuler {}
+
+/// Builds the WGS84 ruler in meters used by the bindings.
+const fn wgs84() -> CheapRuler<f32> {
+    CheapRuler {
+        re: WGS84_RE,
+        e2: WGS84_E2,
+        units: Units::Meters,
+        antimeridian: AntimeridianMode::Wrap,
+        atan2: None,
+        #[cfg(feature = "memoize")]
+        cache: core::cell::Cell::new(None),
+    }
+}
+
+/// Measures with the WGS84 ruler shared by all the bindings.
+///
+/// Where it cannot be shared, i.e. with `memoize` outside of a single threaded WebAssembly
+/// instance, e.g. in native tests of the bindings, a ruler is built for the measurement.
+fn with_ruler<R>(measure: impl FnOnce(&CheapRuler<f32>) -> R) -> R {
+    #[cfg(any(
+        not(feature = "memoize"),
+        all(target_arch = "wasm32", not(target_feature = "atomics"))
+    ))]
+    return measure(&RULER.0);
+
+    #[cfg(not(any(
+        not(feature = "memoize"),
+        all(target_arch = "wasm32", not(target_feature = "atomics"))
+    )))]
+    measure(&wgs84())
+}
+
 /// A geographic coordinate point with longitude and latitude.
 ///
 /// This struct represents a point on Earth's surface using the WGS84 coordinate system.
@@ -78,6 +146,25 @@ pub struct Coords {
     pub y: f32,
 }
 
+/// The bearing and distance from one coordinate point to another.
+///
+/// # Fields
+///
+/// - `bearing`: Initial bearing in degrees, clockwise from north
+/// - `distance`: Distance in meters
+///
+/// # Examples
+///
+/// ```javascript
+/// const vector = new Coords(-73.9857, 40.7484).vector_to(new Coords(-73.9897, 40.7411));
+/// console.log(`${vector.distance.toFixed(1)} meters at ${vector.bearing.toFixed(1)} degrees`);
+/// ```
+#[wasm_bindgen]
+pub struct Vector {
+    pub bearing: f32,
+    pub distance: f32,
+}
+
 #[wasm_bindgen]
 impl Coords {
     /// Creates a new coordinate point with the given longitude and latitude.
@@ -102,6 +189,28 @@ impl Coords {
         Coords { x, y }
     }
 
+    /// Creates a new coordinate point from a `[longitude, latitude]` array.
+    ///
+    /// # Parameters
+    ///
+    /// - `arr`: Longitude and latitude in decimal degrees, e.g. a `Float32Array` of length 2
+    ///
+    /// # Returns
+    ///
+    /// A new `Coords` instance, or `undefined` if the array does not have exactly two elements.
+    ///
+    /// # Examples
+    ///
+    /// ```javascript
+    /// const empireState = Coords.from_array(new Float32Array([-73.9857, 40.7484]));
+    /// ```
+    pub fn from_array(arr: &[f32]) -> Option<Coords> {
+        match arr {
+            [x, y] => Some(Coords::new(*x, *y)),
+            _ => None,
+        }
+    }
+
     /// Calculates the distance between this coordinate and another coordinate.
     ///
     /// Uses the Cheap Ruler algorithm to compute an approximate distance between
@@ -130,7 +239,7 @@ impl Coords {
     /// console.log(`Distance: ${distance.toFixed(1)} meters`);
     /// ```
     pub fn distance(&self, destination: &Coords) -> f32 {
-        CheapRuler::WGS84().distance(&[self.x, self.y], &[destination.x, destination.y])
+        with_ruler(|ruler| ruler.distance(&[self.x, self.y], &[destination.x, destination.y]))
     }
 
     /// Calculates the bearing (direction) from this coordinate to another coordinate.
@@ -159,7 +268,7 @@ impl Coords {
     /// console.log(`Bearing: ${bearing.toFixed(1)} degrees`);
     /// ```
     pub fn bearing(&self, destination: &Coords) -> f32 {
-        CheapRuler::WGS84().bearing(&[self.x, self.y], &[destination.x, destination.y])
+        with_ruler(|ruler| ruler.bearing(&[self.x, self.y], &[destination.x, destination.y]))
     }
 
     /// Calculates the destination coordinate when traveling from this point
@@ -187,7 +296,276 @@ impl Coords {
     /// console.log(`Destination: ${destination.x}, ${destination.y}`);
     /// ```
     pub fn destination(&self, bearing: f32, distance: f32) -> Coords {
-        let [x, y] = CheapRuler::WGS84().destination(&[self.x, self.y], &bearing, &distance);
+        with_ruler(|ruler| ruler.destination(&[self.x, self.y], &bearing, &distance)).into()
+    }
+
+    /// Calculates both the bearing and the distance from this coordinate to another coordinate.
+    ///
+    /// This is equivalent to calling `bearing` and `distance`, but computes the shared
+    /// scaling factors and coordinate deltas only once.
+    ///
+    /// # Parameters
+    ///
+    /// - `destination`: The destination coordinate point
+    ///
+    /// # Returns
+    ///
+    /// A `Vector` with the bearing in degrees and the distance in meters.
+    ///
+    /// # Examples
+    ///
+    /// ```javascript
+    /// const start = new Coords(-73.9857, 40.7484); // Empire State Building
+    /// const end = new Coords(-73.9897, 40.7411); // Flatiron Building
+    /// const { bearing, distance } = start.vector_to(end);
+    /// ```
+    pub fn vector_to(&self, destination: &Coords) -> Vector {
+        let (bearing, distance) = with_ruler(|ruler| {
+            ruler.bearing_and_distance(&[self.x, self.y], &[destination.x, destination.y])
+        });
+
+        Vector { bearing, distance }
+    }
+}
+
+/// A WGS84 ruler building geometries for map libraries.
+///
+/// Geometries are returned as GeoJSON strings, with coordinates as `[longitude, latitude]`
+/// in decimal degrees, ready to be parsed with `JSON.parse`.
+///
+/// # Examples
+///
+/// ```javascript
+/// const ruler = new Ruler();
+///
+/// // Everything within 500 meters of the Empire State Building
+/// const area = JSON.parse(ruler.circle_geojson(-73.9857, 40.7484, 500.0, 64));
+/// map.addSource('area', { type: 'geojson', data: area });
+/// ```
+#[wasm_bindgen]
+pub struct Ruler(CheapRuler<f32>);
+
+#[wasm_bindgen]
+impl Ruler {
+    /// Creates a new ruler for the WGS84 ellipsoid, measuring in meters.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Ruler {
+        Ruler(CheapRuler::WGS84())
+    }
+
+    /// Builds a circle around a center point as a GeoJSON Polygon.
+    ///
+    /// # Parameters
+    ///
+    /// - `center_lon`: Longitude of the center in decimal degrees
+    /// - `center_lat`: Latitude of the center in decimal degrees
+    /// - `radius`: Radius of the circle in meters
+    /// - `steps`: Number of distinct vertices, at least 3 are used
+    ///
+    /// # Returns
+    ///
+    /// A GeoJSON Polygon geometry with a closed, counter-clockwise exterior ring.
+    ///
+    /// # Examples
+    ///
+    /// ```javascript
+    /// const ruler = new Ruler();
+    /// const area = JSON.parse(ruler.circle_geojson(-73.9857, 40.7484, 500.0, 64));
+    /// ```
+    pub fn circle_geojson(
+        &self,
+        center_lon: f32,
+        center_lat: f32,
+        radius: f32,
+        steps: usize,
+    ) -> String {
+        let mut ring = self.0.circle(&[center_lon, center_lat], &radius, steps);
+
+        // GeoJSON exterior rings are counter-clockwise
+        ring.reverse();
+
+        polygon_geojson(&ring)
+    }
+
+    /// Expands a bounding box by a distance as a GeoJSON Polygon.
+    ///
+    /// The buffered box is conservative, every point within `buffer` meters of the
+    /// original box lies inside it.
+    ///
+    /// # Parameters
+    ///
+    /// - `min_lon`, `min_lat`: South-west corner of the box in decimal degrees
+    /// - `max_lon`, `max_lat`: North-east corner of the box in decimal degrees
+    /// - `buffer`: Distance to expand the box by, in meters
+    ///
+    /// # Returns
+    ///
+    /// A GeoJSON Polygon geometry with a closed, counter-clockwise exterior ring.
+    ///
+    /// # Examples
+    ///
+    /// ```javascript
+    /// const ruler = new Ruler();
+    /// const search = JSON.parse(ruler.buffer_bbox_geojson(-73.99, 40.74, -73.98, 40.75, 200.0));
+    /// ```
+    pub fn buffer_bbox_geojson(
+        &self,
+        min_lon: f32,
+        min_lat: f32,
+        max_lon: f32,
+        max_lat: f32,
+        buffer: f32,
+    ) -> String {
+        let [w, s, e, n] = self
+            .0
+            .buffer_bbox(&[min_lon, min_lat, max_lon, max_lat], &buffer);
+
+        polygon_geojson(&[[w, s], [e, s], [e, n], [w, n], [w, s]])
+    }
+}
+
+impl Default for Ruler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Formats a closed ring as a GeoJSON Polygon geometry.
+fn polygon_geojson(ring: &[[f32; 2]]) -> String {
+    let mut json = String::from(r#"{"type":"Polygon","coordinates":[["#);
+
+    for (i, [lon, lat]) in ring.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        // writing to a string cannot fail
+        let _ = write!(json, "[{lon},{lat}]");
+    }
+
+    json.push_str("]]}");
+
+    json
+}
+
+impl From<[f32; 2]> for Coords {
+    fn from([x, y]: [f32; 2]) -> Self {
         Coords { x, y }
     }
 }
+
+impl From<Coords> for [f32; 2] {
+    fn from(coords: Coords) -> Self {
+        [coords.x, coords.y]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn cached_ruler() {
+        let fresh = CheapRuler::<f32>::WGS84();
+
+        let empire_state = [-73.9857, 40.7484];
+        let flatiron = [-73.9897, 40.7411];
+
+        with_ruler(|ruler| {
+            assert_eq!(
+                ruler.distance(&empire_state, &flatiron),
+                fresh.distance(&empire_state, &flatiron)
+            );
+            assert_eq!(
+                ruler.bearing(&empire_state, &flatiron),
+                fresh.bearing(&empire_state, &flatiron)
+            );
+            assert_eq!(
+                ruler.destination(&empire_state, &45., &100.),
+                fresh.destination(&empire_state, &45., &100.)
+            );
+        });
+    }
+
+    #[test]
+    fn vector_to() {
+        let empire_state = Coords::new(-73.9857, 40.7484);
+        let flatiron = Coords::new(-73.9897, 40.7411);
+
+        let vector = empire_state.vector_to(&flatiron);
+
+        assert_eq!(vector.bearing, empire_state.bearing(&flatiron));
+        assert_eq!(vector.distance, empire_state.distance(&flatiron));
+    }
+
+    #[test]
+    fn array_conversions() {
+        let empire_state = [-73.9857, 40.7484];
+
+        let coords = Coords::from(empire_state);
+        assert_eq!((coords.x, coords.y), (-73.9857, 40.7484));
+
+        let array: [f32; 2] = coords.into();
+        assert_eq!(array, empire_state);
+
+        let coords = Coords::from_array(&empire_state).unwrap();
+        assert_eq!((coords.x, coords.y), (-73.9857, 40.7484));
+
+        assert!(Coords::from_array(&[-73.9857]).is_none());
+        assert!(Coords::from_array(&[-73.9857, 40.7484, 0.]).is_none());
+    }
+
+    /// Parses a GeoJSON Polygon and returns its exterior ring.
+    fn exterior_ring(geojson: &str) -> Vec<[f32; 2]> {
+        let geometry: serde_json::Value = serde_json::from_str(geojson).unwrap();
+
+        assert_eq!(geometry["type"], "Polygon");
+
+        let rings = geometry["coordinates"].as_array().unwrap();
+        assert_eq!(rings.len(), 1);
+
+        rings[0]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|position| {
+                let position = position.as_array().unwrap();
+                assert_eq!(position.len(), 2);
+                [
+                    position[0].as_f64().unwrap() as f32,
+                    position[1].as_f64().unwrap() as f32,
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn circle_geojson() {
+        let ruler = Ruler::new();
+        let center = [-73.9857, 40.7484];
+
+        let ring = exterior_ring(&ruler.circle_geojson(center[0], center[1], 500., 64));
+
+        assert_eq!(ring.len(), 65);
+        assert_eq!(ring.first(), ring.last());
+
+        for position in &ring {
+            assert!((with_ruler(|ruler| ruler.distance(&center, position)) - 500.).abs() < 1.);
+        }
+    }
+
+    #[test]
+    fn buffer_bbox_geojson() {
+        let ruler = Ruler::new();
+
+        let ring = exterior_ring(&ruler.buffer_bbox_geojson(-73.99, 40.74, -73.98, 40.75, 200.));
+
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+
+        let [w, s, e, n] =
+            with_ruler(|ruler| ruler.buffer_bbox(&[-73.99, 40.74, -73.98, 40.75], &200.));
+        assert_eq!(ring[0], [w, s]);
+        assert_eq!(ring[2], [e, n]);
+    }
+}
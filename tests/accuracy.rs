@@ -0,0 +1,75 @@
+//! Accuracy envelope of the `geo_ruler` crate against the Geodesic reference model.
+//!
+//! Sweeps distances from 1 m to 1000 km, in all directions and at several latitudes,
+//! tabulating the worst relative error of `RulerMeasure` distances compared to
+//! `geo::Geodesic` (Karney, 2013).
+//!
+//! Since the latitude-dependent scaling is computed at the origin, the error grows
+//! roughly linearly with the distance and with the latitude. The supported range
+//! is pinned by the thresholds below, run with `--nocapture` to print the table.
+
+use geo::{Destination, Distance, Geodesic, point};
+use geo_ruler::geo::RulerMeasure;
+
+/// Maximum relative error for distances up to 10 km, at latitudes up to 60°.
+const CITY_SCALE_ERROR: f64 = 0.001;
+
+/// Maximum relative error for distances up to 100 km, at latitudes up to 60°.
+const REGIONAL_SCALE_ERROR: f64 = 0.006;
+
+/// Returns the largest relative distance error over a full sweep of bearings.
+fn worst_error(latitude: f64, distance: f64) -> f64 {
+    let ruler = RulerMeasure::WGS84();
+    let origin = point!(x: -73.9857, y: latitude);
+
+    (0..360)
+        .step_by(5)
+        .map(|bearing| {
+            let target = Geodesic.destination(origin, bearing as f64, distance);
+            (ruler.distance(origin, target) - distance).abs() / distance
+        })
+        .fold(0., f64::max)
+}
+
+#[test]
+fn accuracy_envelope() {
+    let latitudes = [0., 20., 40.7484, 60., 75.];
+    let distances = [
+        1., 10., 100., 1_000., 10_000., 50_000., 100_000., 200_000., 500_000., 1_000_000.,
+    ];
+
+    println!("{:>10} {:>12} {:>12}", "latitude", "distance", "error");
+
+    for latitude in latitudes {
+        for distance in distances {
+            let error = worst_error(latitude, distance);
+
+            let threshold = match distance {
+                _ if latitude > 60. => None,
+                d if d <= 10_000. => Some(CITY_SCALE_ERROR),
+                d if d <= 100_000. => Some(REGIONAL_SCALE_ERROR),
+                _ => None,
+            };
+
+            let flag = match threshold {
+                Some(threshold) if error > threshold => "EXCEEDS",
+                Some(_) => "",
+                None if error > REGIONAL_SCALE_ERROR => "unsupported",
+                None => "",
+            };
+
+            println!(
+                "{latitude:>10.2} {:>10.0} m {:>10.4} % {flag}",
+                distance,
+                error * 100.
+            );
+
+            if let Some(threshold) = threshold {
+                assert!(
+                    error < threshold,
+                    "relative error {error} exceeds {threshold} at latitude {latitude} and distance {distance} m"
+                );
+            }
+        }
+    }
+}
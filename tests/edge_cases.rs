@@ -0,0 +1,214 @@
+//! Behavior of the collection-taking methods on empty and tiny inputs.
+//!
+//! Every method is exercised with 0, 1, 2 and 3 points, checking that it never panics,
+//! never returns NaN for valid coordinates, and follows the convention documented in
+//! the crate docs.
+
+use geo::{LineString, MultiLineString, Point, Polygon, Rect, coord};
+use geo_ruler::geo::{RulerMeasure, RulerPolygon};
+use geo_ruler::{CheapRuler, Polyline};
+
+/// Three points around Midtown Manhattan, not collinear.
+const POINTS: [[f64; 2]; 3] = [
+    [-73.9897, 40.7411], // Flatiron Building
+    [-73.9857, 40.7484], // Empire State Building
+    [-73.9772, 40.7527], // Grand Central
+];
+
+const ORIGIN: [f64; 2] = [-73.9855, 40.7580]; // Times Square
+
+fn sizes() -> impl Iterator<Item = &'static [[f64; 2]]> {
+    (0..=3).map(|n| &POINTS[..n])
+}
+
+fn line_string(points: &[[f64; 2]]) -> LineString<f64> {
+    points.iter().map(|&[x, y]| coord! {x: x, y: y}).collect()
+}
+
+#[test]
+fn lengths() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let length = ruler.length(points);
+
+        assert_eq!(ruler.try_length(points), Ok(length));
+        assert_eq!(Polyline(points.to_vec()).length(&ruler), length);
+        assert_eq!(
+            RulerMeasure::WGS84().total_length(&line_string(points)),
+            length
+        );
+
+        if points.len() < 2 {
+            assert_eq!(length, 0.);
+        } else {
+            assert!(length > 0.);
+        }
+    }
+}
+
+#[test]
+fn batches() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let mut distances = vec![f64::NAN; points.len()];
+        let mut bearings = vec![f64::NAN; points.len()];
+
+        ruler.distance_to_many(&ORIGIN, points, &mut distances);
+        ruler.bearing_to_many(&ORIGIN, points, &mut bearings);
+
+        assert!(distances.iter().all(|distance| distance.is_finite()));
+        assert!(bearings.iter().all(|bearing| bearing.is_finite()));
+    }
+}
+
+#[test]
+fn along_and_headings() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let along = ruler.along(points, &100.);
+        let heading = ruler.bearing_at_fraction(points, &0.5);
+
+        match points.len() {
+            0 => {
+                assert_eq!(along, None);
+                assert_eq!(heading, None);
+            }
+            1 => {
+                assert_eq!(along, Some(points[0]));
+                assert_eq!(heading, None);
+            }
+            _ => {
+                assert!(along.is_some());
+                assert!(heading.is_some_and(|heading| heading.is_finite()));
+            }
+        }
+    }
+}
+
+#[test]
+fn distances_to_lines() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let distance = ruler.distance_to_line(&ORIGIN, points);
+
+        if points.is_empty() {
+            assert_eq!(distance, f64::INFINITY);
+        } else {
+            assert!(distance.is_finite());
+        }
+    }
+}
+
+#[test]
+fn bounds() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let bbox = Polyline(points.to_vec()).bbox();
+        let circle = ruler.bounding_circle(points);
+        let rect = RulerMeasure::WGS84().bbox(&line_string(points));
+
+        if points.is_empty() {
+            assert_eq!(bbox, None);
+            assert_eq!(circle, None);
+            assert_eq!(rect, None);
+        } else {
+            assert!(bbox.is_some());
+            assert!(circle.is_some_and(|(_, radius)| radius.is_finite()));
+            assert!(rect.is_some());
+        }
+    }
+}
+
+#[test]
+fn hulls() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let hull = ruler.convex_hull(points);
+
+        // fewer than three points are their own hull, three are a triangle
+        if points.len() < 3 {
+            assert_eq!(hull, points);
+        } else {
+            assert_eq!(hull.len(), 3);
+            assert!(points.iter().all(|point| hull.contains(point)));
+        }
+    }
+}
+
+#[test]
+fn vertex_lists() {
+    let ruler = CheapRuler::WGS84();
+    let measure = RulerMeasure::WGS84();
+
+    for points in sizes() {
+        // fewer than three vertices are returned unchanged
+        if points.len() < 3 {
+            assert_eq!(ruler.simplify(points, &1_000.), points);
+            assert_eq!(ruler.simplify_area(points, &1_000_000.), points);
+            assert_eq!(
+                measure.simplify(&line_string(points), 1_000.),
+                line_string(points)
+            );
+            assert_eq!(
+                measure.simplify_vw(&line_string(points), 1_000_000.),
+                line_string(points)
+            );
+        }
+
+        let dense = ruler.densify(points, &100.);
+
+        if points.len() < 2 {
+            assert_eq!(dense, points);
+        } else {
+            assert!(dense.len() > points.len());
+        }
+    }
+}
+
+#[test]
+fn clipping() {
+    let ruler = CheapRuler::WGS84();
+    let bbox = [-74., 40.7, -73.9, 40.8];
+    let rect = Rect::new(coord! {x: -74., y: 40.7}, coord! {x: -73.9, y: 40.8});
+
+    for points in sizes() {
+        let parts = ruler.clip_to_bbox(points, &bbox);
+        let clipped = RulerMeasure::WGS84().clip_to_rect(&line_string(points), &rect);
+
+        // a single vertex is not a line and has no parts
+        if points.len() < 2 {
+            assert!(parts.is_empty());
+            assert_eq!(clipped, MultiLineString::<f64>::new(vec![]));
+        } else {
+            assert_eq!(parts, [points]);
+            assert_eq!(clipped.0.len(), 1);
+        }
+    }
+}
+
+#[test]
+fn rings() {
+    let ruler = CheapRuler::WGS84();
+
+    for points in sizes() {
+        let inside = ruler.inside_ring(&ORIGIN, points);
+        let fence = RulerPolygon::new(
+            Polygon::new(line_string(points), vec![]),
+            RulerMeasure::WGS84(),
+        );
+
+        // the origin is outside every ring, and the vertices of a ring are on its boundary
+        assert!(!inside);
+        assert!(!fence.contains(Point::from(ORIGIN)));
+
+        for vertex in points {
+            assert!(ruler.inside_ring(vertex, points));
+        }
+    }
+}
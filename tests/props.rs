@@ -1,90 +1,224 @@
 //! Property tests and correctness verification for the `geo_ruler` crate.
 //!
-//! This test suite verifies that the `Ruler` implementation maintains essential mathematical
-//! properties of geodesic calculations (such as symmetry and accuracy).
+//! This test suite verifies that the `RulerMeasure` implementation maintains essential
+//! mathematical properties of geodesic calculations (such as symmetry and accuracy).
 //!
 //! For verification, we use the Geodesic model from the `geo` crate as the reference implementation.
 //! The Geodesic model provides highly accurate calculations based on Karney's algorithm (2013),
 //! which is considered the gold standard for geodesic problems on an ellipsoid. By comparing
-//! our approximation against this reference implementation, we ensure that the `Ruler`
+//! our approximation against this reference implementation, we ensure that the `RulerMeasure`
 //! maintains acceptable accuracy for city-scale distances.
+//!
+//! Every property is checked on three models, so the scaling is verified across
+//! eccentricities and not only Earth's: the WGS84 ellipsoid against `Geodesic`, a sphere
+//! against the `Haversine` formula with the same radius, and the Mars ellipsoid against
+//! a Geodesic model of Mars.
 
 use approx::relative_eq;
-use geo::Geodesic;
-use geo::{Bearing, Destination, Distance, point};
+use geo::{Bearing, Coord, Destination, Distance, Point};
+use geo::{Geodesic, GeodesicMeasure, HaversineMeasure};
 use geo_ruler::geo::RulerMeasure;
 use proptest::prelude::*;
+use proptest::test_runner::TestCaseResult;
 
 const RELATIVE_ERROR: f64 = 0.01;
 
-proptest! {
-    #[test]
-    fn distance_arguments_are_symmetric(distance in 10f64..1_000., bearing in 0f64..360.) {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
+/// Mean radius of the Earth in meters, the same used by geo's Haversine.
+const SPHERE_RADIUS: f64 = 6_371_008.8;
+
+/// Equatorial and polar radius of Mars in meters.
+const MARS_RADII: (f64, f64) = (3_396_200., 3_376_200.);
+
+/// The Empire State Building, on Earth.
+const EMPIRE_STATE: Point<f64> = Point(Coord {
+    x: -73.9857,
+    y: 40.7484,
+});
+
+/// Olympus Mons, on Mars.
+const OLYMPUS_MONS: Point<f64> = Point(Coord {
+    x: -133.8,
+    y: 18.65,
+});
+
+fn sphere() -> (RulerMeasure<f64>, HaversineMeasure) {
+    (
+        RulerMeasure::sphere(SPHERE_RADIUS),
+        HaversineMeasure::new(SPHERE_RADIUS),
+    )
+}
 
-        let ruler = RulerMeasure::WGS84();
+fn mars() -> (
+    RulerMeasure<f64>,
+    impl Distance<f64, Point<f64>, Point<f64>> + Bearing<f64> + Destination<f64>,
+) {
+    let (major, minor) = MARS_RADII;
+
+    (
+        RulerMeasure::new(major, minor),
+        // geo names the argument inverse flattening, but it is passed on as the flattening
+        GeodesicMeasure::new(major, (major - minor) / major),
+    )
+}
 
-        let destination = ruler.destination(empire_state, bearing, distance);
+fn distance_arguments_are_symmetric_on(
+    ruler: &RulerMeasure<f64>,
+    origin: Point<f64>,
+    distance: f64,
+    bearing: f64,
+) -> TestCaseResult {
+    let destination = ruler.destination(origin, bearing, distance);
 
-        let error = (ruler.distance(empire_state, destination) - ruler.distance(destination, empire_state)).abs();
+    let error = (ruler.distance(origin, destination) - ruler.distance(destination, origin)).abs();
 
-        prop_assert!((error/distance) < RELATIVE_ERROR );
-    }
+    prop_assert!((error / distance) < RELATIVE_ERROR);
 
-    #[test]
-    fn destination_is_an_involutory_function(distance in 100f64..1000., bearing in 0f64..360.) {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
+    Ok(())
+}
 
-        let ruler = RulerMeasure::WGS84();
+fn destination_is_an_involutory_function_on(
+    ruler: &RulerMeasure<f64>,
+    origin: Point<f64>,
+    distance: f64,
+    bearing: f64,
+) -> TestCaseResult {
+    let destination = ruler.destination(origin, bearing, distance);
 
-        let destination = ruler.destination(empire_state, bearing, distance);
+    let inverse_bearing = (bearing + 180.).rem_euclid(360.);
+    let origin = ruler.destination(destination, inverse_bearing, distance);
 
-        let inverse_bearing = (bearing + 180.).rem_euclid(360.);
-        let origin = ruler.destination(destination, inverse_bearing, distance);
+    let error = (ruler.distance(origin, destination) - distance).abs();
 
-        let error = (ruler.distance(origin, destination) - distance).abs();
+    prop_assert!((error / distance) < RELATIVE_ERROR);
 
-        prop_assert!((error/distance) < RELATIVE_ERROR );
-    }
+    Ok(())
+}
 
-    #[test]
-    fn distance_is_precise(distance in 10f64..1_000., bearing in 0f64..360.) {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
-        let target = Geodesic.destination(empire_state, bearing, distance);
+fn distance_is_precise_on(
+    ruler: &RulerMeasure<f64>,
+    reference: &impl Destination<f64>,
+    origin: Point<f64>,
+    distance: f64,
+    bearing: f64,
+) -> TestCaseResult {
+    let target = reference.destination(origin, bearing, distance);
+
+    let error = (ruler.distance(origin, target) - distance).abs();
+
+    prop_assert!((error / distance) < RELATIVE_ERROR);
+
+    Ok(())
+}
+
+fn bearing_is_precise_on(
+    ruler: &RulerMeasure<f64>,
+    reference: &impl Destination<f64>,
+    origin: Point<f64>,
+    distance: f64,
+    bearing: f64,
+) -> TestCaseResult {
+    let target = reference.destination(origin, bearing, distance);
+
+    let error = (ruler.bearing(origin, target) - bearing).rem_euclid(360.);
+
+    prop_assert!(
+        error / distance < RELATIVE_ERROR
+            || relative_eq!(error, 360., max_relative = RELATIVE_ERROR)
+            || relative_eq!(error, 0., max_relative = RELATIVE_ERROR)
+    );
+
+    Ok(())
+}
+
+fn destination_is_precise_on(
+    ruler: &RulerMeasure<f64>,
+    reference: &(impl Destination<f64> + Distance<f64, Point<f64>, Point<f64>>),
+    origin: Point<f64>,
+    distance: f64,
+    bearing: f64,
+) -> TestCaseResult {
+    let target = reference.destination(origin, bearing, distance);
 
-        let ruler = RulerMeasure::WGS84();
+    let destination = ruler.destination(origin, bearing, distance);
 
-        let error = (ruler.distance(empire_state, target) - distance).abs();
+    let error = reference.distance(target, destination);
 
-        prop_assert!((error/distance) < RELATIVE_ERROR);
+    prop_assert!((error / distance) < RELATIVE_ERROR);
+
+    Ok(())
+}
+
+fn distance_error_is_bounded_on(
+    ruler: &RulerMeasure<f64>,
+    reference: &(impl Destination<f64> + Distance<f64, Point<f64>, Point<f64>>),
+    origin: Point<f64>,
+    distance: f64,
+    bearing: f64,
+) -> TestCaseResult {
+    let target = reference.destination(origin, bearing, distance);
+
+    let (approximation, bound) = ruler.distance_with_error(origin, target);
+
+    prop_assert!((approximation - reference.distance(origin, target)).abs() <= bound);
+
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn distance_arguments_are_symmetric(distance in 10f64..1_000., bearing in 0f64..360.) {
+        distance_arguments_are_symmetric_on(&RulerMeasure::WGS84(), EMPIRE_STATE, distance, bearing)?;
+        distance_arguments_are_symmetric_on(&sphere().0, EMPIRE_STATE, distance, bearing)?;
+        distance_arguments_are_symmetric_on(&mars().0, OLYMPUS_MONS, distance, bearing)?;
     }
 
     #[test]
-    fn bearing_is_precise(distance in 100f64..1000., bearing in 0f64..360.) {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
-        let target = Geodesic.destination(empire_state, bearing, distance);
+    fn destination_is_an_involutory_function(distance in 100f64..1000., bearing in 0f64..360.) {
+        destination_is_an_involutory_function_on(&RulerMeasure::WGS84(), EMPIRE_STATE, distance, bearing)?;
+        destination_is_an_involutory_function_on(&sphere().0, EMPIRE_STATE, distance, bearing)?;
+        destination_is_an_involutory_function_on(&mars().0, OLYMPUS_MONS, distance, bearing)?;
+    }
 
-        let ruler = RulerMeasure::WGS84();
+    #[test]
+    fn distance_is_precise(distance in 10f64..1_000., bearing in 0f64..360.) {
+        let (sphere, haversine) = sphere();
+        let (mars, mars_geodesic) = mars();
 
-        let error = (ruler.bearing(empire_state, target) - bearing).rem_euclid(360.);
+        distance_is_precise_on(&RulerMeasure::WGS84(), &Geodesic, EMPIRE_STATE, distance, bearing)?;
+        distance_is_precise_on(&sphere, &haversine, EMPIRE_STATE, distance, bearing)?;
+        distance_is_precise_on(&mars, &mars_geodesic, OLYMPUS_MONS, distance, bearing)?;
+    }
 
-        prop_assert!(error/distance < RELATIVE_ERROR
-            || relative_eq!(error, 360., max_relative = RELATIVE_ERROR)
-            || relative_eq!(error, 0., max_relative = RELATIVE_ERROR))
+    #[test]
+    fn bearing_is_precise(distance in 100f64..1000., bearing in 0f64..360.) {
+        let (sphere, haversine) = sphere();
+        let (mars, mars_geodesic) = mars();
+
+        bearing_is_precise_on(&RulerMeasure::WGS84(), &Geodesic, EMPIRE_STATE, distance, bearing)?;
+        bearing_is_precise_on(&sphere, &haversine, EMPIRE_STATE, distance, bearing)?;
+        bearing_is_precise_on(&mars, &mars_geodesic, OLYMPUS_MONS, distance, bearing)?;
     }
 
     #[test]
     fn destination_is_precise(distance in 100f64..1000., bearing in 0f64..360.) {
-        let empire_state = point!(x: -73.9857, y: 40.7484);
+        let (sphere, haversine) = sphere();
+        let (mars, mars_geodesic) = mars();
 
-        let target = Geodesic.destination(empire_state, bearing, distance);
-
-        let ruler = RulerMeasure::WGS84();
+        destination_is_precise_on(&RulerMeasure::WGS84(), &Geodesic, EMPIRE_STATE, distance, bearing)?;
+        destination_is_precise_on(&sphere, &haversine, EMPIRE_STATE, distance, bearing)?;
+        destination_is_precise_on(&mars, &mars_geodesic, OLYMPUS_MONS, distance, bearing)?;
+    }
 
-        let destination = ruler.destination(empire_state, bearing, distance);
+    #[test]
+    fn distance_error_is_bounded(distance in 10f64..100_000., bearing in 0f64..360., lat in -80f64..80.) {
+        let (sphere, haversine) = sphere();
+        let (mars, mars_geodesic) = mars();
 
-        let error = Geodesic.distance(target, destination);
+        let origin = Point::new(EMPIRE_STATE.x(), lat);
 
-        prop_assert!((error/distance) < RELATIVE_ERROR);
+        distance_error_is_bounded_on(&RulerMeasure::WGS84(), &Geodesic, EMPIRE_STATE, distance, bearing)?;
+        distance_error_is_bounded_on(&RulerMeasure::WGS84(), &Geodesic, origin, distance, bearing)?;
+        distance_error_is_bounded_on(&sphere, &haversine, origin, distance, bearing)?;
+        distance_error_is_bounded_on(&mars, &mars_geodesic, OLYMPUS_MONS, distance, bearing)?;
     }
 }